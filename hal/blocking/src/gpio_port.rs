@@ -242,3 +242,41 @@ pub trait GpioBankPassthrough: GpioPort {
     /// Disable passthrough for all pins.
     fn clear_passthrough(&mut self) -> Result<(), Self::Error>;
 }
+
+/// Logic level of a single GPIO pin
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Level {
+    /// Pin is at logic low
+    Low,
+    /// Pin is at logic high
+    High,
+}
+
+/// Electrical mode of a single GPIO pin
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PinMode {
+    /// High-impedance input
+    Input,
+    /// Push-pull output
+    Output,
+    /// Open-drain output: drives low, releases the line for high
+    OpenDrain,
+}
+
+/// Per-pin GPIO access by pin number, for board bring-up and strap reading
+///
+/// Pin numbers are platform-defined. Out-of-range pins report an error that
+/// maps to [`GpioErrorKind::InvalidPin`].
+pub trait Gpio: GpioErrorType {
+    /// Set the electrical mode of `pin`
+    fn configure(&mut self, pin: u32, mode: PinMode) -> Result<(), Self::Error>;
+
+    /// Drive `pin` to `level`; the pin must be configured as an output
+    fn set(&mut self, pin: u32, level: Level) -> Result<(), Self::Error>;
+
+    /// Read the current level of `pin`
+    ///
+    /// For open-drain pins this is the level on the line, which may be held
+    /// low externally while the pin is released.
+    fn get(&self, pin: u32) -> Result<Level, Self::Error>;
+}
@@ -20,6 +20,8 @@
 //! ```text
 //! I2cHardwareCore (foundation)
 //!     ├── I2cMaster (master operations)
+//!     │   └── I2cSmbusPec (SMBus packet error checking)
+//!     ├── I2cMasterTenBit (10-bit master operations, see TenBit)
 //!     └── slave module (blocking operations only)
 //!         ├── I2cSlaveCore (basic slave setup)
 //!         ├── I2cSlaveBuffer (data transfer)
@@ -33,7 +35,42 @@
 //!
 //! For non-blocking slave operations, see `openprot-hal-nb::i2c_hardware`.
 
-use embedded_hal::i2c::{AddressMode, ErrorType, Operation, SevenBitAddress};
+use embedded_hal::i2c::{AddressMode, ErrorType, Operation, SevenBitAddress, TenBitAddress};
+
+/// I2C target address in either addressing mode
+///
+/// [`I2cMaster`] is generic over the embedded-hal address mode; this enum lets
+/// code that handles both mixed on one bus (device tables, routing) carry an
+/// address together with its mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum I2cAddr {
+    /// 7-bit address (0x00..=0x7F)
+    Seven(SevenBitAddress),
+    /// 10-bit address (0x000..=0x3FF)
+    Ten(TenBitAddress),
+}
+
+impl I2cAddr {
+    /// Returns `true` if the address is within range for its addressing mode
+    pub const fn is_valid(self) -> bool {
+        match self {
+            I2cAddr::Seven(addr) => addr <= 0x7F,
+            I2cAddr::Ten(addr) => addr <= 0x3FF,
+        }
+    }
+}
+
+impl From<SevenBitAddress> for I2cAddr {
+    fn from(addr: SevenBitAddress) -> Self {
+        I2cAddr::Seven(addr)
+    }
+}
+
+impl From<TenBitAddress> for I2cAddr {
+    fn from(addr: TenBitAddress) -> Self {
+        I2cAddr::Ten(addr)
+    }
+}
 
 /// Core I2C hardware interface providing basic operations
 ///
@@ -92,6 +129,12 @@ pub trait I2cHardwareCore: ErrorType {
     fn handle_interrupt(&mut self);
 }
 
+/// Maximum number of SCL pulses clocked out by bus recovery
+///
+/// A slave holding SDA low is in the middle of a byte; after at most eight
+/// data bits and the ACK bit it releases SDA and the master can issue a STOP.
+pub const BUS_RECOVERY_CLOCKS: u8 = 9;
+
 /// Bus recovery — minimal seam for callers that need recovery without the full
 /// `I2cHardwareCore` init/timing contract.
 ///
@@ -101,6 +144,9 @@ pub trait I2cHardwareCore: ErrorType {
 pub trait I2cBusRecovery: ErrorType {
     /// Attempt to recover the bus from a stuck condition (held SDA/SCL).
     ///
+    /// The standard routine clocks SCL until the slave holding SDA releases
+    /// it, at most [`BUS_RECOVERY_CLOCKS`] pulses, then issues a STOP.
+    ///
     /// On success the bus is idle and the next transaction can proceed.
     /// On error the bus is unrecoverable by software; the caller should
     /// propagate the original transfer error to its client.
@@ -129,6 +175,153 @@ pub trait I2cMaster<A: AddressMode = SevenBitAddress>: I2cHardwareCore {
     ) -> Result<(), Self::Error>;
 }
 
+/// I2C Master mode operations on 10-bit addresses
+///
+/// Controllers implement [`I2cMaster`] for 7-bit addresses only, so an
+/// untyped literal in `write(0x50, ..)` is never ambiguous, and provide 10-bit
+/// transfers through this trait instead. Drivers written against
+/// `I2cMaster<TenBitAddress>` run on such a controller through [`TenBit`].
+pub trait I2cMasterTenBit: I2cHardwareCore {
+    /// Write data to a slave device at the given 10-bit address
+    fn write_ten_bit(&mut self, addr: TenBitAddress, bytes: &[u8]) -> Result<(), Self::Error>;
+
+    /// Read data from a slave device at the given 10-bit address
+    fn read_ten_bit(&mut self, addr: TenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Combined write-then-read operation with restart condition
+    fn write_read_ten_bit(
+        &mut self,
+        addr: TenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+
+    /// Execute a sequence of I2C operations as a single atomic transaction
+    fn transaction_slice_ten_bit(
+        &mut self,
+        addr: TenBitAddress,
+        ops_slice: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error>;
+}
+
+/// Presents an [`I2cMasterTenBit`] controller as `I2cMaster<TenBitAddress>`
+///
+/// ```text
+/// let mut ten_bit = TenBit(&mut controller);
+/// driver.probe(&mut ten_bit)?; // driver: I2cMaster<TenBitAddress>
+/// ```
+pub struct TenBit<'a, T>(pub &'a mut T);
+
+impl<T: ErrorType> ErrorType for TenBit<'_, T> {
+    type Error = T::Error;
+}
+
+impl<T: I2cHardwareCore> I2cHardwareCore for TenBit<'_, T> {
+    type Config = T::Config;
+    type I2cSpeed = T::I2cSpeed;
+    type TimingConfig = T::TimingConfig;
+
+    fn init(&mut self, config: &mut Self::Config) -> Result<(), Self::Error> {
+        self.0.init(config)
+    }
+
+    fn configure_timing(
+        &mut self,
+        speed: Self::I2cSpeed,
+        timing: &Self::TimingConfig,
+    ) -> Result<u32, Self::Error> {
+        self.0.configure_timing(speed, timing)
+    }
+
+    fn enable_interrupts(&mut self, mask: u32) {
+        self.0.enable_interrupts(mask)
+    }
+
+    fn clear_interrupts(&mut self, mask: u32) {
+        self.0.clear_interrupts(mask)
+    }
+
+    fn handle_interrupt(&mut self) {
+        self.0.handle_interrupt()
+    }
+}
+
+impl<T: I2cMasterTenBit> I2cMaster<TenBitAddress> for TenBit<'_, T> {
+    fn write(&mut self, addr: TenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write_ten_bit(addr, bytes)
+    }
+
+    fn read(&mut self, addr: TenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.read_ten_bit(addr, buffer)
+    }
+
+    fn write_read(
+        &mut self,
+        addr: TenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.0.write_read_ten_bit(addr, bytes, buffer)
+    }
+
+    fn transaction_slice(
+        &mut self,
+        addr: TenBitAddress,
+        ops_slice: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.0.transaction_slice_ten_bit(addr, ops_slice)
+    }
+}
+
+/// Compute an SMBus packet error code (PEC) over `bytes`
+///
+/// The PEC is a CRC-8 (polynomial x^8 + x^2 + x + 1, initial value 0) over
+/// every byte of the transfer, including each address byte with its R/W bit.
+/// Pass 0 as `crc` to start a new PEC, or a previous result to continue it
+/// across the segments of a combined transfer.
+pub const fn smbus_pec(crc: u8, bytes: &[u8]) -> u8 {
+    let mut crc = crc;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i];
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+/// SMBus packet error checking (PEC) transfers
+///
+/// Extends master mode with a `with_pec` transaction mode for SMBus devices.
+/// Writes append the PEC byte computed by [`smbus_pec`]; reads receive one
+/// extra trailing byte and check it against the PEC of the whole transfer.
+/// A mismatch is reported through the implementation's error type.
+pub trait I2cSmbusPec<A: AddressMode = SevenBitAddress>: I2cMaster<A> {
+    /// Write data to a slave device, followed by the PEC byte
+    fn write_with_pec(&mut self, addr: A, bytes: &[u8]) -> Result<(), Self::Error>;
+
+    /// Read data from a slave device and validate the trailing PEC byte
+    fn read_with_pec(&mut self, addr: A, buffer: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Combined write-then-read with restart, validating the trailing PEC byte
+    ///
+    /// The PEC covers both segments, including the repeated address byte.
+    fn write_read_with_pec(
+        &mut self,
+        addr: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+}
+
 /// I2C Slave/Target mode functionality
 ///
 /// This module contains all slave-related traits decomposed into
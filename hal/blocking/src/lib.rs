@@ -27,8 +27,14 @@ pub mod i2c_device;
 pub mod i2c_hardware;
 /// Message Authentication Code (MAC) traits and implementations
 pub mod mac;
+/// Random number generation traits
+pub mod rng;
+/// SPI host controller traits
+pub mod spi_host;
 /// Reset and clocking traits for OpenPRoT HAL
 pub mod system_control;
+/// Monotonic timer traits
+pub mod timer;
 
 /// Key management traits
 pub mod key_vault;
@@ -43,4 +49,7 @@ pub use embedded_hal::i2c::{I2c, SevenBitAddress, TenBitAddress};
 pub use embedded_hal::spi::{SpiBus, SpiDevice};
 
 // Re-export system control traits
-pub use system_control::{ClockControl, ResetControl, SystemControl};
+pub use system_control::{
+    ClockControl, ClockDomain, ClockGating, ClockTree, Peripheral, PowerControl, PowerState,
+    ResetControl, ResetReason, ResetReasonReport, SystemControl, WatchdogControl,
+};
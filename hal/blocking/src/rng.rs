@@ -0,0 +1,62 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Random number generation
+//!
+//! [`Rng`] abstracts the platform's random source (TRNG, DRBG seeded from
+//! one, or a deterministic mock in tests) for nonce and challenge
+//! generation. Whether output is suitable for key material is a property of
+//! the implementation, not of this trait.
+
+/// Represents common random number generation errors.
+///
+/// Implementations are free to define more specific or additional error
+/// types. However, by providing a mapping to these common errors, generic
+/// code can still react to them appropriately.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The entropy source has not produced enough output yet.
+    NotReady,
+    /// The entropy source failed a health test; its output must not be used.
+    HealthTestFailed,
+    /// A hardware-level failure occurred during the operation.
+    HardwareFailure,
+}
+
+/// Trait for random number generation error types.
+pub trait Error: core::fmt::Debug {
+    /// Convert error to a generic error kind.
+    ///
+    /// By using this method, errors freely defined by RNG implementations
+    /// can be converted to a set of generic errors upon which generic
+    /// code can act.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// Trait providing access to the associated error type.
+pub trait ErrorType {
+    /// The error type used by this implementation.
+    type Error: Error;
+}
+
+/// Source of random bytes.
+pub trait Rng: ErrorType {
+    /// Fill `buf` entirely with random bytes.
+    ///
+    /// On error the contents of `buf` are unspecified and must not be used.
+    fn fill_bytes(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Return the next random `u32`.
+    ///
+    /// This is for callers that cannot act on a failure; implementations
+    /// block until output is available. Use [`fill_bytes`](Self::fill_bytes)
+    /// where an entropy failure must be reported.
+    fn next_u32(&mut self) -> u32;
+}
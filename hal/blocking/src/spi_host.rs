@@ -0,0 +1,27 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! # SPI Host Abstraction
+//!
+//! [`SpiHost`] is the command-oriented view of a SPI controller that flash
+//! and TPM drivers are written against: each call is one chip-select
+//! assertion. The error type comes from [`embedded_hal::spi::ErrorType`], as
+//! the I2C hardware traits do for their bus.
+
+use embedded_hal::spi::ErrorType;
+
+/// SPI host controller operations, one chip-select cycle per call
+pub trait SpiHost: ErrorType {
+    /// Full-duplex transfer of `max(tx.len(), rx.len())` bytes
+    ///
+    /// Byte `i` of `tx` is shifted out while byte `i` of `rx` is shifted in.
+    /// Once `tx` is exhausted the implementation clocks out filler bytes;
+    /// bytes received beyond `rx` are discarded.
+    fn transfer(&mut self, tx: &[u8], rx: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Half-duplex command: shift out all of `tx`, then shift in `rx.len()`
+    /// bytes, with chip select held across both phases
+    ///
+    /// This is the usual shape of a flash command such as JEDEC ID or Read.
+    fn write_then_read(&mut self, tx: &[u8], rx: &mut [u8]) -> Result<(), Self::Error>;
+}
@@ -79,6 +79,8 @@ pub enum ErrorKind {
     PermissionDenied,
     /// The operation timed out before completion.
     Timeout,
+    /// An argument was invalid for the current state (e.g. an illegal power-state transition).
+    InvalidArgument,
 }
 
 /// Trait for system control error types.
@@ -206,6 +208,67 @@ pub trait ClockControl: ErrorType {
     fn get_config(&self, clock_id: &Self::ClockId) -> Result<Self::ClockConfig, Self::Error>;
 }
 
+/// Clock domain feeding a group of peripherals.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ClockDomain {
+    /// Core and system bus clock.
+    System,
+    /// Clock feeding peripheral blocks such as UART, I2C and SPI.
+    Peripheral,
+    /// Always-on low-frequency clock (e.g. for timers and wake sources).
+    AlwaysOn,
+}
+
+/// Trait for querying the frequencies of the clock tree.
+/// Lets drivers derive dividers (e.g. UART baud or I2C timing) from the clock
+/// actually feeding them instead of a hardcoded constant.
+pub trait ClockTree {
+    /// Returns the current frequency of a clock domain (in Hz).
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The clock domain to query.
+    ///
+    /// # Returns
+    ///
+    /// * `u32` - The domain frequency in Hertz.
+    fn clock_hz(&self, domain: ClockDomain) -> u32;
+}
+
+/// Peripheral instance, identified by kind and instance number.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Peripheral {
+    /// I2C controller instance.
+    I2c(u8),
+    /// SPI controller instance.
+    Spi(u8),
+    /// UART instance.
+    Uart(u8),
+}
+
+/// Trait for peripheral clock gating.
+/// Abstracts turning individual peripheral clocks on and off, e.g. to save power.
+pub trait ClockGating {
+    /// Ungates (`on == true`) or gates the clock of a peripheral.
+    ///
+    /// # Arguments
+    ///
+    /// * `peripheral` - The peripheral whose clock to control.
+    /// * `on` - Whether the clock should run.
+    fn set_clock_enabled(&mut self, peripheral: Peripheral, on: bool);
+
+    /// Checks whether the clock of a peripheral is running.
+    ///
+    /// # Arguments
+    ///
+    /// * `peripheral` - The peripheral to check.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - `true` if the clock is ungated.
+    fn is_clock_enabled(&self, peripheral: Peripheral) -> bool;
+}
+
 /// Trait for reset control operations.
 /// Abstracts asserting and deasserting reset signals for peripherals or system components.
 pub trait ResetControl: ErrorType {
@@ -262,6 +325,101 @@ pub trait ResetControl: ErrorType {
     fn reset_is_asserted(&self, reset_id: &Self::ResetId) -> Result<bool, Self::Error>;
 }
 
+/// Trait for watchdog timer control.
+/// Abstracts arming, petting, and disarming a watchdog that resets the system on a hang.
+pub trait WatchdogControl: ErrorType {
+    /// Enables the watchdog with the given timeout (in milliseconds).
+    ///
+    /// If the watchdog is not pet within `timeout_ms` the system is reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout_ms` - The timeout window in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Self::Error>` - Ok if the operation is successful, or an error of type `Self::Error`.
+    fn wdt_enable(&mut self, timeout_ms: u32) -> Result<(), Self::Error>;
+
+    /// Pets (services) the watchdog, restarting the timeout window.
+    ///
+    /// Has no effect while the watchdog is disabled.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Self::Error>` - Ok if the operation is successful, or an error of type `Self::Error`.
+    fn wdt_pet(&mut self) -> Result<(), Self::Error>;
+
+    /// Disables the watchdog.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Self::Error>` - Ok if the operation is successful, or an error of type `Self::Error`.
+    fn wdt_disable(&mut self) -> Result<(), Self::Error>;
+}
+
+/// System power state.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PowerState {
+    /// Fully running.
+    Active,
+    /// Clock-gated while waiting for an interrupt; resumes immediately.
+    Idle,
+    /// Most of the system powered down; only wake sources remain active.
+    DeepSleep,
+    /// Powered off; leaving this state requires a reset.
+    Off,
+}
+
+/// Trait for power-state control.
+/// Abstracts moving the system between active and low-power states.
+pub trait PowerControl: ErrorType {
+    /// Requests a transition to the given power state.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The power state to enter.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Self::Error>` - Ok if the transition is performed, or an error of type
+    ///   `Self::Error` if it is not allowed from the current state.
+    fn set_power_state(&mut self, state: PowerState) -> Result<(), Self::Error>;
+
+    /// Returns the current power state.
+    ///
+    /// # Returns
+    ///
+    /// * `PowerState` - The state the system is currently in.
+    fn power_state(&self) -> PowerState;
+}
+
+/// Cause of the most recent system reset.
+///
+/// Boot flows use this to distinguish a cold start from recovery after a
+/// fault (e.g. a watchdog expiry or brownout).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ResetReason {
+    /// Power was applied to the device (cold boot).
+    PowerOn,
+    /// The watchdog timer expired.
+    Watchdog,
+    /// Firmware requested the reset.
+    Software,
+    /// Supply voltage dropped below the brownout threshold.
+    Brownout,
+}
+
+/// Trait for reporting why the system last reset.
+pub trait ResetReasonReport {
+    /// Returns the cause of the most recent system reset.
+    ///
+    /// # Returns
+    ///
+    /// * `ResetReason` - The reset cause latched by hardware at boot.
+    fn last_reset_reason(&self) -> ResetReason;
+}
+
 /// Blanket trait that combines clock and reset control functionality.
 ///
 /// This trait provides a unified interface for system control operations,
@@ -0,0 +1,31 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Monotonic time since boot
+//!
+//! [`MonotonicTimer`] is the shared time source for components that schedule
+//! timeouts (the MCTP server, telemetry timestamps). Implementations never
+//! go backwards and are not adjusted for wall-clock time.
+
+/// Read-only monotonic time since boot
+///
+/// Both resolutions read the same underlying counter, so
+/// `now_millis() == now_micros() / 1000` for reads without time in between.
+/// Reads take `&self` so one timer can be shared by several consumers.
+pub trait MonotonicTimer {
+    /// Milliseconds since boot
+    fn now_millis(&self) -> u64;
+
+    /// Microseconds since boot
+    fn now_micros(&self) -> u64;
+}
+
+impl<T: MonotonicTimer + ?Sized> MonotonicTimer for &T {
+    fn now_millis(&self) -> u64 {
+        (**self).now_millis()
+    }
+
+    fn now_micros(&self) -> u64 {
+        (**self).now_micros()
+    }
+}
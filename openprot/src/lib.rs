@@ -1,16 +1,271 @@
 // Licensed under the Apache-2.0 license
 // SPDX-License-Identifier: Apache-2.0
 
-pub fn greet(name: &str) -> String {
-    format!("Hello, {name}!")
+//! # OpenPRoT
+//!
+//! Ties the platform abstractions together. A [`Platform`] owns one
+//! implementation of each HAL capability a service needs (hashing, I2C,
+//! system control, randomness, time), so services take a single handle
+//! instead of wiring every trait separately.
+//!
+//! Platforms are assembled with [`Platform::builder`]. Every component must
+//! be supplied before [`PlatformBuilder::build`] is callable; a missing one
+//! is a compile error rather than a runtime failure.
+
+#![no_std]
+#![warn(missing_docs)]
+
+use openprot_hal_blocking::digest::ErrorType as DigestErrorType;
+use openprot_hal_blocking::i2c_hardware::I2cHardwareCore;
+use openprot_hal_blocking::rng::Rng;
+use openprot_hal_blocking::system_control::SystemControl;
+use openprot_hal_blocking::timer::MonotonicTimer;
+
+/// Handle to the hardware abstractions of one platform.
+///
+/// - `H`: digest accelerator
+/// - `I`: I2C controller
+/// - `S`: clock and reset control
+/// - `R`: random number generator
+/// - `T`: monotonic timer
+pub struct Platform<H, I, S, R, T> {
+    hash: H,
+    i2c: I,
+    system_control: S,
+    rng: R,
+    timer: T,
+}
+
+impl Platform<(), (), (), (), ()> {
+    /// Start assembling a platform.
+    pub fn builder() -> PlatformBuilder<(), (), (), (), ()> {
+        PlatformBuilder {
+            hash: (),
+            i2c: (),
+            system_control: (),
+            rng: (),
+            timer: (),
+        }
+    }
+}
+
+impl<H, I, S, R, T> Platform<H, I, S, R, T>
+where
+    H: DigestErrorType,
+    I: I2cHardwareCore,
+    S: SystemControl,
+    R: Rng,
+    T: MonotonicTimer,
+{
+    /// Digest accelerator.
+    pub fn hash(&mut self) -> &mut H {
+        &mut self.hash
+    }
+
+    /// I2C controller.
+    pub fn i2c(&mut self) -> &mut I {
+        &mut self.i2c
+    }
+
+    /// Clock and reset control.
+    pub fn system_control(&mut self) -> &mut S {
+        &mut self.system_control
+    }
+
+    /// Random number generator.
+    pub fn rng(&mut self) -> &mut R {
+        &mut self.rng
+    }
+
+    /// Monotonic timer.
+    pub fn timer(&self) -> &T {
+        &self.timer
+    }
+
+    /// Take the platform apart into its components.
+    pub fn into_parts(self) -> (H, I, S, R, T) {
+        (
+            self.hash,
+            self.i2c,
+            self.system_control,
+            self.rng,
+            self.timer,
+        )
+    }
+}
+
+/// Builder for [`Platform`].
+///
+/// Each setter replaces one `()` placeholder with a component; `build`
+/// exists only once all five are set.
+pub struct PlatformBuilder<H, I, S, R, T> {
+    hash: H,
+    i2c: I,
+    system_control: S,
+    rng: R,
+    timer: T,
+}
+
+impl<H, I, S, R, T> PlatformBuilder<H, I, S, R, T> {
+    /// Set the digest accelerator.
+    pub fn hash<H2: DigestErrorType>(self, hash: H2) -> PlatformBuilder<H2, I, S, R, T> {
+        PlatformBuilder {
+            hash,
+            i2c: self.i2c,
+            system_control: self.system_control,
+            rng: self.rng,
+            timer: self.timer,
+        }
+    }
+
+    /// Set the I2C controller.
+    pub fn i2c<I2: I2cHardwareCore>(self, i2c: I2) -> PlatformBuilder<H, I2, S, R, T> {
+        PlatformBuilder {
+            hash: self.hash,
+            i2c,
+            system_control: self.system_control,
+            rng: self.rng,
+            timer: self.timer,
+        }
+    }
+
+    /// Set the clock and reset controller.
+    pub fn system_control<S2: SystemControl>(
+        self,
+        system_control: S2,
+    ) -> PlatformBuilder<H, I, S2, R, T> {
+        PlatformBuilder {
+            hash: self.hash,
+            i2c: self.i2c,
+            system_control,
+            rng: self.rng,
+            timer: self.timer,
+        }
+    }
+
+    /// Set the random number generator.
+    pub fn rng<R2: Rng>(self, rng: R2) -> PlatformBuilder<H, I, S, R2, T> {
+        PlatformBuilder {
+            hash: self.hash,
+            i2c: self.i2c,
+            system_control: self.system_control,
+            rng,
+            timer: self.timer,
+        }
+    }
+
+    /// Set the monotonic timer.
+    pub fn timer<T2: MonotonicTimer>(self, timer: T2) -> PlatformBuilder<H, I, S, R, T2> {
+        PlatformBuilder {
+            hash: self.hash,
+            i2c: self.i2c,
+            system_control: self.system_control,
+            rng: self.rng,
+            timer,
+        }
+    }
+}
+
+impl<H, I, S, R, T> PlatformBuilder<H, I, S, R, T>
+where
+    H: DigestErrorType,
+    I: I2cHardwareCore,
+    S: SystemControl,
+    R: Rng,
+    T: MonotonicTimer,
+{
+    /// Finish assembling the platform.
+    pub fn build(self) -> Platform<H, I, S, R, T> {
+        Platform {
+            hash: self.hash,
+            i2c: self.i2c,
+            system_control: self.system_control,
+            rng: self.rng,
+            timer: self.timer,
+        }
+    }
+}
+
+/// Greeting printed by the `openprot` binary.
+///
+/// Formats as `Hello, {name}!` without allocating.
+pub struct Greeting<'a>(&'a str);
+
+impl core::fmt::Display for Greeting<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Hello, {}!", self.0)
+    }
+}
+
+/// Greet `name`.
+pub fn greet(name: &str) -> Greeting<'_> {
+    Greeting(name)
 }
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
     use super::*;
+    use openprot_hal_blocking::digest::scoped::{DigestInit, DigestOp};
+    use openprot_hal_blocking::digest::Sha2_256;
+    use openprot_hal_blocking::i2c_hardware::I2cMaster;
+    use openprot_hal_blocking::system_control::ClockControl;
+    use openprot_platform_mock::hash::MockDigestDevice;
+    use openprot_platform_mock::i2c_hardware::MockI2cHardware;
+    use openprot_platform_mock::rng::MockRng;
+    use openprot_platform_mock::system_control::{MockClockId, MockSystemControl};
+    use openprot_platform_mock::timer::MockTimer;
+    use std::string::ToString;
+
+    fn mock_platform(
+    ) -> Platform<MockDigestDevice, MockI2cHardware, MockSystemControl, MockRng, MockTimer> {
+        Platform::builder()
+            .hash(MockDigestDevice::new())
+            .i2c(MockI2cHardware::new())
+            .system_control(MockSystemControl::new())
+            .rng(MockRng::new(7))
+            .timer(MockTimer::new())
+            .build()
+    }
+
+    #[test]
+    fn accessors_reach_each_component() {
+        let mut platform = mock_platform();
+
+        let mut ctx = platform.hash().init(Sha2_256).unwrap();
+        ctx.update(b"abc").unwrap();
+        ctx.finalize().unwrap();
+
+        let mut config = Default::default();
+        platform.i2c().init(&mut config).unwrap();
+        platform.i2c().write(0x50, &[0x00]).unwrap();
+
+        let clock = MockClockId::from(1);
+        platform.system_control().enable(&clock).unwrap();
+
+        let mut expected = MockRng::new(7);
+        assert_eq!(platform.rng().next_u32(), expected.next_u32());
+
+        platform.timer().advance_millis(10);
+        assert_eq!(platform.timer().now_millis(), 10);
+    }
+
+    #[test]
+    fn setters_may_come_in_any_order() {
+        let platform = Platform::builder()
+            .timer(MockTimer::new())
+            .rng(MockRng::new(1))
+            .system_control(MockSystemControl::new())
+            .i2c(MockI2cHardware::new())
+            .hash(MockDigestDevice::new())
+            .build();
+        let (_, _, _, _, timer) = platform.into_parts();
+        assert_eq!(timer.now_micros(), 0);
+    }
 
     #[test]
     fn test_greet() {
-        assert_eq!(greet("OpenProt"), "Hello, OpenProt!");
+        assert_eq!(greet("OpenProt").to_string(), "Hello, OpenProt!");
     }
 }
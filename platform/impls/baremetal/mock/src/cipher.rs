@@ -0,0 +1,311 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mock AEAD Cipher
+//!
+//! Provides an authenticated cipher built from the software HMAC-SHA-256 in
+//! [`crate::hash::software`]: data is XORed with an HMAC keystream and the tag
+//! is a truncated HMAC over the nonce, associated data and ciphertext
+//! (encrypt-then-MAC). The 96-bit nonce and 128-bit tag match AES-GCM, so it
+//! can stand in for one in tests. It has not been reviewed as a cipher and
+//! must never back a real platform.
+//!
+//! [`MockAead`] works on slices in place, for callers whose messages vary in
+//! length; [`MockCipherDevice`] exposes the same cipher through the HAL
+//! [`CipherInit`] and [`AeadCipherOp`] traits.
+
+use crate::hash::software::{MockMacKey, SoftwareMacDevice};
+use openprot_hal_blocking::cipher::{
+    AeadCipherMode, AeadCipherOp, CipherInit, CipherMode, CipherOp, Error, ErrorKind, ErrorType,
+    SymmetricCipher,
+};
+use openprot_hal_blocking::mac::HmacSha2_256;
+use openprot_hal_blocking::mac::scoped::{MacInit, MacOp};
+use subtle::ConstantTimeEq;
+
+/// Key length, in bytes.
+pub const KEY_LEN: usize = 32;
+
+/// Nonce length, in bytes.
+pub const NONCE_LEN: usize = 12;
+
+/// Authentication tag length, in bytes.
+pub const TAG_LEN: usize = 16;
+
+/// Size of the fixed plaintext and ciphertext buffers of the HAL traits.
+pub const BUFFER_LEN: usize = 256;
+
+/// Keystream block length: one HMAC-SHA-256 output.
+const BLOCK_LEN: usize = 32;
+
+/// Mock cipher error type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockCipherError {
+    /// The tag does not authenticate the ciphertext and associated data.
+    AuthenticationFailed,
+}
+
+impl Error for MockCipherError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::AuthenticationFailed => ErrorKind::InvalidInput,
+        }
+    }
+}
+
+/// Mock AEAD mode marker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MockAeadMode;
+
+impl CipherMode for MockAeadMode {}
+impl AeadCipherMode for MockAeadMode {}
+
+/// Mock AEAD cipher keyed at construction
+#[derive(Clone)]
+pub struct MockAead {
+    key: [u8; KEY_LEN],
+}
+
+impl MockAead {
+    /// Create a cipher keyed by `key`
+    pub const fn new(key: [u8; KEY_LEN]) -> Self {
+        Self { key }
+    }
+
+    /// HMAC-SHA-256 under the cipher key over `parts`, in order.
+    fn hmac(&self, parts: &[&[u8]]) -> [u8; BLOCK_LEN] {
+        let mut device = SoftwareMacDevice::new();
+        let key = MockMacKey::from_slice(&self.key).unwrap();
+        let mut ctx = device.init(HmacSha2_256, key).unwrap();
+        for part in parts {
+            ctx.update(part).unwrap();
+        }
+        let mut out = [0u8; BLOCK_LEN];
+        out.copy_from_slice(ctx.finalize().unwrap().as_bytes());
+        out
+    }
+
+    fn apply_keystream(&self, nonce: &[u8; NONCE_LEN], data: &mut [u8]) {
+        for (counter, chunk) in data.chunks_mut(BLOCK_LEN).enumerate() {
+            let block = self.hmac(&[b"K", nonce, &(counter as u32).to_be_bytes()]);
+            for (byte, k) in chunk.iter_mut().zip(block) {
+                *byte ^= k;
+            }
+        }
+    }
+
+    fn tag(&self, nonce: &[u8; NONCE_LEN], aad: &[u8], ciphertext: &[u8]) -> [u8; TAG_LEN] {
+        let aad_len = (aad.len() as u64).to_le_bytes();
+        let mac = self.hmac(&[b"T", nonce, &aad_len, aad, ciphertext]);
+        let mut tag = [0u8; TAG_LEN];
+        tag.copy_from_slice(&mac[..TAG_LEN]);
+        tag
+    }
+
+    /// Encrypt `data` in place and return the tag authenticating it and `aad`
+    pub fn encrypt_in_place(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        data: &mut [u8],
+    ) -> [u8; TAG_LEN] {
+        self.apply_keystream(nonce, data);
+        self.tag(nonce, aad, data)
+    }
+
+    /// Check `tag` against `data` and `aad`, then decrypt `data` in place
+    ///
+    /// The tag is compared in constant time. On a mismatch `data` is left
+    /// as it was.
+    pub fn decrypt_in_place(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &[u8; TAG_LEN],
+    ) -> Result<(), MockCipherError> {
+        let expected = self.tag(nonce, aad, data);
+        if !bool::from(expected[..].ct_eq(&tag[..])) {
+            return Err(MockCipherError::AuthenticationFailed);
+        }
+        self.apply_keystream(nonce, data);
+        Ok(())
+    }
+}
+
+/// Mock cipher device, keyed per operation through [`CipherInit`]
+#[derive(Default)]
+pub struct MockCipherDevice;
+
+impl MockCipherDevice {
+    /// Create a new mock cipher device
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ErrorType for MockCipherDevice {
+    type Error = MockCipherError;
+}
+
+impl SymmetricCipher for MockCipherDevice {
+    type Key = [u8; KEY_LEN];
+    type Nonce = [u8; NONCE_LEN];
+    type PlainText = [u8; BUFFER_LEN];
+    type CipherText = [u8; BUFFER_LEN];
+}
+
+/// Cipher context binding a key and nonce for the lifetime of the device borrow
+pub struct MockAeadContext<'a> {
+    #[allow(dead_code)] // Ties the context to the device borrow
+    hw: &'a mut MockCipherDevice,
+    aead: MockAead,
+    nonce: [u8; NONCE_LEN],
+}
+
+impl ErrorType for MockAeadContext<'_> {
+    type Error = MockCipherError;
+}
+
+impl SymmetricCipher for MockAeadContext<'_> {
+    type Key = [u8; KEY_LEN];
+    type Nonce = [u8; NONCE_LEN];
+    type PlainText = [u8; BUFFER_LEN];
+    type CipherText = [u8; BUFFER_LEN];
+}
+
+impl CipherInit<MockAeadMode> for MockCipherDevice {
+    type CipherContext<'a> = MockAeadContext<'a>;
+
+    fn init<'a>(
+        &'a mut self,
+        key: &Self::Key,
+        nonce: &Self::Nonce,
+    ) -> Result<Self::CipherContext<'a>, Self::Error> {
+        Ok(MockAeadContext {
+            hw: self,
+            aead: MockAead::new(*key),
+            nonce: *nonce,
+        })
+    }
+}
+
+impl CipherOp<MockAeadMode> for MockAeadContext<'_> {
+    /// Encrypt without producing a tag; use [`AeadCipherOp::encrypt_aead`]
+    /// for authenticated encryption.
+    fn encrypt(&mut self, mut plaintext: Self::PlainText) -> Result<Self::CipherText, Self::Error> {
+        self.aead.apply_keystream(&self.nonce, &mut plaintext);
+        Ok(plaintext)
+    }
+
+    /// Decrypt without checking a tag; use [`AeadCipherOp::decrypt_aead`]
+    /// for authenticated decryption.
+    fn decrypt(
+        &mut self,
+        mut ciphertext: Self::CipherText,
+    ) -> Result<Self::PlainText, Self::Error> {
+        self.aead.apply_keystream(&self.nonce, &mut ciphertext);
+        Ok(ciphertext)
+    }
+}
+
+impl AeadCipherOp for MockAeadContext<'_> {
+    type Tag = [u8; TAG_LEN];
+
+    fn encrypt_aead(
+        &mut self,
+        mut plaintext: Self::PlainText,
+        associated_data: &[u8],
+    ) -> Result<(Self::CipherText, Self::Tag), Self::Error> {
+        let tag = self
+            .aead
+            .encrypt_in_place(&self.nonce, associated_data, &mut plaintext);
+        Ok((plaintext, tag))
+    }
+
+    fn decrypt_aead(
+        &mut self,
+        mut ciphertext: Self::CipherText,
+        associated_data: &[u8],
+        tag: Self::Tag,
+    ) -> Result<Self::PlainText, Self::Error> {
+        self.aead
+            .decrypt_in_place(&self.nonce, associated_data, &mut ciphertext, &tag)?;
+        Ok(ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; KEY_LEN] = *b"mock-aead-key-for-platform-tests";
+    const NONCE: [u8; NONCE_LEN] = [0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7];
+
+    #[test]
+    fn in_place_round_trip() {
+        let aead = MockAead::new(KEY);
+        // Longer than one keystream block, not a multiple of it
+        let plaintext: [u8; 45] = core::array::from_fn(|i| i as u8);
+
+        let mut data = plaintext;
+        let tag = aead.encrypt_in_place(&NONCE, b"header", &mut data);
+        assert_ne!(data, plaintext);
+
+        aead.decrypt_in_place(&NONCE, b"header", &mut data, &tag)
+            .unwrap();
+        assert_eq!(data, plaintext);
+    }
+
+    #[test]
+    fn tampering_rejected() {
+        let aead = MockAead::new(KEY);
+        let mut sealed = *b"measurement";
+        let tag = aead.encrypt_in_place(&NONCE, b"aad", &mut sealed);
+
+        let mut data = sealed;
+        data[3] ^= 0x01;
+        let err = aead.decrypt_in_place(&NONCE, b"aad", &mut data, &tag);
+        assert_eq!(err, Err(MockCipherError::AuthenticationFailed));
+
+        // Rejected data is left sealed
+        let rejected = |aead: &MockAead, nonce, aad: &[u8], tag| {
+            let mut data = sealed;
+            let result = aead.decrypt_in_place(nonce, aad, &mut data, tag);
+            result.is_err() && data == sealed
+        };
+        let mut bad_tag = tag;
+        bad_tag[TAG_LEN - 1] ^= 0x80;
+        let mut nonce = NONCE;
+        nonce[0] ^= 0x01;
+        assert!(rejected(&aead, &NONCE, b"aae", &tag));
+        assert!(rejected(&aead, &NONCE, b"aad", &bad_tag));
+        assert!(rejected(&aead, &nonce, b"aad", &tag));
+        assert!(rejected(&MockAead::new([0; KEY_LEN]), &NONCE, b"aad", &tag));
+    }
+
+    #[test]
+    fn hal_aead_round_trip() {
+        let mut device = MockCipherDevice::new();
+        let mut ctx = device.init(&KEY, &NONCE).unwrap();
+
+        let mut plaintext = [0u8; BUFFER_LEN];
+        plaintext[..5].copy_from_slice(b"fw v2");
+        let (ciphertext, tag) = ctx.encrypt_aead(plaintext, b"slot-b").unwrap();
+        assert_eq!(ctx.decrypt_aead(ciphertext, b"slot-b", tag), Ok(plaintext));
+        assert_eq!(
+            ctx.decrypt_aead(ciphertext, b"slot-a", tag)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidInput
+        );
+
+        // Matches the in-place API under the same key and nonce
+        let mut data = plaintext;
+        assert_eq!(
+            MockAead::new(KEY).encrypt_in_place(&NONCE, b"slot-b", &mut data),
+            tag
+        );
+        assert_eq!(data, ciphertext);
+    }
+}
@@ -0,0 +1,186 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mock GPIO Implementation
+//!
+//! Provides a stub implementation of the per-pin GPIO trait. Tests preset the
+//! level seen on each input with [`MockGpio::set_input`] and observe what the
+//! code under test drove with [`MockGpio::output`].
+
+use openprot_hal_blocking::gpio_port::{
+    Gpio, GpioError, GpioErrorKind, GpioErrorType, Level, PinMode,
+};
+
+/// Default number of pins on a [`MockGpio`]
+pub const DEFAULT_PINS: usize = 32;
+
+/// Mock GPIO error type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockGpioError {
+    /// Pin number is out of range
+    BadArgument,
+    /// Pin is not configured as an output
+    InvalidMode,
+}
+
+impl GpioError for MockGpioError {
+    fn kind(&self) -> GpioErrorKind {
+        match self {
+            MockGpioError::BadArgument => GpioErrorKind::InvalidPin,
+            MockGpioError::InvalidMode => GpioErrorKind::InvalidMode,
+        }
+    }
+}
+
+/// State of one mock pin
+#[derive(Debug, Clone, Copy)]
+struct PinState {
+    mode: PinMode,
+    /// Level presented on the line by the outside world
+    input: Level,
+    /// Last level driven by the code under test, if any
+    output: Option<Level>,
+}
+
+/// Mock GPIO bank with `PINS` pins, all initially inputs reading low
+#[derive(Debug, Clone)]
+pub struct MockGpio<const PINS: usize = DEFAULT_PINS> {
+    pins: [PinState; PINS],
+}
+
+impl<const PINS: usize> MockGpio<PINS> {
+    /// Create a bank with every pin an input reading low
+    pub const fn new() -> Self {
+        Self {
+            pins: [PinState {
+                mode: PinMode::Input,
+                input: Level::Low,
+                output: None,
+            }; PINS],
+        }
+    }
+
+    /// Preset the level the outside world presents on `pin`
+    pub fn set_input(&mut self, pin: u32, level: Level) -> Result<(), MockGpioError> {
+        self.pin_mut(pin)?.input = level;
+        Ok(())
+    }
+
+    /// Last level driven on `pin`, or `None` if it was never written
+    pub fn output(&self, pin: u32) -> Result<Option<Level>, MockGpioError> {
+        Ok(self.pin(pin)?.output)
+    }
+
+    /// Current mode of `pin`
+    pub fn mode(&self, pin: u32) -> Result<PinMode, MockGpioError> {
+        Ok(self.pin(pin)?.mode)
+    }
+
+    fn pin(&self, pin: u32) -> Result<&PinState, MockGpioError> {
+        usize::try_from(pin)
+            .ok()
+            .and_then(|index| self.pins.get(index))
+            .ok_or(MockGpioError::BadArgument)
+    }
+
+    fn pin_mut(&mut self, pin: u32) -> Result<&mut PinState, MockGpioError> {
+        usize::try_from(pin)
+            .ok()
+            .and_then(|index| self.pins.get_mut(index))
+            .ok_or(MockGpioError::BadArgument)
+    }
+}
+
+impl<const PINS: usize> Default for MockGpio<PINS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const PINS: usize> GpioErrorType for MockGpio<PINS> {
+    type Error = MockGpioError;
+}
+
+impl<const PINS: usize> Gpio for MockGpio<PINS> {
+    fn configure(&mut self, pin: u32, mode: PinMode) -> Result<(), Self::Error> {
+        self.pin_mut(pin)?.mode = mode;
+        Ok(())
+    }
+
+    fn set(&mut self, pin: u32, level: Level) -> Result<(), Self::Error> {
+        let state = self.pin_mut(pin)?;
+        if state.mode == PinMode::Input {
+            return Err(MockGpioError::InvalidMode);
+        }
+        state.output = Some(level);
+        Ok(())
+    }
+
+    fn get(&self, pin: u32) -> Result<Level, Self::Error> {
+        let state = self.pin(pin)?;
+        Ok(match (state.mode, state.output) {
+            (PinMode::Output, Some(level)) => level,
+            // A driven-low open-drain pin holds the line low; released, the
+            // line follows whatever is on it externally.
+            (PinMode::OpenDrain, Some(Level::Low)) => Level::Low,
+            _ => state.input,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_writes_are_observable() {
+        let mut gpio = MockGpio::<DEFAULT_PINS>::new();
+        gpio.configure(3, PinMode::Output).unwrap();
+        assert_eq!(gpio.output(3).unwrap(), None);
+
+        gpio.set(3, Level::High).unwrap();
+        assert_eq!(gpio.output(3).unwrap(), Some(Level::High));
+        assert_eq!(gpio.get(3).unwrap(), Level::High);
+
+        gpio.set(3, Level::Low).unwrap();
+        assert_eq!(gpio.output(3).unwrap(), Some(Level::Low));
+    }
+
+    #[test]
+    fn preset_input_is_read_back() {
+        let mut gpio = MockGpio::<DEFAULT_PINS>::new();
+        assert_eq!(gpio.get(7).unwrap(), Level::Low);
+        gpio.set_input(7, Level::High).unwrap();
+        assert_eq!(gpio.get(7).unwrap(), Level::High);
+
+        // Inputs cannot be driven.
+        assert_eq!(gpio.set(7, Level::Low), Err(MockGpioError::InvalidMode));
+    }
+
+    #[test]
+    fn open_drain_reads_the_line() {
+        let mut gpio = MockGpio::<DEFAULT_PINS>::new();
+        gpio.configure(0, PinMode::OpenDrain).unwrap();
+        gpio.set_input(0, Level::High).unwrap();
+
+        gpio.set(0, Level::Low).unwrap();
+        assert_eq!(gpio.get(0).unwrap(), Level::Low);
+
+        // Released, but another device holds the line low.
+        gpio.set(0, Level::High).unwrap();
+        gpio.set_input(0, Level::Low).unwrap();
+        assert_eq!(gpio.get(0).unwrap(), Level::Low);
+    }
+
+    #[test]
+    fn out_of_range_pins_are_rejected() {
+        let mut gpio = MockGpio::<4>::new();
+        assert_eq!(
+            gpio.configure(4, PinMode::Output),
+            Err(MockGpioError::BadArgument)
+        );
+        assert_eq!(gpio.set(4, Level::High), Err(MockGpioError::BadArgument));
+        assert_eq!(gpio.get(u32::MAX), Err(MockGpioError::BadArgument));
+        assert_eq!(gpio.get(4).unwrap_err().kind(), GpioErrorKind::InvalidPin);
+    }
+}
@@ -9,6 +9,10 @@
 //! This module demonstrates both the scoped and owned digest APIs:
 //! - **Scoped API**: Traditional lifetime-constrained contexts for simple use cases
 //! - **Owned API**: Move-based resource management for server applications
+//!
+//! The [`software`] module adds a streaming hasher and HMAC producing real
+//! SHA-2 output, for tests that need genuine digests and tags, and exposes
+//! them through the same scoped digest and MAC traits.
 
 // Allow security lints for mock/test code
 #![allow(clippy::unwrap_used)]
@@ -235,10 +239,473 @@ pub mod owned {
     impl_owned_sha2!(Sha2_512);
 }
 
+//
+// STREAMING SOFTWARE HASHER
+//
+
+/// Software hashing and HMAC that produce real SHA-2 output.
+///
+/// The accelerator stubs above return fake, length-derived digests. Code
+/// that checks measurements or compares chunked against single-shot results
+/// needs genuine output, which this module provides via the RustCrypto
+/// `sha2` crate. [`SoftwareDigestDevice`] and [`SoftwareMacDevice`] expose
+/// the same computations through the scoped HAL traits.
+pub mod software {
+    use super::MockDigestError;
+    use openprot_hal_blocking::digest::scoped::{DigestInit, DigestOp};
+    use openprot_hal_blocking::digest::{DigestAlgorithm, ErrorType, Sha2_256, Sha2_384, Sha2_512};
+    use openprot_hal_blocking::mac::scoped::{MacInit, MacOp};
+    use openprot_hal_blocking::mac::{
+        self, HmacSha2_256, HmacSha2_384, HmacSha2_512, KeyHandle, MacAlgorithm,
+    };
+    use sha2::{Digest as _, Sha256, Sha384, Sha512};
+    use subtle::ConstantTimeEq;
+
+    /// Hash algorithms supported by [`SoftwareHasher`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum HashAlgorithm {
+        /// SHA-256 (32-byte digest)
+        Sha256,
+        /// SHA-384 (48-byte digest)
+        Sha384,
+        /// SHA-512 (64-byte digest)
+        Sha512,
+    }
+
+    impl HashAlgorithm {
+        /// Digest length in bytes.
+        pub const fn digest_len(self) -> usize {
+            match self {
+                Self::Sha256 => 32,
+                Self::Sha384 => 48,
+                Self::Sha512 => 64,
+            }
+        }
+
+        /// Internal block length in bytes, as used by HMAC key padding.
+        pub const fn block_len(self) -> usize {
+            match self {
+                Self::Sha256 => 64,
+                Self::Sha384 | Self::Sha512 => 128,
+            }
+        }
+    }
+
+    /// Largest digest produced by any [`HashAlgorithm`], in bytes.
+    pub const MAX_DIGEST_LEN: usize = 64;
+
+    /// Largest block length of any [`HashAlgorithm`], in bytes.
+    const MAX_BLOCK_LEN: usize = 128;
+
+    /// A digest whose length is set by the algorithm that produced it.
+    ///
+    /// Equality is evaluated in constant time over the digest bytes, so
+    /// comparing a computed measurement against an expected value does not
+    /// leak how many leading bytes matched. Digests of different lengths
+    /// always compare unequal.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Digest {
+        algorithm: HashAlgorithm,
+        bytes: [u8; MAX_DIGEST_LEN],
+    }
+
+    impl Digest {
+        /// Wrap an expected digest value, e.g. a reference measurement.
+        ///
+        /// Fails if `bytes` is not exactly `algorithm.digest_len()` long.
+        pub fn from_bytes(algorithm: HashAlgorithm, bytes: &[u8]) -> Result<Self, MockDigestError> {
+            if bytes.len() != algorithm.digest_len() {
+                return Err(MockDigestError);
+            }
+            let mut value = [0u8; MAX_DIGEST_LEN];
+            value[..bytes.len()].copy_from_slice(bytes);
+            Ok(Self {
+                algorithm,
+                bytes: value,
+            })
+        }
+
+        /// Algorithm that produced this digest.
+        pub fn algorithm(&self) -> HashAlgorithm {
+            self.algorithm
+        }
+
+        /// Digest bytes, exactly `algorithm().digest_len()` long.
+        pub fn as_bytes(&self) -> &[u8] {
+            &self.bytes[..self.algorithm.digest_len()]
+        }
+    }
+
+    impl AsRef<[u8]> for Digest {
+        fn as_ref(&self) -> &[u8] {
+            self.as_bytes()
+        }
+    }
+
+    impl PartialEq for Digest {
+        fn eq(&self, other: &Self) -> bool {
+            ct_eq(self, other)
+        }
+    }
+
+    impl Eq for Digest {}
+
+    /// Compare two digests in constant time.
+    ///
+    /// The running time does not depend on where the first differing byte
+    /// is, so comparing a measurement against a golden value leaks nothing
+    /// about how much of it matched. Callers must use this (or `==`, which
+    /// delegates here) rather than comparing `as_bytes()` slices, which
+    /// exits at the first mismatch.
+    ///
+    /// Digests of different lengths are never equal; the length check is
+    /// not constant-time, as digest lengths are public.
+    pub fn ct_eq(a: &Digest, b: &Digest) -> bool {
+        a.as_bytes().ct_eq(b.as_bytes()).into()
+    }
+
+    /// Hash `data` in one shot.
+    pub fn digest(algorithm: HashAlgorithm, data: &[u8]) -> Digest {
+        let mut hasher = SoftwareHasher::new(algorithm);
+        hasher.update(data);
+        hasher.finalize()
+    }
+
+    /// Incremental hashing: feed data in any number of chunks, then finalize.
+    ///
+    /// Hashing the same bytes in one call or split across several `update`
+    /// calls yields the same digest.
+    pub trait Hasher: Sized {
+        /// Start a new hash computation.
+        fn new(algorithm: HashAlgorithm) -> Self;
+
+        /// Absorb the next chunk of input.
+        fn update(&mut self, data: &[u8]);
+
+        /// Finish the computation and return the digest.
+        fn finalize(self) -> Digest;
+    }
+
+    /// [`Hasher`] backed by the RustCrypto `sha2` crate.
+    #[derive(Clone)]
+    pub struct SoftwareHasher {
+        state: HasherState,
+    }
+
+    #[derive(Clone)]
+    enum HasherState {
+        Sha256(Sha256),
+        Sha384(Sha384),
+        Sha512(Sha512),
+    }
+
+    impl Hasher for SoftwareHasher {
+        fn new(algorithm: HashAlgorithm) -> Self {
+            let state = match algorithm {
+                HashAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+                HashAlgorithm::Sha384 => HasherState::Sha384(Sha384::new()),
+                HashAlgorithm::Sha512 => HasherState::Sha512(Sha512::new()),
+            };
+            Self { state }
+        }
+
+        fn update(&mut self, data: &[u8]) {
+            match &mut self.state {
+                HasherState::Sha256(h) => h.update(data),
+                HasherState::Sha384(h) => h.update(data),
+                HasherState::Sha512(h) => h.update(data),
+            }
+        }
+
+        fn finalize(self) -> Digest {
+            let mut bytes = [0u8; MAX_DIGEST_LEN];
+            let algorithm = match self.state {
+                HasherState::Sha256(h) => {
+                    bytes[..32].copy_from_slice(&h.finalize());
+                    HashAlgorithm::Sha256
+                }
+                HasherState::Sha384(h) => {
+                    bytes[..48].copy_from_slice(&h.finalize());
+                    HashAlgorithm::Sha384
+                }
+                HasherState::Sha512(h) => {
+                    bytes[..64].copy_from_slice(&h.finalize());
+                    HashAlgorithm::Sha512
+                }
+            };
+            Digest { algorithm, bytes }
+        }
+    }
+
+    /// Keyed message authentication (HMAC, RFC 2104) over a [`HashAlgorithm`].
+    pub trait Hmac: Sized {
+        /// Start a new MAC computation with `key`.
+        ///
+        /// Keys longer than the algorithm's block length are hashed first.
+        fn new(algorithm: HashAlgorithm, key: &[u8]) -> Self;
+
+        /// Absorb the next chunk of the message.
+        fn update(&mut self, data: &[u8]);
+
+        /// Finish the computation and write the tag to the start of `out`.
+        ///
+        /// Returns the tag length, or an error if `out` is shorter than the
+        /// algorithm's digest length.
+        fn finalize_into(self, out: &mut [u8]) -> Result<usize, MockDigestError>;
+    }
+
+    /// [`Hmac`] built on [`SoftwareHasher`].
+    #[derive(Clone)]
+    pub struct SoftwareHmac {
+        algorithm: HashAlgorithm,
+        inner: SoftwareHasher,
+        /// Key block XORed with the outer pad, absorbed at finalization.
+        outer_pad: [u8; MAX_BLOCK_LEN],
+    }
+
+    impl Hmac for SoftwareHmac {
+        fn new(algorithm: HashAlgorithm, key: &[u8]) -> Self {
+            let block_len = algorithm.block_len();
+            let mut key_block = [0u8; MAX_BLOCK_LEN];
+            if key.len() > block_len {
+                let mut hasher = SoftwareHasher::new(algorithm);
+                hasher.update(key);
+                let digest = hasher.finalize();
+                key_block[..digest.as_bytes().len()].copy_from_slice(digest.as_bytes());
+            } else {
+                key_block[..key.len()].copy_from_slice(key);
+            }
+
+            let mut inner_pad = [0u8; MAX_BLOCK_LEN];
+            let mut outer_pad = [0u8; MAX_BLOCK_LEN];
+            for ((ipad, opad), k) in inner_pad.iter_mut().zip(&mut outer_pad).zip(key_block) {
+                *ipad = k ^ 0x36;
+                *opad = k ^ 0x5c;
+            }
+
+            let mut inner = SoftwareHasher::new(algorithm);
+            inner.update(&inner_pad[..block_len]);
+            Self {
+                algorithm,
+                inner,
+                outer_pad,
+            }
+        }
+
+        fn update(&mut self, data: &[u8]) {
+            self.inner.update(data);
+        }
+
+        fn finalize_into(self, out: &mut [u8]) -> Result<usize, MockDigestError> {
+            let tag_len = self.algorithm.digest_len();
+            let out = out.get_mut(..tag_len).ok_or(MockDigestError)?;
+
+            let inner_digest = self.inner.finalize();
+            let mut outer = SoftwareHasher::new(self.algorithm);
+            outer.update(&self.outer_pad[..self.algorithm.block_len()]);
+            outer.update(inner_digest.as_bytes());
+            out.copy_from_slice(outer.finalize().as_bytes());
+            Ok(tag_len)
+        }
+    }
+
+    /// Longest key accepted by [`MockMacKey`], in bytes.
+    ///
+    /// Twice the largest block length, so keys that HMAC hashes down
+    /// first (RFC 4231 test case 6) are covered.
+    pub const MAX_KEY_LEN: usize = 2 * MAX_BLOCK_LEN;
+
+    /// Pack SHA-2 output bytes into digest words, as the RustCrypto backend does.
+    fn to_words<const N: usize>(bytes: &[u8]) -> [u32; N] {
+        let mut value = [0u32; N];
+        for (word, chunk) in value.iter_mut().zip(bytes.chunks_exact(4)) {
+            *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        value
+    }
+
+    /// Software digest device producing real SHA-2 digests.
+    #[derive(Default)]
+    pub struct SoftwareDigestDevice;
+
+    impl SoftwareDigestDevice {
+        /// Create a new software digest device
+        pub fn new() -> Self {
+            Self
+        }
+    }
+
+    impl ErrorType for SoftwareDigestDevice {
+        type Error = MockDigestError;
+    }
+
+    /// Hashing context for the HAL algorithm `A`, forwarding to a
+    /// [`SoftwareHasher`].
+    pub struct SoftwareDigestContext<'a, A> {
+        #[allow(dead_code)] // Ties the context to the device borrow
+        hw: &'a mut SoftwareDigestDevice,
+        _alg: A,
+        hasher: SoftwareHasher,
+    }
+
+    impl<A> ErrorType for SoftwareDigestContext<'_, A> {
+        type Error = MockDigestError;
+    }
+
+    /// Macro to implement software digest traits for each algorithm
+    macro_rules! impl_software_sha2 {
+        ($algo:ident, $alg:ident) => {
+            impl DigestInit<$algo> for SoftwareDigestDevice {
+                type OpContext<'a> = SoftwareDigestContext<'a, $algo>;
+
+                fn init(&mut self, algorithm: $algo) -> Result<Self::OpContext<'_>, Self::Error> {
+                    Ok(SoftwareDigestContext {
+                        hw: self,
+                        _alg: algorithm,
+                        hasher: SoftwareHasher::new(HashAlgorithm::$alg),
+                    })
+                }
+            }
+
+            impl DigestOp for SoftwareDigestContext<'_, $algo> {
+                type Output = <$algo as DigestAlgorithm>::Digest;
+
+                fn update(&mut self, input: &[u8]) -> Result<(), Self::Error> {
+                    self.hasher.update(input);
+                    Ok(())
+                }
+
+                fn finalize(self) -> Result<Self::Output, Self::Error> {
+                    let value = to_words(self.hasher.finalize().as_bytes());
+                    Ok(Self::Output { value })
+                }
+            }
+        };
+    }
+
+    impl_software_sha2!(Sha2_256, Sha256);
+    impl_software_sha2!(Sha2_384, Sha384);
+    impl_software_sha2!(Sha2_512, Sha512);
+
+    impl mac::Error for MockDigestError {
+        fn kind(&self) -> mac::ErrorKind {
+            mac::ErrorKind::HardwareFailure
+        }
+    }
+
+    /// HMAC key of any length up to [`MAX_KEY_LEN`].
+    #[derive(Clone)]
+    pub struct MockMacKey {
+        bytes: [u8; MAX_KEY_LEN],
+        len: usize,
+    }
+
+    impl MockMacKey {
+        /// Copy `key` into a new key handle.
+        ///
+        /// # Returns
+        /// - `Ok(MockMacKey)` if the key fits
+        /// - `Err(ErrorKind::InvalidInputLength)` if it is longer than [`MAX_KEY_LEN`]
+        pub fn from_slice(key: &[u8]) -> Result<Self, mac::ErrorKind> {
+            if key.len() > MAX_KEY_LEN {
+                return Err(mac::ErrorKind::InvalidInputLength);
+            }
+            let mut bytes = [0u8; MAX_KEY_LEN];
+            bytes[..key.len()].copy_from_slice(key);
+            Ok(Self {
+                bytes,
+                len: key.len(),
+            })
+        }
+
+        /// Key bytes.
+        pub fn as_bytes(&self) -> &[u8] {
+            &self.bytes[..self.len]
+        }
+    }
+
+    impl KeyHandle for MockMacKey {}
+
+    /// Software MAC device computing HMAC (RFC 2104) over SHA-2.
+    #[derive(Default)]
+    pub struct SoftwareMacDevice;
+
+    impl SoftwareMacDevice {
+        /// Create a new software MAC device
+        pub fn new() -> Self {
+            Self
+        }
+    }
+
+    impl mac::ErrorType for SoftwareMacDevice {
+        type Error = MockDigestError;
+    }
+
+    /// MAC context for the HAL algorithm `A`, forwarding to a [`SoftwareHmac`].
+    pub struct SoftwareMacContext<'a, A> {
+        #[allow(dead_code)] // Ties the context to the device borrow
+        hw: &'a mut SoftwareMacDevice,
+        _alg: A,
+        hmac: SoftwareHmac,
+    }
+
+    impl<A> mac::ErrorType for SoftwareMacContext<'_, A> {
+        type Error = MockDigestError;
+    }
+
+    /// Macro to implement software HMAC traits for each algorithm
+    macro_rules! impl_software_hmac {
+        ($algo:ident, $alg:ident) => {
+            impl MacInit<$algo> for SoftwareMacDevice {
+                type Key = MockMacKey;
+                type OpContext<'a> = SoftwareMacContext<'a, $algo>;
+
+                fn init<'a>(
+                    &'a mut self,
+                    algo: $algo,
+                    key: Self::Key,
+                ) -> Result<Self::OpContext<'a>, Self::Error> {
+                    Ok(SoftwareMacContext {
+                        hw: self,
+                        _alg: algo,
+                        hmac: SoftwareHmac::new(HashAlgorithm::$alg, key.as_bytes()),
+                    })
+                }
+            }
+
+            impl MacOp for SoftwareMacContext<'_, $algo> {
+                type Output = <$algo as MacAlgorithm>::MacOutput;
+
+                fn update(&mut self, input: &[u8]) -> Result<(), Self::Error> {
+                    self.hmac.update(input);
+                    Ok(())
+                }
+
+                fn finalize(self) -> Result<Self::Output, Self::Error> {
+                    let mut tag = [0u8; MAX_DIGEST_LEN];
+                    let len = self.hmac.finalize_into(&mut tag)?;
+                    let value = to_words(&tag[..len]);
+                    Ok(Self::Output { value })
+                }
+            }
+        };
+    }
+
+    impl_software_hmac!(HmacSha2_256, Sha256);
+    impl_software_hmac!(HmacSha2_384, Sha384);
+    impl_software_hmac!(HmacSha2_512, Sha512);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use openprot_hal_blocking::digest::{Digest, Sha2_256};
+    use crate::hash::software::{
+        HashAlgorithm, Hmac, MAX_KEY_LEN, MockMacKey, SoftwareDigestDevice, SoftwareHmac,
+        SoftwareMacDevice, digest,
+    };
+    use openprot_hal_blocking::digest::Digest;
+    use openprot_hal_blocking::mac::scoped::{MacInit, MacOp};
+    use openprot_hal_blocking::mac::{HmacSha2_256, HmacSha2_384, HmacSha2_512, MacAlgorithm};
 
     #[test]
     fn test_scoped_api() {
@@ -349,4 +816,265 @@ mod tests {
         let result2 = manager.finalize_session().unwrap();
         assert_eq!(result2.value[0], 0x87654321); // 0 bytes processed
     }
+
+    #[test]
+    fn test_software_hasher_chunked_matches_single_shot() {
+        use crate::hash::software::{HashAlgorithm, Hasher, SoftwareHasher};
+
+        let data: [u8; 200] = core::array::from_fn(|i| i as u8);
+
+        for algorithm in [
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha384,
+            HashAlgorithm::Sha512,
+        ] {
+            let mut single = SoftwareHasher::new(algorithm);
+            single.update(&data);
+            let single = single.finalize();
+
+            // Uneven chunks that straddle the block boundaries
+            let mut chunked = SoftwareHasher::new(algorithm);
+            chunked.update(&data[..7]);
+            chunked.update(&data[7..130]);
+            chunked.update(&data[130..]);
+            let chunked = chunked.finalize();
+
+            assert_eq!(single, chunked);
+            assert_eq!(single.as_bytes().len(), algorithm.digest_len());
+        }
+    }
+
+    /// Decode a hex test vector.
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    #[test]
+    fn test_software_hmac_rfc4231_sha256() {
+        use crate::hash::software::{HashAlgorithm, Hmac, SoftwareHmac};
+
+        // RFC 4231 test cases 1, 2, 3, 4 and 6 (key longer than block size)
+        let case4_key: [u8; 25] = core::array::from_fn(|i| i as u8 + 1);
+        let vectors: [(&[u8], &[u8], &str); 5] = [
+            (
+                &[0x0b; 20],
+                b"Hi There",
+                "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+            ),
+            (
+                b"Jefe",
+                b"what do ya want for nothing?",
+                "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            ),
+            (
+                &[0xaa; 20],
+                &[0xdd; 50],
+                "773ea91e36800e46854db8ebd09181a72959098b3ef8c122d9635514ced565fe",
+            ),
+            (
+                &case4_key,
+                &[0xcd; 50],
+                "82558a389a443c0ea4cc819899f2083a85f0faa3e578f8077a2e3ff46729665b",
+            ),
+            (
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First",
+                "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+            ),
+        ];
+
+        for (key, data, expected) in vectors {
+            let mut mac = SoftwareHmac::new(HashAlgorithm::Sha256, key);
+            mac.update(data);
+            let mut tag = [0u8; 32];
+            assert_eq!(mac.finalize_into(&mut tag).unwrap(), 32);
+            assert_eq!(tag, from_hex::<32>(expected));
+        }
+    }
+
+    #[test]
+    fn test_software_hmac_sha384_chunked() {
+        use crate::hash::software::{HashAlgorithm, Hmac, SoftwareHmac};
+
+        // RFC 4231 test case 2, message fed in two chunks
+        let mut mac = SoftwareHmac::new(HashAlgorithm::Sha384, b"Jefe");
+        mac.update(b"what do ya want ");
+        mac.update(b"for nothing?");
+        let mut tag = [0u8; 64];
+        assert_eq!(mac.finalize_into(&mut tag).unwrap(), 48);
+        assert_eq!(
+            tag[..48],
+            from_hex::<48>(
+                "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47\
+                 e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649"
+            )
+        );
+    }
+
+    #[test]
+    fn test_software_hmac_short_output_rejected() {
+        use crate::hash::software::{HashAlgorithm, Hmac, SoftwareHmac};
+
+        let mac = SoftwareHmac::new(HashAlgorithm::Sha256, b"key");
+        let mut tag = [0u8; 16];
+        assert!(mac.finalize_into(&mut tag).is_err());
+    }
+
+    #[test]
+    fn test_digest_known_answers() {
+        use crate::hash::software::{HashAlgorithm, digest};
+
+        // FIPS 180-2 "abc" test vectors
+        assert_eq!(
+            digest(HashAlgorithm::Sha256, b"abc").as_bytes(),
+            from_hex::<32>("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+        );
+        assert_eq!(
+            digest(HashAlgorithm::Sha384, b"abc").as_bytes(),
+            from_hex::<48>(
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded163\
+                 1a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+            )
+        );
+        assert_eq!(
+            digest(HashAlgorithm::Sha512, b"abc").as_bytes(),
+            from_hex::<64>(
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                 2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            )
+        );
+    }
+
+    #[test]
+    fn test_digest_comparison() {
+        use crate::hash::software::{Digest, HashAlgorithm, digest};
+
+        let measured = digest(HashAlgorithm::Sha256, b"firmware image");
+        let expected = Digest::from_bytes(HashAlgorithm::Sha256, measured.as_bytes()).unwrap();
+        assert_eq!(measured, expected);
+        assert_ne!(measured, digest(HashAlgorithm::Sha256, b"tampered image"));
+
+        // Same leading bytes, different length: never equal
+        let mut longer = [0u8; 48];
+        longer[..32].copy_from_slice(measured.as_bytes());
+        let longer = Digest::from_bytes(HashAlgorithm::Sha384, &longer).unwrap();
+        assert_ne!(measured, longer);
+
+        // Wrong-length expected values are rejected outright
+        assert!(Digest::from_bytes(HashAlgorithm::Sha512, measured.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_digest_ct_eq() {
+        use crate::hash::software::{Digest, HashAlgorithm, ct_eq, digest};
+
+        let golden = digest(HashAlgorithm::Sha256, b"firmware image");
+        let same = Digest::from_bytes(HashAlgorithm::Sha256, golden.as_bytes()).unwrap();
+        assert!(ct_eq(&golden, &same));
+
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(golden.as_bytes());
+        bytes[0] ^= 0x01;
+        let first_differs = Digest::from_bytes(HashAlgorithm::Sha256, &bytes).unwrap();
+        assert!(!ct_eq(&golden, &first_differs));
+
+        bytes.copy_from_slice(golden.as_bytes());
+        bytes[31] ^= 0x80;
+        let last_differs = Digest::from_bytes(HashAlgorithm::Sha256, &bytes).unwrap();
+        assert!(!ct_eq(&golden, &last_differs));
+
+        let mut longer = [0u8; 64];
+        longer[..32].copy_from_slice(golden.as_bytes());
+        let longer = Digest::from_bytes(HashAlgorithm::Sha512, &longer).unwrap();
+        assert!(!ct_eq(&golden, &longer));
+        assert!(!ct_eq(&longer, &golden));
+    }
+
+    /// Hash `chunks` in order with the software digest device.
+    fn software_digest<A: DigestAlgorithm>(algorithm: A, chunks: &[&[u8]]) -> A::Digest
+    where
+        SoftwareDigestDevice: DigestInit<A>,
+    {
+        let mut device = SoftwareDigestDevice::new();
+        let mut ctx = device.init(algorithm).unwrap();
+        for chunk in chunks {
+            ctx.update(chunk).unwrap();
+        }
+        ctx.finalize().unwrap()
+    }
+
+    /// MAC `chunks` in order with the software MAC device.
+    fn software_hmac<A: MacAlgorithm>(algorithm: A, key: &[u8], chunks: &[&[u8]]) -> A::MacOutput
+    where
+        SoftwareMacDevice: MacInit<A, Key = MockMacKey>,
+    {
+        let mut device = SoftwareMacDevice::new();
+        let key = MockMacKey::from_slice(key).unwrap();
+        let mut ctx = device.init(algorithm, key).unwrap();
+        for chunk in chunks {
+            ctx.update(chunk).unwrap();
+        }
+        ctx.finalize().unwrap()
+    }
+
+    #[test]
+    fn test_software_digest_device_matches_digest() {
+        let data: [u8; 200] = core::array::from_fn(|i| i as u8);
+        // Uneven chunks that straddle the block boundaries
+        let chunks: [&[u8]; 3] = [&data[..7], &data[7..130], &data[130..]];
+
+        assert_eq!(
+            software_digest(Sha2_256, &chunks).as_bytes(),
+            digest(HashAlgorithm::Sha256, &data).as_bytes()
+        );
+        assert_eq!(
+            software_digest(Sha2_384, &chunks).as_bytes(),
+            digest(HashAlgorithm::Sha384, &data).as_bytes()
+        );
+        assert_eq!(
+            software_digest(Sha2_512, &chunks).as_bytes(),
+            digest(HashAlgorithm::Sha512, &data).as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_software_mac_device_matches_hmac() {
+        // Longer than every block length, so the key is hashed first
+        let key = [0xaa; 131];
+        let data = b"what do ya want for nothing?";
+        let chunks: [&[u8]; 2] = [&data[..9], &data[9..]];
+        let hmac = |algorithm| {
+            let mut mac = SoftwareHmac::new(algorithm, &key);
+            mac.update(data);
+            let mut tag = [0u8; 64];
+            let len = mac.finalize_into(&mut tag).unwrap();
+            (tag, len)
+        };
+
+        let (tag, len) = hmac(HashAlgorithm::Sha256);
+        assert_eq!(
+            software_hmac(HmacSha2_256, &key, &chunks).as_bytes(),
+            &tag[..len]
+        );
+        let (tag, len) = hmac(HashAlgorithm::Sha384);
+        assert_eq!(
+            software_hmac(HmacSha2_384, &key, &chunks).as_bytes(),
+            &tag[..len]
+        );
+        let (tag, len) = hmac(HashAlgorithm::Sha512);
+        assert_eq!(
+            software_hmac(HmacSha2_512, &key, &chunks).as_bytes(),
+            &tag[..len]
+        );
+    }
+
+    #[test]
+    fn test_software_hmac_oversized_key_rejected() {
+        assert!(MockMacKey::from_slice(&[0xaa; MAX_KEY_LEN]).is_ok());
+        assert!(MockMacKey::from_slice(&[0xaa; MAX_KEY_LEN + 1]).is_err());
+    }
 }
@@ -14,6 +14,13 @@
 //! - **Configurable behavior**: Success/failure modes for testing error paths
 //! - **Event simulation**: Inject and poll I2C slave events for testing
 //! - **Buffer management**: Realistic slave receive/transmit buffer simulation
+//! - **SMBus PEC**: Packet error code generation and validation on master transfers
+//! - **Clock stretching**: Per-address stretch delays checked against a bus timeout
+//! - **Bus recovery**: Stuck-SDA injection cleared by the 9-clock recovery routine
+//! - **7-bit and 10-bit addressing**: Master transfers in both address modes
+//! - **Transaction trace**: Opt-in ordered record of every master transfer segment
+//! - **Failure injection**: Scripted per-transfer outcomes (NACK, arbitration loss, timeout)
+//! - **Clock gating**: The system-control wrapper rejects transfers while its clock is gated
 //! - **No external dependencies**: Uses only core Rust and OpenPRoT traits
 //! - **Production testing**: Comprehensive test suite with 20+ test cases
 //!
@@ -46,6 +53,26 @@
 //! }
 //! ```
 //!
+//! ## 10-bit Addressing
+//!
+//! `I2cMaster` uses 7-bit addresses. 10-bit transfers go through the
+//! `I2cMasterTenBit` methods, which take a `TenBitAddress` (`u16`); wrap the
+//! mock in `TenBit` to pass it to a driver written against
+//! `I2cMaster<TenBitAddress>`.
+//!
+//! ```text
+//! use openprot_platform_mock::i2c_hardware::MockI2cHardware;
+//! use openprot_hal_blocking::i2c_hardware::{I2cAddr, I2cMasterTenBit};
+//!
+//! let mut mock = MockI2cHardware::new();
+//!
+//! // 0x150 is a 10-bit address; 0x50 is an unrelated 7-bit one
+//! match mock.write_ten_bit(0x150, &[0x01]) {
+//!     Ok(()) => assert_eq!(mock.last_master_address(), Some(I2cAddr::Ten(0x150))),
+//!     Err(_) => return,
+//! }
+//! ```
+//!
 //! ## Slave Mode Testing
 //!
 //! ```text
@@ -164,8 +191,12 @@
 //! }
 //! ```
 
-use embedded_hal::i2c::{ErrorType, Operation, SevenBitAddress};
-use openprot_hal_blocking::i2c_hardware::{I2cBusRecovery, I2cHardwareCore, I2cMaster};
+use embedded_hal::i2c::{ErrorType, Operation, SevenBitAddress, TenBitAddress};
+use openprot_hal_blocking::i2c_hardware::{
+    smbus_pec, I2cAddr, I2cBusRecovery, I2cHardwareCore, I2cMaster, I2cMasterTenBit, I2cSmbusPec,
+    BUS_RECOVERY_CLOCKS,
+};
+use openprot_hal_blocking::system_control::ClockGating;
 
 /// Mock error type for I2C operations
 ///
@@ -196,6 +227,24 @@ pub enum MockI2cError {
     ///
     /// The addressed device did not respond or is not present.
     NoAcknowledge,
+    /// SMBus packet error code mismatch (`PecError`)
+    ///
+    /// The PEC byte received at the end of a read did not match the PEC
+    /// computed over the transfer.
+    Pec,
+    /// Bus timeout
+    ///
+    /// The addressed slave stretched the clock for longer than the
+    /// configured bus timeout.
+    Timeout,
+    /// Invalid argument
+    ///
+    /// An address was out of range for its addressing mode.
+    BadArgument,
+    /// Peripheral clock gated
+    ///
+    /// The controller was used while its clock was turned off.
+    ClockGated,
     /// Other unspecified error
     ///
     /// Catch-all for any other error conditions.
@@ -205,12 +254,15 @@ pub enum MockI2cError {
 impl embedded_hal::i2c::Error for MockI2cError {
     fn kind(&self) -> embedded_hal::i2c::ErrorKind {
         match self {
-            MockI2cError::Bus => embedded_hal::i2c::ErrorKind::Bus,
+            MockI2cError::Bus | MockI2cError::Timeout => embedded_hal::i2c::ErrorKind::Bus,
             MockI2cError::ArbitrationLoss => embedded_hal::i2c::ErrorKind::ArbitrationLoss,
             MockI2cError::NoAcknowledge => embedded_hal::i2c::ErrorKind::NoAcknowledge(
                 embedded_hal::i2c::NoAcknowledgeSource::Unknown,
             ),
-            MockI2cError::Other => embedded_hal::i2c::ErrorKind::Other,
+            MockI2cError::Pec
+            | MockI2cError::BadArgument
+            | MockI2cError::ClockGated
+            | MockI2cError::Other => embedded_hal::i2c::ErrorKind::Other,
         }
     }
 }
@@ -284,16 +336,42 @@ impl Default for MockI2cConfig {
 /// slave_tx_count           |     8        |    8
 /// last_slave_event         |     1        |    1
 /// [padding]                |     7        |    -
+/// master_tx_buffer         |    64        |    1
+/// master_tx_count          |     8        |    8
+/// master_rx_buffer         |    64        |    1
+/// master_rx_count          |     8        |    8
+/// clock_stretch            |    32        |    4
+/// bus_timeout_ticks        |     8        |    4
+/// last_stretch_ticks       |     4        |    4
+/// [padding]                |     4        |    -
+/// sda_stuck_clocks         |     2        |    1
+/// last_recovery_clocks     |     1        |    1
+/// [padding]                |     1        |    -
+/// last_master_address      |     4        |    2
+/// recording                |     1        |    1
+/// trace_overflowed         |     1        |    1
+/// trace                    |   352        |    2
+/// trace_len                |     8        |    8
+/// injected                 |     8        |    1
+/// injected_head            |     1        |    1
+/// injected_len             |     1        |    1
 /// -------------------------|--------------|----------
-/// TOTAL                    |   168 bytes  |    8
+/// TOTAL                    |   728 bytes  |    8
 /// ```
 ///
-/// **Final Size**: 168 bytes per instance (72% reduction from original 608 bytes)
+/// **Final Size**: 728 bytes per instance, 352 of them for the transaction trace
 ///
 /// **Memory Breakdown**:
 /// - Base fields: 16 bytes (config, flags, addresses)
 /// - Slave buffers: 128 bytes (2x 64-byte arrays)
-/// - Counters: 16 bytes (2x usize = 2x 8 bytes on 64-bit)
+/// - Master buffers: 128 bytes (last write and injected read response)
+/// - Counters: 32 bytes (4x usize = 4x 8 bytes on 64-bit)
+/// - Clock stretching: 44 bytes (4-entry stretch table, timeout, last stretch)
+/// - Bus recovery: 3 bytes (stuck-SDA state, last recovery pulse count)
+/// - Address tracking: 4 bytes (last master address)
+/// - Transaction trace: 362 bytes (16x 22-byte entries, length, flags),
+///   partly packed into former padding
+/// - Failure injection: 10 bytes (8-entry outcome ring, head, length)
 /// - Event storage: 1 byte (enum discriminant)
 /// - Padding: 7 bytes (for alignment)
 ///
@@ -341,6 +419,93 @@ pub struct MockI2cHardware {
     slave_tx_count: usize,
     /// Most recent slave event that occurred (1 byte: `Option<enum>`)
     last_slave_event: Option<openprot_hal_blocking::i2c_hardware::slave::I2cIsrEvent>,
+
+    // Master mode fields
+    /// Bytes put on the bus by the most recent master write (64 bytes),
+    /// including any appended PEC byte
+    master_tx_buffer: [u8; 64],
+    /// Number of valid bytes in the master write record (8 bytes: usize on 64-bit)
+    master_tx_count: usize,
+    /// Response returned by the addressed device on master reads (64 bytes)
+    master_rx_buffer: [u8; 64],
+    /// Number of valid bytes in the read response (8 bytes: usize on 64-bit)
+    master_rx_count: usize,
+    /// Clock stretch in ticks per slave address (32 bytes: 4x `Option<(I2cAddr, u32)>`)
+    clock_stretch: [Option<(I2cAddr, u32)>; MAX_STRETCHING_SLAVES],
+    /// Longest stretch tolerated before a transfer times out (8 bytes: `Option<u32>`)
+    bus_timeout_ticks: Option<u32>,
+    /// Ticks the most recent master transfer was stretched (4 bytes)
+    last_stretch_ticks: u32,
+    /// SCL pulses a slave holding SDA low needs before releasing it
+    /// (2 bytes: `Option<u8>`, `None` while SDA is free)
+    sda_stuck_clocks: Option<u8>,
+    /// SCL pulses clocked out by the most recent bus recovery (1 byte)
+    last_recovery_clocks: u8,
+    /// Address of the most recent master transfer (4 bytes: `Option<I2cAddr>`)
+    last_master_address: Option<I2cAddr>,
+
+    // Transaction trace
+    /// Whether master transfers are being recorded (1 byte)
+    recording: bool,
+    /// Whether segments were dropped because the trace was full (1 byte)
+    trace_overflowed: bool,
+    /// Recorded transfer segments, oldest first
+    /// (`MAX_TRACE_ENTRIES`x `Option<I2cTraceEntry>`)
+    trace: [Option<I2cTraceEntry>; MAX_TRACE_ENTRIES],
+    /// Number of recorded segments (8 bytes: usize on 64-bit)
+    trace_len: usize,
+
+    // Failure injection
+    /// Scripted outcomes for upcoming master transfers, as a ring
+    /// (`MAX_INJECTED_OUTCOMES` bytes)
+    injected: [Result<(), MockI2cError>; MAX_INJECTED_OUTCOMES],
+    /// Index of the next scripted outcome (1 byte)
+    injected_head: u8,
+    /// Number of scripted outcomes queued (1 byte)
+    injected_len: u8,
+}
+
+/// Maximum number of slave addresses with a configured clock stretch
+pub const MAX_STRETCHING_SLAVES: usize = 4;
+
+/// Maximum number of scripted outcomes queued with `inject_outcome()`
+pub const MAX_INJECTED_OUTCOMES: usize = 8;
+
+/// Maximum number of transfer segments kept in the transaction trace
+pub const MAX_TRACE_ENTRIES: usize = 16;
+
+/// Maximum number of bytes kept per traced transfer segment
+pub const MAX_TRACE_DATA: usize = 16;
+
+/// Direction of a traced transfer segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum I2cDirection {
+    /// Master to slave
+    Write,
+    /// Slave to master
+    Read,
+}
+
+/// One segment of a recorded master transfer
+///
+/// A combined write-then-read transfer is recorded as two segments, one per
+/// direction. `data` holds the bytes as they crossed the bus, including any
+/// SMBus PEC byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct I2cTraceEntry {
+    /// Slave address, with its addressing mode
+    pub addr: I2cAddr,
+    /// Transfer direction
+    pub direction: I2cDirection,
+    data: [u8; MAX_TRACE_DATA],
+    len: u8,
+}
+
+impl I2cTraceEntry {
+    /// The bytes transferred, truncated to `MAX_TRACE_DATA`
+    pub fn data(&self) -> &[u8] {
+        self.data.get(..usize::from(self.len)).unwrap_or(&[])
+    }
 }
 
 impl MockI2cHardware {
@@ -368,6 +533,23 @@ impl MockI2cHardware {
             slave_tx_buffer: [0; 64],
             slave_tx_count: 0,
             last_slave_event: None,
+            master_tx_buffer: [0; 64],
+            master_tx_count: 0,
+            master_rx_buffer: [0; 64],
+            master_rx_count: 0,
+            clock_stretch: [None; MAX_STRETCHING_SLAVES],
+            bus_timeout_ticks: None,
+            last_stretch_ticks: 0,
+            sda_stuck_clocks: None,
+            last_recovery_clocks: 0,
+            last_master_address: None,
+            recording: false,
+            trace_overflowed: false,
+            trace: [None; MAX_TRACE_ENTRIES],
+            trace_len: 0,
+            injected: [Ok(()); MAX_INJECTED_OUTCOMES],
+            injected_head: 0,
+            injected_len: 0,
         }
     }
 
@@ -389,6 +571,23 @@ impl MockI2cHardware {
             slave_tx_buffer: [0; 64],
             slave_tx_count: 0,
             last_slave_event: None,
+            master_tx_buffer: [0; 64],
+            master_tx_count: 0,
+            master_rx_buffer: [0; 64],
+            master_rx_count: 0,
+            clock_stretch: [None; MAX_STRETCHING_SLAVES],
+            bus_timeout_ticks: None,
+            last_stretch_ticks: 0,
+            sda_stuck_clocks: None,
+            last_recovery_clocks: 0,
+            last_master_address: None,
+            recording: false,
+            trace_overflowed: false,
+            trace: [None; MAX_TRACE_ENTRIES],
+            trace_len: 0,
+            injected: [Ok(()); MAX_INJECTED_OUTCOMES],
+            injected_head: 0,
+            injected_len: 0,
         }
     }
 
@@ -413,6 +612,261 @@ impl MockI2cHardware {
         self.initialized
     }
 
+    /// Set the response the addressed device returns on master reads
+    ///
+    /// Read buffers are filled from `data` in order and padded with 0xFF
+    /// once it is exhausted. For SMBus PEC reads the byte following the data
+    /// is the device's PEC. Up to 64 bytes are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// use openprot_platform_mock::i2c_hardware::MockI2cHardware;
+    /// use openprot_hal_blocking::i2c_hardware::I2cSmbusPec;
+    ///
+    /// let mut mock = MockI2cHardware::new();
+    /// // Read Word response 0xCDAB followed by its PEC
+    /// mock.inject_master_read_data(&[0xAB, 0xCD, 0xE4]);
+    ///
+    /// let mut word = [0u8; 2];
+    /// assert!(mock.write_read_with_pec(0x5A, &[0x07], &mut word).is_ok());
+    /// ```
+    pub fn inject_master_read_data(&mut self, data: &[u8]) {
+        let copy_len = data.len().min(self.master_rx_buffer.len());
+        if let (Some(dst_slice), Some(src_slice)) = (
+            self.master_rx_buffer.get_mut(..copy_len),
+            data.get(..copy_len),
+        ) {
+            dst_slice.copy_from_slice(src_slice);
+            self.master_rx_count = copy_len;
+        }
+    }
+
+    /// Bytes put on the bus by the most recent master write
+    ///
+    /// Includes the PEC byte appended by `write_with_pec()`.
+    pub fn last_master_write(&self) -> &[u8] {
+        self.master_tx_buffer
+            .get(..self.master_tx_count)
+            .unwrap_or(&[])
+    }
+
+    /// Make the slave at `addr` stretch the clock for `ticks` on every transfer
+    ///
+    /// `addr` is a 7-bit (`u8`) or 10-bit (`u16`) address; the same number in
+    /// the two modes names different slaves. A stretch of 0 removes the entry.
+    ///
+    /// Returns `MockI2cError::BadArgument` if `addr` is out of range, or
+    /// `MockI2cError::Other` if `MAX_STRETCHING_SLAVES` addresses already have
+    /// a stretch configured.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// use openprot_platform_mock::i2c_hardware::{MockI2cHardware, MockI2cError};
+    /// use openprot_hal_blocking::i2c_hardware::I2cMaster;
+    ///
+    /// let mut mock = MockI2cHardware::new();
+    /// mock.set_bus_timeout(Some(100));
+    ///
+    /// mock.set_clock_stretch(0x50u8, 250).unwrap();
+    /// assert_eq!(mock.write(0x50, &[0x01]), Err(MockI2cError::Timeout));
+    ///
+    /// // The slave releases the clock; retry succeeds
+    /// mock.set_clock_stretch(0x50u8, 0).unwrap();
+    /// assert!(mock.write(0x50, &[0x01]).is_ok());
+    /// ```
+    pub fn set_clock_stretch(
+        &mut self,
+        addr: impl Into<I2cAddr>,
+        ticks: u32,
+    ) -> Result<(), MockI2cError> {
+        let addr = addr.into();
+        if !addr.is_valid() {
+            return Err(MockI2cError::BadArgument);
+        }
+        let existing = self
+            .clock_stretch
+            .iter()
+            .position(|entry| matches!(entry, Some((a, _)) if *a == addr));
+        if ticks == 0 {
+            if let Some(slot) = existing.and_then(|i| self.clock_stretch.get_mut(i)) {
+                *slot = None;
+            }
+            return Ok(());
+        }
+        let index = existing
+            .or_else(|| self.clock_stretch.iter().position(Option::is_none))
+            .ok_or(MockI2cError::Other)?;
+        if let Some(slot) = self.clock_stretch.get_mut(index) {
+            *slot = Some((addr, ticks));
+        }
+        Ok(())
+    }
+
+    /// Clock stretch configured for the slave at `addr`, in ticks
+    pub fn clock_stretch(&self, addr: impl Into<I2cAddr>) -> u32 {
+        let addr = addr.into();
+        self.clock_stretch
+            .iter()
+            .flatten()
+            .find(|(a, _)| *a == addr)
+            .map_or(0, |(_, ticks)| *ticks)
+    }
+
+    /// Set the longest clock stretch a transfer tolerates, in ticks
+    ///
+    /// `None` disables the timeout: stretching slaves are waited for
+    /// indefinitely.
+    pub fn set_bus_timeout(&mut self, ticks: Option<u32>) {
+        self.bus_timeout_ticks = ticks;
+    }
+
+    /// Ticks the most recent master transfer was stretched
+    ///
+    /// Recorded for timed-out transfers as well.
+    pub fn last_stretch_ticks(&self) -> u32 {
+        self.last_stretch_ticks
+    }
+
+    /// Simulate a slave holding SDA low
+    ///
+    /// The slave releases SDA after `release_after` SCL pulses (at least one).
+    /// Until then every master transfer fails with `MockI2cError::Bus`.
+    /// `recover_bus()` clears the condition if `release_after` is within
+    /// `BUS_RECOVERY_CLOCKS`; otherwise the bus stays stuck.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// use openprot_platform_mock::i2c_hardware::{MockI2cHardware, MockI2cError};
+    /// use openprot_hal_blocking::i2c_hardware::{I2cBusRecovery, I2cMaster};
+    ///
+    /// let mut mock = MockI2cHardware::new();
+    /// mock.inject_stuck_sda(3);
+    /// assert_eq!(mock.write(0x50, &[0x01]), Err(MockI2cError::Bus));
+    ///
+    /// assert!(mock.recover_bus().is_ok());
+    /// assert!(mock.write(0x50, &[0x01]).is_ok());
+    /// ```
+    pub fn inject_stuck_sda(&mut self, release_after: u8) {
+        self.sda_stuck_clocks = Some(release_after.max(1));
+    }
+
+    /// Check whether a slave is currently holding SDA low
+    pub fn is_sda_stuck(&self) -> bool {
+        self.sda_stuck_clocks.is_some()
+    }
+
+    /// SCL pulses clocked out by the most recent `recover_bus()`
+    pub fn last_recovery_clocks(&self) -> u8 {
+        self.last_recovery_clocks
+    }
+
+    /// Address of the most recent master transfer, with its addressing mode
+    ///
+    /// Recorded once the address is accepted, including for transfers that
+    /// later fail or time out.
+    pub fn last_master_address(&self) -> Option<I2cAddr> {
+        self.last_master_address
+    }
+
+    /// Script the outcome of the next master transfer not yet scripted
+    ///
+    /// Each master transfer takes the oldest queued outcome: `Err(e)` fails
+    /// the transfer with `e` after the address phase, as for a NACK,
+    /// arbitration loss or timeout; `Ok(())` lets it run normally. Once the
+    /// queue is empty, transfers behave normally again. Out-of-range
+    /// addresses are rejected before an outcome is taken.
+    ///
+    /// Returns `MockI2cError::Other` if `MAX_INJECTED_OUTCOMES` outcomes are
+    /// already queued.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// use openprot_platform_mock::i2c_hardware::{MockI2cHardware, MockI2cError};
+    /// use openprot_hal_blocking::i2c_hardware::I2cMaster;
+    ///
+    /// let mut mock = MockI2cHardware::new();
+    /// mock.inject_outcome(Err(MockI2cError::NoAcknowledge)).unwrap();
+    ///
+    /// assert_eq!(mock.write(0x50, &[0x01]), Err(MockI2cError::NoAcknowledge));
+    /// assert!(mock.write(0x50, &[0x01]).is_ok());
+    /// ```
+    pub fn inject_outcome(
+        &mut self,
+        outcome: Result<(), MockI2cError>,
+    ) -> Result<(), MockI2cError> {
+        let len = usize::from(self.injected_len);
+        if len >= MAX_INJECTED_OUTCOMES {
+            return Err(MockI2cError::Other);
+        }
+        let tail = (usize::from(self.injected_head) + len) % MAX_INJECTED_OUTCOMES;
+        if let Some(slot) = self.injected.get_mut(tail) {
+            *slot = outcome;
+            self.injected_len += 1;
+        }
+        Ok(())
+    }
+
+    /// Number of scripted outcomes not yet taken by a transfer
+    pub fn pending_outcomes(&self) -> usize {
+        usize::from(self.injected_len)
+    }
+
+    /// Discard every scripted outcome not yet taken
+    pub fn clear_outcomes(&mut self) {
+        self.injected_head = 0;
+        self.injected_len = 0;
+    }
+
+    /// Start or stop recording master transfers
+    ///
+    /// While recording, every master transfer that reaches the bus appends
+    /// one trace entry per segment, including transfers that then fail a
+    /// PEC check. Transfers that fail before any data moves (bad address,
+    /// failure mode, injected outcome, stuck SDA, stretch timeout) are not
+    /// recorded. Stopping
+    /// keeps the entries recorded so far.
+    ///
+    /// # Examples
+    ///
+    /// ```text
+    /// use openprot_platform_mock::i2c_hardware::{I2cDirection, MockI2cHardware};
+    /// use openprot_hal_blocking::i2c_hardware::I2cMaster;
+    ///
+    /// let mut mock = MockI2cHardware::new();
+    /// mock.set_recording(true);
+    /// let mut id = [0u8; 1];
+    /// mock.write_read(0x50, &[0x0F], &mut id).unwrap();
+    ///
+    /// let trace: Vec<_> = mock.trace().map(|e| (e.direction, e.data())).collect();
+    /// assert_eq!(trace, [(I2cDirection::Write, &[0x0F][..]), (I2cDirection::Read, &[0xFF][..])]);
+    /// ```
+    pub fn set_recording(&mut self, enabled: bool) {
+        self.recording = enabled;
+    }
+
+    /// Recorded transfer segments, oldest first
+    pub fn trace(&self) -> impl Iterator<Item = &I2cTraceEntry> {
+        self.trace.iter().take(self.trace_len).flatten()
+    }
+
+    /// Check whether segments were dropped because the trace filled up
+    ///
+    /// The trace keeps the first `MAX_TRACE_ENTRIES` segments.
+    pub fn trace_overflowed(&self) -> bool {
+        self.trace_overflowed
+    }
+
+    /// Discard the recorded trace
+    pub fn clear_trace(&mut self) {
+        self.trace = [None; MAX_TRACE_ENTRIES];
+        self.trace_len = 0;
+        self.trace_overflowed = false;
+    }
+
     /// Check if operations should succeed
     ///
     /// Internal helper method that returns Ok(()) if operations should succeed,
@@ -424,6 +878,166 @@ impl MockI2cHardware {
             Err(MockI2cError::Bus)
         }
     }
+
+    /// Take the oldest scripted outcome, or `Ok(())` if none is queued
+    fn take_outcome(&mut self) -> Result<(), MockI2cError> {
+        if self.injected_len == 0 {
+            return Ok(());
+        }
+        let outcome = self
+            .injected
+            .get(usize::from(self.injected_head))
+            .copied()
+            .unwrap_or(Ok(()));
+        self.injected_head = ((usize::from(self.injected_head) + 1) % MAX_INJECTED_OUTCOMES) as u8;
+        self.injected_len -= 1;
+        outcome
+    }
+
+    /// Start a master transfer to `addr`
+    ///
+    /// Rejects out-of-range addresses with `MockI2cError::BadArgument`, checks
+    /// the configured success mode, applies the next scripted outcome, and
+    /// fails with `MockI2cError::Bus` while
+    /// SDA is stuck, then simulates the slave stretching the clock. Returns
+    /// `MockI2cError::Timeout` if the stretch exceeds the bus timeout.
+    fn start_transfer(&mut self, addr: I2cAddr) -> Result<(), MockI2cError> {
+        if !addr.is_valid() {
+            return Err(MockI2cError::BadArgument);
+        }
+        self.check_success()?;
+        self.last_master_address = Some(addr);
+        self.take_outcome()?;
+        if self.sda_stuck_clocks.is_some() {
+            return Err(MockI2cError::Bus);
+        }
+        let ticks = self.clock_stretch(addr);
+        self.last_stretch_ticks = ticks;
+        match self.bus_timeout_ticks {
+            Some(limit) if ticks > limit => Err(MockI2cError::Timeout),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record `bytes` (and an optional trailing PEC byte) as the last master write
+    fn record_master_write(&mut self, bytes: &[u8], pec: Option<u8>) {
+        let copy_len = bytes.len().min(self.master_tx_buffer.len());
+        if let (Some(dst_slice), Some(src_slice)) = (
+            self.master_tx_buffer.get_mut(..copy_len),
+            bytes.get(..copy_len),
+        ) {
+            dst_slice.copy_from_slice(src_slice);
+            self.master_tx_count = copy_len;
+        }
+        if let (Some(pec), Some(slot)) = (pec, self.master_tx_buffer.get_mut(self.master_tx_count))
+        {
+            *slot = pec;
+            self.master_tx_count += 1;
+        }
+    }
+
+    /// Fill `buffer` from the injected read response, padding with 0xFF
+    ///
+    /// Returns the response byte following the data, which holds the PEC
+    /// for SMBus reads.
+    fn fill_master_read(&self, buffer: &mut [u8]) -> u8 {
+        let response = self
+            .master_rx_buffer
+            .get(..self.master_rx_count)
+            .unwrap_or(&[]);
+        let mut bytes = response.iter().copied();
+        for byte in buffer.iter_mut() {
+            *byte = bytes.next().unwrap_or(0xFF);
+        }
+        bytes.next().unwrap_or(0xFF)
+    }
+
+    /// Append a transfer segment to the trace while recording
+    ///
+    /// `pec` is appended to `bytes` as it followed them on the bus.
+    fn trace_segment(
+        &mut self,
+        addr: I2cAddr,
+        direction: I2cDirection,
+        bytes: &[u8],
+        pec: Option<u8>,
+    ) {
+        if !self.recording {
+            return;
+        }
+        let Some(slot) = self.trace.get_mut(self.trace_len) else {
+            self.trace_overflowed = true;
+            return;
+        };
+        let mut entry = I2cTraceEntry {
+            addr,
+            direction,
+            data: [0; MAX_TRACE_DATA],
+            len: 0,
+        };
+        for (dst, &byte) in entry.data.iter_mut().zip(bytes.iter().chain(pec.iter())) {
+            *dst = byte;
+            entry.len += 1;
+        }
+        *slot = Some(entry);
+        self.trace_len += 1;
+    }
+
+    /// Write `bytes` within a started transfer, as one segment
+    fn write_segment(&mut self, addr: I2cAddr, bytes: &[u8]) {
+        self.record_master_write(bytes, None);
+        self.trace_segment(addr, I2cDirection::Write, bytes, None);
+    }
+
+    /// Read into `buffer` within a started transfer, as one segment
+    ///
+    /// The buffer gets the injected response (0xFF dummy data by default).
+    fn read_segment(&mut self, addr: I2cAddr, buffer: &mut [u8]) {
+        self.fill_master_read(buffer);
+        self.trace_segment(addr, I2cDirection::Read, buffer, None);
+    }
+
+    // Master transfers shared by the 7-bit and 10-bit `I2cMaster` impls;
+    // only the address mode differs
+
+    fn master_write(&mut self, addr: I2cAddr, bytes: &[u8]) -> Result<(), MockI2cError> {
+        self.start_transfer(addr)?;
+        self.write_segment(addr, bytes);
+        Ok(())
+    }
+
+    fn master_read(&mut self, addr: I2cAddr, buffer: &mut [u8]) -> Result<(), MockI2cError> {
+        self.start_transfer(addr)?;
+        self.read_segment(addr, buffer);
+        Ok(())
+    }
+
+    fn master_write_read(
+        &mut self,
+        addr: I2cAddr,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), MockI2cError> {
+        self.start_transfer(addr)?;
+        self.write_segment(addr, bytes);
+        self.read_segment(addr, buffer);
+        Ok(())
+    }
+
+    fn master_transaction(
+        &mut self,
+        addr: I2cAddr,
+        ops_slice: &mut [Operation<'_>],
+    ) -> Result<(), MockI2cError> {
+        self.start_transfer(addr)?;
+        for op in ops_slice.iter_mut() {
+            match op {
+                Operation::Read(buffer) => self.read_segment(addr, buffer),
+                Operation::Write(bytes) => self.write_segment(addr, bytes),
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Default for MockI2cHardware {
@@ -598,18 +1212,20 @@ impl I2cBusRecovery for MockI2cHardware {
     /// Recover the I2C bus from error conditions
     ///
     /// Attempts to recover the I2C bus from stuck or error conditions
-    /// by performing bus recovery procedures.
+    /// by clocking out up to nine SCL pulses and issuing a STOP.
     ///
     /// # Returns
     ///
     /// - `Ok(())` - Bus recovery was successful
-    /// - `Err(MockI2cError::Bus)` - If the mock is configured to fail
+    /// - `Err(MockI2cError::Bus)` - If the mock is configured to fail, or
+    ///   SDA is still held after `BUS_RECOVERY_CLOCKS` pulses
     ///
     /// # Mock Behavior
     ///
-    /// - Simply checks the configured success/failure mode
-    /// - No actual bus recovery operations performed
-    /// - Can be configured to fail for testing error handling
+    /// - Checks the configured success/failure mode
+    /// - Clears a stuck SDA injected with `inject_stuck_sda()` if the slave
+    ///   releases it within `BUS_RECOVERY_CLOCKS` pulses
+    /// - Records the pulses clocked out, see `last_recovery_clocks()`
     ///
     /// # Examples
     ///
@@ -625,58 +1241,156 @@ impl I2cBusRecovery for MockI2cHardware {
     /// assert!(failing_mock.recover_bus().is_err());
     /// ```
     fn recover_bus(&mut self) -> Result<(), Self::Error> {
-        self.check_success()
+        self.check_success()?;
+        let Some(release_after) = self.sda_stuck_clocks else {
+            // Bus already idle: only the STOP is issued
+            self.last_recovery_clocks = 0;
+            return Ok(());
+        };
+        if release_after > BUS_RECOVERY_CLOCKS {
+            self.last_recovery_clocks = BUS_RECOVERY_CLOCKS;
+            self.sda_stuck_clocks = Some(release_after - BUS_RECOVERY_CLOCKS);
+            return Err(MockI2cError::Bus);
+        }
+        self.last_recovery_clocks = release_after;
+        self.sda_stuck_clocks = None;
+        Ok(())
     }
 }
 
 impl I2cMaster<SevenBitAddress> for MockI2cHardware {
-    fn write(&mut self, _addr: SevenBitAddress, _bytes: &[u8]) -> Result<(), Self::Error> {
-        self.check_success()
+    fn write(&mut self, addr: SevenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.master_write(I2cAddr::Seven(addr), bytes)
     }
 
-    fn read(&mut self, _addr: SevenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error> {
-        self.check_success()?;
-        // Fill buffer with dummy data
-        for byte in buffer.iter_mut() {
-            *byte = 0xFF;
-        }
-        Ok(())
+    fn read(&mut self, addr: SevenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.master_read(I2cAddr::Seven(addr), buffer)
     }
 
     fn write_read(
         &mut self,
-        _addr: SevenBitAddress,
-        _bytes: &[u8],
+        addr: SevenBitAddress,
+        bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Self::Error> {
-        self.check_success()?;
-        // Fill buffer with dummy data
-        for byte in buffer.iter_mut() {
-            *byte = 0xFF;
-        }
-        Ok(())
+        self.master_write_read(I2cAddr::Seven(addr), bytes, buffer)
     }
 
     fn transaction_slice(
         &mut self,
-        _addr: SevenBitAddress,
+        addr: SevenBitAddress,
         ops_slice: &mut [Operation<'_>],
     ) -> Result<(), Self::Error> {
-        self.check_success()?;
+        self.master_transaction(I2cAddr::Seven(addr), ops_slice)
+    }
+}
 
-        // Process each operation
-        for op in ops_slice.iter_mut() {
-            match op {
-                Operation::Read(buffer) => {
-                    // Fill read buffers with dummy data
-                    for byte in buffer.iter_mut() {
-                        *byte = 0xFF;
-                    }
-                }
-                Operation::Write(_) => {
-                    // Write operations are no-ops in mock
-                }
-            }
+// 10-bit addressing shares the 7-bit behavior; only the address mode differs
+impl I2cMasterTenBit for MockI2cHardware {
+    fn write_ten_bit(&mut self, addr: TenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.master_write(I2cAddr::Ten(addr), bytes)
+    }
+
+    fn read_ten_bit(&mut self, addr: TenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.master_read(I2cAddr::Ten(addr), buffer)
+    }
+
+    fn write_read_ten_bit(
+        &mut self,
+        addr: TenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.master_write_read(I2cAddr::Ten(addr), bytes, buffer)
+    }
+
+    fn transaction_slice_ten_bit(
+        &mut self,
+        addr: TenBitAddress,
+        ops_slice: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.master_transaction(I2cAddr::Ten(addr), ops_slice)
+    }
+}
+
+/// Address byte for a 7-bit address and R/W direction, as covered by the PEC
+fn address_byte(addr: SevenBitAddress, read: bool) -> u8 {
+    (addr << 1) | u8::from(read)
+}
+
+impl I2cSmbusPec<SevenBitAddress> for MockI2cHardware {
+    /// Write data followed by the PEC byte
+    ///
+    /// # Mock Behavior
+    ///
+    /// - The PEC is computed over the address byte (R/W = 0) and `bytes`
+    /// - The data and appended PEC byte are recorded and can be inspected
+    ///   with `last_master_write()`
+    fn write_with_pec(&mut self, addr: SevenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.start_transfer(I2cAddr::Seven(addr))?;
+        let pec = smbus_pec(smbus_pec(0, &[address_byte(addr, false)]), bytes);
+        self.record_master_write(bytes, Some(pec));
+        self.trace_segment(I2cAddr::Seven(addr), I2cDirection::Write, bytes, Some(pec));
+        Ok(())
+    }
+
+    /// Read data and validate the trailing PEC byte
+    ///
+    /// # Mock Behavior
+    ///
+    /// - Data comes from the response set with `inject_master_read_data()`;
+    ///   the response byte following the data is taken as the device's PEC
+    /// - Returns `MockI2cError::Pec` if it does not match the PEC computed
+    ///   over the address byte (R/W = 1) and the data read
+    fn read_with_pec(
+        &mut self,
+        addr: SevenBitAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.start_transfer(I2cAddr::Seven(addr))?;
+        let received = self.fill_master_read(buffer);
+        self.trace_segment(
+            I2cAddr::Seven(addr),
+            I2cDirection::Read,
+            buffer,
+            Some(received),
+        );
+        let pec = smbus_pec(smbus_pec(0, &[address_byte(addr, true)]), buffer);
+        if received != pec {
+            return Err(MockI2cError::Pec);
+        }
+        Ok(())
+    }
+
+    /// Combined write-then-read, validating the trailing PEC byte
+    ///
+    /// # Mock Behavior
+    ///
+    /// - The write segment is recorded without a PEC byte, as on the bus
+    /// - The PEC is computed over the write address byte, `bytes`, the read
+    ///   address byte and the data read; a mismatch returns `MockI2cError::Pec`
+    fn write_read_with_pec(
+        &mut self,
+        addr: SevenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.start_transfer(I2cAddr::Seven(addr))?;
+        self.record_master_write(bytes, None);
+        let received = self.fill_master_read(buffer);
+        self.trace_segment(I2cAddr::Seven(addr), I2cDirection::Write, bytes, None);
+        self.trace_segment(
+            I2cAddr::Seven(addr),
+            I2cDirection::Read,
+            buffer,
+            Some(received),
+        );
+        let mut pec = smbus_pec(0, &[address_byte(addr, false)]);
+        pec = smbus_pec(pec, bytes);
+        pec = smbus_pec(pec, &[address_byte(addr, true)]);
+        pec = smbus_pec(pec, buffer);
+        if received != pec {
+            return Err(MockI2cError::Pec);
         }
         Ok(())
     }
@@ -1027,6 +1741,21 @@ where
     }
 }
 
+impl<S: ClockGating> MockI2cHardwareWithSystem<S> {
+    /// Reject master transfers while this controller's clock is gated
+    ///
+    /// Real hardware would hang or return garbage; failing with
+    /// `MockI2cError::ClockGated` catches drivers that forget to ungate.
+    fn check_clock(&self) -> Result<(), MockI2cError> {
+        match self.clock_id.peripheral() {
+            Some(peripheral) if !self.system_control.is_clock_enabled(peripheral) => {
+                Err(MockI2cError::ClockGated)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 impl<S> ErrorType for MockI2cHardwareWithSystem<S> {
     type Error = MockI2cError;
 }
@@ -1123,15 +1852,17 @@ where
 impl<S> I2cMaster<SevenBitAddress> for MockI2cHardwareWithSystem<S>
 where
     S: openprot_hal_blocking::system_control::SystemControl<
-        ClockId = crate::system_control::MockClockId,
-        ResetId = crate::system_control::MockResetId,
-    >,
+            ClockId = crate::system_control::MockClockId,
+            ResetId = crate::system_control::MockResetId,
+        > + ClockGating,
 {
     fn write(&mut self, addr: SevenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_clock()?;
         self.base_hardware.write(addr, bytes)
     }
 
     fn read(&mut self, addr: SevenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.check_clock()?;
         self.base_hardware.read(addr, buffer)
     }
 
@@ -1141,6 +1872,7 @@ where
         bytes: &[u8],
         buffer: &mut [u8],
     ) -> Result<(), Self::Error> {
+        self.check_clock()?;
         self.base_hardware.write_read(addr, bytes, buffer)
     }
 
@@ -1149,10 +1881,82 @@ where
         addr: SevenBitAddress,
         ops_slice: &mut [Operation<'_>],
     ) -> Result<(), Self::Error> {
+        self.check_clock()?;
         self.base_hardware.transaction_slice(addr, ops_slice)
     }
 }
 
+// 10-bit transfers, gated on the clock like the `I2cMaster` ones
+impl<S> I2cMasterTenBit for MockI2cHardwareWithSystem<S>
+where
+    S: openprot_hal_blocking::system_control::SystemControl<
+            ClockId = crate::system_control::MockClockId,
+            ResetId = crate::system_control::MockResetId,
+        > + ClockGating,
+{
+    fn write_ten_bit(&mut self, addr: TenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_clock()?;
+        self.base_hardware.write_ten_bit(addr, bytes)
+    }
+
+    fn read_ten_bit(&mut self, addr: TenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.check_clock()?;
+        self.base_hardware.read_ten_bit(addr, buffer)
+    }
+
+    fn write_read_ten_bit(
+        &mut self,
+        addr: TenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.check_clock()?;
+        self.base_hardware.write_read_ten_bit(addr, bytes, buffer)
+    }
+
+    fn transaction_slice_ten_bit(
+        &mut self,
+        addr: TenBitAddress,
+        ops_slice: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.check_clock()?;
+        self.base_hardware
+            .transaction_slice_ten_bit(addr, ops_slice)
+    }
+}
+
+impl<S> I2cSmbusPec<SevenBitAddress> for MockI2cHardwareWithSystem<S>
+where
+    S: openprot_hal_blocking::system_control::SystemControl<
+            ClockId = crate::system_control::MockClockId,
+            ResetId = crate::system_control::MockResetId,
+        > + ClockGating,
+{
+    fn write_with_pec(&mut self, addr: SevenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.check_clock()?;
+        self.base_hardware.write_with_pec(addr, bytes)
+    }
+
+    fn read_with_pec(
+        &mut self,
+        addr: SevenBitAddress,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.check_clock()?;
+        self.base_hardware.read_with_pec(addr, buffer)
+    }
+
+    fn write_read_with_pec(
+        &mut self,
+        addr: SevenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.check_clock()?;
+        self.base_hardware.write_read_with_pec(addr, bytes, buffer)
+    }
+}
+
 // Forward all slave operations to the base hardware
 impl<S> openprot_hal_blocking::i2c_hardware::slave::I2cSlaveCore<SevenBitAddress>
     for MockI2cHardwareWithSystem<S>
@@ -1270,6 +2074,29 @@ where
     ) -> Result<Option<openprot_hal_blocking::i2c_hardware::slave::I2cIsrEvent>, MockI2cError> {
         self.base_hardware.poll_slave_events()
     }
+
+    /// Start or stop recording master transfers (for testing)
+    pub fn set_recording(&mut self, enabled: bool) {
+        self.base_hardware.set_recording(enabled);
+    }
+
+    /// Recorded transfer segments, oldest first (for testing)
+    pub fn trace(&self) -> impl Iterator<Item = &I2cTraceEntry> {
+        self.base_hardware.trace()
+    }
+
+    /// Discard the recorded trace (for testing)
+    pub fn clear_trace(&mut self) {
+        self.base_hardware.clear_trace();
+    }
+
+    /// Script the outcome of the next master transfer (for testing)
+    pub fn inject_outcome(
+        &mut self,
+        outcome: Result<(), MockI2cError>,
+    ) -> Result<(), MockI2cError> {
+        self.base_hardware.inject_outcome(outcome)
+    }
 }
 
 /// Calculate optimal source clock frequency for given I2C speed
@@ -1361,6 +2188,38 @@ mod tests {
         assert_eq!(read_buffer, [0xFF; 4]);
     }
 
+    #[test]
+    fn test_transaction_slice_uses_injected_data() {
+        let mut mock = MockI2cHardware::new();
+        mock.set_recording(true);
+        mock.inject_master_read_data(&[0x12, 0x34]);
+
+        let mut seven = [0u8; 2];
+        let mut ops = [Operation::Write(&[0x01]), Operation::Read(&mut seven)];
+        assert!(mock.transaction_slice(0x50, &mut ops).is_ok());
+        assert_eq!(seven, [0x12, 0x34]);
+        assert_eq!(mock.last_master_write(), &[0x01]);
+
+        let mut ten = [0u8; 3];
+        let mut ops = [Operation::Write(&[0x02]), Operation::Read(&mut ten)];
+        assert!(mock.transaction_slice_ten_bit(0x155, &mut ops).is_ok());
+        assert_eq!(ten, [0x12, 0x34, 0xFF]);
+        assert_eq!(mock.last_master_write(), &[0x02]);
+
+        let expected: [(I2cAddr, I2cDirection, &[u8]); 4] = [
+            (I2cAddr::Seven(0x50), I2cDirection::Write, &[0x01]),
+            (I2cAddr::Seven(0x50), I2cDirection::Read, &[0x12, 0x34]),
+            (I2cAddr::Ten(0x155), I2cDirection::Write, &[0x02]),
+            (I2cAddr::Ten(0x155), I2cDirection::Read, &[0x12, 0x34, 0xFF]),
+        ];
+        assert_eq!(mock.trace().count(), expected.len());
+        for (entry, (addr, direction, data)) in mock.trace().zip(expected) {
+            assert_eq!(entry.addr, addr);
+            assert_eq!(entry.direction, direction);
+            assert_eq!(entry.data(), data);
+        }
+    }
+
     #[test]
     fn test_bus_recovery() {
         let mut mock = MockI2cHardware::new();
@@ -1370,6 +2229,428 @@ mod tests {
         assert_eq!(failing_mock.recover_bus(), Err(MockI2cError::Bus));
     }
 
+    #[test]
+    fn test_smbus_pec_check_value() {
+        // CRC-8/SMBUS check value
+        assert_eq!(smbus_pec(0, b"123456789"), 0xF4);
+        // Continuing across segments matches a single pass
+        assert_eq!(smbus_pec(smbus_pec(0, b"1234"), b"56789"), 0xF4);
+    }
+
+    #[test]
+    fn test_write_with_pec_appends_pec() {
+        let mut mock = MockI2cHardware::new();
+
+        // Write Word: address 0x5A, command 0x06, data 0x3412
+        assert!(mock.write_with_pec(0x5A, &[0x06, 0x12, 0x34]).is_ok());
+        assert_eq!(mock.last_master_write(), &[0x06, 0x12, 0x34, 0x4C]);
+
+        // Plain writes carry no PEC byte
+        assert!(mock.write(0x5A, &[0x06, 0x12, 0x34]).is_ok());
+        assert_eq!(mock.last_master_write(), &[0x06, 0x12, 0x34]);
+    }
+
+    #[test]
+    fn test_read_with_pec_validates_pec() {
+        let mut mock = MockI2cHardware::new();
+
+        // Receive Byte: address 0x5A, data 0x42
+        mock.inject_master_read_data(&[0x42, 0xC7]);
+        let mut byte = [0u8; 1];
+        assert!(mock.read_with_pec(0x5A, &mut byte).is_ok());
+        assert_eq!(byte, [0x42]);
+
+        // Same data from a different address fails the check
+        assert_eq!(mock.read_with_pec(0x5B, &mut byte), Err(MockI2cError::Pec));
+    }
+
+    #[test]
+    fn test_write_read_with_pec() {
+        let mut mock = MockI2cHardware::new();
+
+        // Read Word: address 0x5A, command 0x07, data 0xCDAB
+        mock.inject_master_read_data(&[0xAB, 0xCD, 0xE4]);
+        let mut word = [0u8; 2];
+        assert!(mock.write_read_with_pec(0x5A, &[0x07], &mut word).is_ok());
+        assert_eq!(word, [0xAB, 0xCD]);
+        assert_eq!(mock.last_master_write(), &[0x07]);
+
+        // Corrupted data byte
+        mock.inject_master_read_data(&[0xAB, 0xCE, 0xE4]);
+        assert_eq!(
+            mock.write_read_with_pec(0x5A, &[0x07], &mut word),
+            Err(MockI2cError::Pec)
+        );
+
+        // Missing PEC byte (response padded with 0xFF)
+        mock.inject_master_read_data(&[0xAB, 0xCD]);
+        assert_eq!(
+            mock.write_read_with_pec(0x5A, &[0x07], &mut word),
+            Err(MockI2cError::Pec)
+        );
+
+        // PEC errors map to ErrorKind::Other
+        use embedded_hal::i2c::{Error, ErrorKind};
+        assert_eq!(MockI2cError::Pec.kind(), ErrorKind::Other);
+    }
+
+    #[test]
+    fn test_pec_failing_operations() {
+        let mut mock = MockI2cHardware::new_failing();
+        let mut buffer = [0u8; 2];
+        assert_eq!(mock.write_with_pec(0x5A, &[0x06]), Err(MockI2cError::Bus));
+        assert_eq!(
+            mock.read_with_pec(0x5A, &mut buffer),
+            Err(MockI2cError::Bus)
+        );
+        assert_eq!(
+            mock.write_read_with_pec(0x5A, &[0x07], &mut buffer),
+            Err(MockI2cError::Bus)
+        );
+    }
+
+    #[test]
+    fn test_clock_stretch_within_timeout() {
+        let mut mock = MockI2cHardware::new();
+        mock.set_bus_timeout(Some(100));
+        mock.set_clock_stretch(0x50u8, 100)
+            .expect("Failed to set stretch");
+
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+        assert_eq!(mock.last_stretch_ticks(), 100);
+
+        let mut buffer = [0u8; 2];
+        assert!(mock.write_read(0x50, &[0x01], &mut buffer).is_ok());
+
+        // Other addresses do not stretch
+        assert!(mock.write(0x51, &[0x01]).is_ok());
+        assert_eq!(mock.last_stretch_ticks(), 0);
+    }
+
+    #[test]
+    fn test_clock_stretch_beyond_timeout() {
+        let mut mock = MockI2cHardware::new();
+        mock.set_bus_timeout(Some(100));
+        mock.set_clock_stretch(0x50u8, 101)
+            .expect("Failed to set stretch");
+
+        let mut buffer = [0u8; 2];
+        assert_eq!(mock.write(0x50, &[0x01]), Err(MockI2cError::Timeout));
+        assert_eq!(mock.read(0x50, &mut buffer), Err(MockI2cError::Timeout));
+        assert_eq!(
+            mock.write_read_with_pec(0x50, &[0x01], &mut buffer),
+            Err(MockI2cError::Timeout)
+        );
+        assert_eq!(mock.last_stretch_ticks(), 101);
+
+        use embedded_hal::i2c::{Error, ErrorKind};
+        assert_eq!(MockI2cError::Timeout.kind(), ErrorKind::Bus);
+
+        // Without a timeout the transfer waits out the stretch
+        mock.set_bus_timeout(None);
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+
+        // Retry after the slave releases the clock
+        mock.set_bus_timeout(Some(100));
+        mock.set_clock_stretch(0x50u8, 0)
+            .expect("Failed to clear stretch");
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+        assert_eq!(mock.clock_stretch(0x50u8), 0);
+    }
+
+    #[test]
+    fn test_clock_stretch_table_full() {
+        let mut mock = MockI2cHardware::new();
+        for addr in 0..MAX_STRETCHING_SLAVES as u8 {
+            assert!(mock.set_clock_stretch(0x10 + addr, 5).is_ok());
+        }
+        assert_eq!(mock.set_clock_stretch(0x20u8, 5), Err(MockI2cError::Other));
+
+        // Updating an existing entry still works
+        assert!(mock.set_clock_stretch(0x10u8, 7).is_ok());
+        assert_eq!(mock.clock_stretch(0x10u8), 7);
+    }
+
+    #[test]
+    fn test_bus_recovery_clears_stuck_sda() {
+        let mut mock = MockI2cHardware::new();
+        mock.inject_stuck_sda(5);
+        assert!(mock.is_sda_stuck());
+
+        let mut buffer = [0u8; 2];
+        assert_eq!(mock.write(0x50, &[0x01]), Err(MockI2cError::Bus));
+        assert_eq!(mock.read(0x50, &mut buffer), Err(MockI2cError::Bus));
+
+        assert!(mock.recover_bus().is_ok());
+        assert!(!mock.is_sda_stuck());
+        assert_eq!(mock.last_recovery_clocks(), 5);
+
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+        assert!(mock.read(0x50, &mut buffer).is_ok());
+    }
+
+    #[test]
+    fn test_bus_recovery_gives_up_after_nine_clocks() {
+        let mut mock = MockI2cHardware::new();
+        mock.inject_stuck_sda(BUS_RECOVERY_CLOCKS + 3);
+
+        assert_eq!(mock.recover_bus(), Err(MockI2cError::Bus));
+        assert_eq!(mock.last_recovery_clocks(), BUS_RECOVERY_CLOCKS);
+        assert!(mock.is_sda_stuck());
+        assert_eq!(mock.write(0x50, &[0x01]), Err(MockI2cError::Bus));
+
+        // A second attempt clocks out the remaining pulses
+        assert!(mock.recover_bus().is_ok());
+        assert_eq!(mock.last_recovery_clocks(), 3);
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+    }
+
+    #[test]
+    fn test_ten_bit_addressing() {
+        let mut mock = MockI2cHardware::new();
+        mock.set_bus_timeout(Some(100));
+
+        // The 10-bit device 0x050 stretches past the timeout; the 7-bit
+        // device 0x50 shares the low address bits but is a different slave
+        mock.set_clock_stretch(0x050u16, 200)
+            .expect("Failed to set stretch");
+
+        let mut buffer = [0u8; 2];
+        assert_eq!(
+            mock.write_ten_bit(0x050, &[0x01]),
+            Err(MockI2cError::Timeout)
+        );
+        assert_eq!(mock.last_master_address(), Some(I2cAddr::Ten(0x050)));
+
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+        assert_eq!(mock.last_master_address(), Some(I2cAddr::Seven(0x50)));
+        assert_eq!(mock.last_stretch_ticks(), 0);
+
+        // Transfers to other 10-bit devices go through
+        mock.inject_master_read_data(&[0x12, 0x34]);
+        assert!(mock.write_read_ten_bit(0x3FF, &[0x00], &mut buffer).is_ok());
+        assert_eq!(buffer, [0x12, 0x34]);
+        assert_eq!(mock.last_master_write(), &[0x00]);
+        assert_eq!(mock.last_master_address(), Some(I2cAddr::Ten(0x3FF)));
+
+        let mut ops = [Operation::Write(&[0x02]), Operation::Read(&mut buffer)];
+        assert!(mock.transaction_slice_ten_bit(0x155, &mut ops).is_ok());
+    }
+
+    #[test]
+    fn test_ten_bit_adapter_runs_generic_driver() {
+        use openprot_hal_blocking::i2c_hardware::TenBit;
+
+        /// A driver written against the HAL's 10-bit master trait
+        fn read_register<M: I2cMaster<TenBitAddress>>(
+            i2c: &mut M,
+            addr: TenBitAddress,
+            reg: u8,
+        ) -> Result<u8, M::Error> {
+            let mut value = [0u8; 1];
+            i2c.write_read(addr, &[reg], &mut value)?;
+            Ok(value[0])
+        }
+
+        let mut mock = MockI2cHardware::new();
+        mock.inject_master_read_data(&[0x5A]);
+        assert_eq!(read_register(&mut TenBit(&mut mock), 0x2A0, 0x07), Ok(0x5A));
+        assert_eq!(mock.last_master_write(), &[0x07]);
+        assert_eq!(mock.last_master_address(), Some(I2cAddr::Ten(0x2A0)));
+    }
+
+    #[test]
+    fn test_out_of_range_addresses() {
+        let mut mock = MockI2cHardware::new();
+        let mut buffer = [0u8; 1];
+
+        assert_eq!(mock.write(0x80, &[0x01]), Err(MockI2cError::BadArgument));
+        assert_eq!(
+            mock.read_ten_bit(0x400, &mut buffer),
+            Err(MockI2cError::BadArgument)
+        );
+        assert_eq!(mock.last_master_address(), None);
+
+        assert_eq!(
+            mock.set_clock_stretch(0x400u16, 5),
+            Err(MockI2cError::BadArgument)
+        );
+        assert_eq!(
+            mock.set_clock_stretch(0xFFu8, 5),
+            Err(MockI2cError::BadArgument)
+        );
+    }
+
+    /// Driver routine under test: read-modify-write a config register,
+    /// then read back the status register
+    fn enable_sensor<I: I2cMaster<SevenBitAddress>>(bus: &mut I, addr: u8) -> Result<u8, I::Error> {
+        let mut config = [0u8; 1];
+        bus.write_read(addr, &[0x01], &mut config)?;
+        bus.write(addr, &[0x01, config[0] | 0x80])?;
+        let mut status = [0u8; 1];
+        bus.write_read(addr, &[0x00], &mut status)?;
+        Ok(status[0])
+    }
+
+    #[test]
+    fn test_trace_records_driver_sequence() {
+        let mut mock = MockI2cHardware::new();
+        mock.inject_master_read_data(&[0x12]);
+        mock.set_recording(true);
+
+        assert_eq!(enable_sensor(&mut mock, 0x48), Ok(0x12));
+
+        let seven = I2cAddr::Seven(0x48);
+        let expected: [(I2cDirection, &[u8]); 5] = [
+            (I2cDirection::Write, &[0x01]),
+            (I2cDirection::Read, &[0x12]),
+            (I2cDirection::Write, &[0x01, 0x92]),
+            (I2cDirection::Write, &[0x00]),
+            (I2cDirection::Read, &[0x12]),
+        ];
+        assert_eq!(mock.trace().count(), expected.len());
+        for (entry, (direction, data)) in mock.trace().zip(expected) {
+            assert_eq!(entry.addr, seven);
+            assert_eq!(entry.direction, direction);
+            assert_eq!(entry.data(), data);
+        }
+        assert!(!mock.trace_overflowed());
+
+        mock.clear_trace();
+        assert_eq!(mock.trace().count(), 0);
+    }
+
+    #[test]
+    fn test_trace_pec_and_rejected_transfers() {
+        let mut mock = MockI2cHardware::new();
+        mock.set_recording(true);
+
+        // Not recording until enabled; rejected transfers never reach the bus
+        mock.set_recording(false);
+        assert!(mock.write(0x50, &[0xAA]).is_ok());
+        mock.set_recording(true);
+        assert_eq!(mock.write(0x80, &[0xAA]), Err(MockI2cError::BadArgument));
+
+        assert!(mock.write_with_pec(0x50, &[0x01]).is_ok());
+        let entry = mock.trace().next().copied().expect("PEC write recorded");
+        assert_eq!(entry.direction, I2cDirection::Write);
+        assert_eq!(entry.data(), mock.last_master_write());
+        assert_eq!(mock.trace().count(), 1);
+    }
+
+    #[test]
+    fn test_trace_truncates_and_overflows() {
+        let mut mock = MockI2cHardware::new();
+        mock.set_recording(true);
+
+        let long = [0x5Au8; MAX_TRACE_DATA + 4];
+        assert!(mock.write(0x50, &long).is_ok());
+        assert_eq!(
+            mock.trace().next().map(|e| e.data().len()),
+            Some(MAX_TRACE_DATA)
+        );
+
+        for _ in 1..MAX_TRACE_ENTRIES {
+            assert!(mock.write(0x50, &[0x01]).is_ok());
+        }
+        assert!(!mock.trace_overflowed());
+        assert!(mock.write(0x51, &[0x02]).is_ok());
+        assert!(mock.trace_overflowed());
+        assert_eq!(mock.trace().count(), MAX_TRACE_ENTRIES);
+        assert!(mock.trace().all(|e| e.addr == I2cAddr::Seven(0x50)));
+    }
+
+    /// Driver routine under test: retry a write up to `attempts` times,
+    /// returning the number of attempts used
+    fn write_with_retry<I: I2cMaster<SevenBitAddress>>(
+        bus: &mut I,
+        addr: u8,
+        bytes: &[u8],
+        attempts: usize,
+    ) -> Result<usize, I::Error> {
+        let mut attempt = 1;
+        loop {
+            match bus.write(addr, bytes) {
+                Ok(()) => return Ok(attempt),
+                Err(e) if attempt == attempts => return Err(e),
+                Err(_) => attempt += 1,
+            }
+        }
+    }
+
+    #[test]
+    fn test_injected_nack_then_retry_recovers() {
+        let mut mock = MockI2cHardware::new();
+        mock.set_recording(true);
+        mock.inject_outcome(Err(MockI2cError::NoAcknowledge))
+            .expect("Failed to inject outcome");
+
+        assert_eq!(write_with_retry(&mut mock, 0x50, &[0x01, 0x02], 3), Ok(2));
+        assert_eq!(mock.pending_outcomes(), 0);
+        assert_eq!(mock.last_master_write(), &[0x01, 0x02]);
+        // Only the successful attempt put data on the bus
+        assert_eq!(mock.trace().count(), 1);
+    }
+
+    #[test]
+    fn test_injected_outcomes_in_order() {
+        let mut mock = MockI2cHardware::new();
+        for outcome in [
+            Err(MockI2cError::ArbitrationLoss),
+            Ok(()),
+            Err(MockI2cError::Timeout),
+        ] {
+            mock.inject_outcome(outcome)
+                .expect("Failed to inject outcome");
+        }
+        assert_eq!(mock.pending_outcomes(), 3);
+
+        let mut buffer = [0u8; 2];
+        assert_eq!(
+            mock.read(0x50, &mut buffer),
+            Err(MockI2cError::ArbitrationLoss)
+        );
+        // Out-of-range addresses do not consume an outcome
+        assert_eq!(mock.write(0x80, &[0x01]), Err(MockI2cError::BadArgument));
+        assert!(mock.write_read(0x50, &[0x01], &mut buffer).is_ok());
+        assert_eq!(
+            mock.write_with_pec(0x50, &[0x01]),
+            Err(MockI2cError::Timeout)
+        );
+        // Queue drained: back to normal behavior
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+    }
+
+    #[test]
+    fn test_injected_outcome_queue_full() {
+        let mut mock = MockI2cHardware::new();
+        for _ in 0..MAX_INJECTED_OUTCOMES {
+            mock.inject_outcome(Err(MockI2cError::NoAcknowledge))
+                .expect("Failed to inject outcome");
+        }
+        assert_eq!(
+            mock.inject_outcome(Err(MockI2cError::NoAcknowledge)),
+            Err(MockI2cError::Other)
+        );
+
+        // A retry budget smaller than the scripted failures gives up
+        assert_eq!(
+            write_with_retry(&mut mock, 0x50, &[0x01], 3),
+            Err(MockI2cError::NoAcknowledge)
+        );
+        assert_eq!(mock.pending_outcomes(), MAX_INJECTED_OUTCOMES - 3);
+
+        // The ring wraps around once space frees up
+        for _ in 0..3 {
+            mock.inject_outcome(Ok(()))
+                .expect("Failed to inject outcome");
+        }
+        assert_eq!(mock.pending_outcomes(), MAX_INJECTED_OUTCOMES);
+        mock.clear_outcomes();
+        assert_eq!(mock.pending_outcomes(), 0);
+        assert!(mock.write(0x50, &[0x01]).is_ok());
+    }
+
     #[test]
     fn test_configuration() {
         let mut mock = MockI2cHardware::new();
@@ -1725,6 +3006,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_system_control_gated_clock_rejects_transfers() {
+        use crate::system_control::{MockClockId, MockResetId, MockSystemControl};
+        use openprot_hal_blocking::system_control::Peripheral;
+
+        let system_control = MockSystemControl::new();
+        let mut i2c_with_system =
+            MockI2cHardwareWithSystem::new(system_control, MockClockId::I2c1, MockResetId::I2c1);
+        let mut config = MockI2cConfig::default();
+        assert!(i2c_with_system.init(&mut config).is_ok());
+
+        // Gate the clock behind the driver's back
+        i2c_with_system
+            .system_control_mut()
+            .set_clock_enabled(Peripheral::I2c(0), false);
+        assert_eq!(
+            i2c_with_system.write(0x50, &[0x01]),
+            Err(MockI2cError::ClockGated)
+        );
+        let mut buffer = [0u8; 2];
+        assert_eq!(
+            i2c_with_system.write_read_with_pec(0x50, &[0x01], &mut buffer),
+            Err(MockI2cError::ClockGated)
+        );
+        // Gating another controller does not matter
+        i2c_with_system
+            .system_control_mut()
+            .set_clock_enabled(Peripheral::I2c(1), true);
+        assert_eq!(
+            i2c_with_system.read(0x50, &mut buffer),
+            Err(MockI2cError::ClockGated)
+        );
+
+        i2c_with_system
+            .system_control_mut()
+            .set_clock_enabled(Peripheral::I2c(0), true);
+        assert!(i2c_with_system.write(0x50, &[0x01]).is_ok());
+        assert!(i2c_with_system.read(0x50, &mut buffer).is_ok());
+    }
+
     #[test]
     fn test_system_control_access_methods() {
         use crate::system_control::{MockClockId, MockResetId, MockSystemControl};
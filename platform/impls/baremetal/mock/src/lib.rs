@@ -12,6 +12,11 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::arithmetic_side_effects)]
 
+pub mod cipher;
+pub mod gpio;
 pub mod hash;
 pub mod i2c_hardware;
+pub mod rng;
+pub mod spi_host;
 pub mod system_control;
+pub mod timer;
@@ -0,0 +1,99 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mock Random Number Generator
+//!
+//! Provides a deterministic, seeded implementation of the RNG trait so tests
+//! that consume nonces are reproducible. The output is **not** random in any
+//! cryptographic sense and must never back a real platform.
+
+use core::convert::Infallible;
+use openprot_hal_blocking::rng::{ErrorType, Rng};
+
+/// Deterministic RNG producing a SplitMix64 stream from a seed
+///
+/// Two instances created with the same seed produce identical output, for
+/// both `fill_bytes` and `next_u32`.
+#[derive(Debug, Clone)]
+pub struct MockRng {
+    state: u64,
+}
+
+impl MockRng {
+    /// Create a generator whose stream is fully determined by `seed`
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Default for MockRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl ErrorType for MockRng {
+    type Error = Infallible;
+}
+
+impl Rng for MockRng {
+    fn fill_bytes(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+        for chunk in buf.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+        Ok(())
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_produces_identical_streams() {
+        let mut a = MockRng::new(0x5EED);
+        let mut b = MockRng::new(0x5EED);
+
+        let mut buf_a = [0u8; 37];
+        let mut buf_b = [0u8; 37];
+        a.fill_bytes(&mut buf_a).unwrap();
+        b.fill_bytes(&mut buf_b).unwrap();
+        assert_eq!(buf_a, buf_b);
+
+        for _ in 0..16 {
+            assert_eq!(a.next_u32(), b.next_u32());
+        }
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        let mut a = MockRng::new(1);
+        let mut b = MockRng::new(2);
+        let mut buf_a = [0u8; 16];
+        let mut buf_b = [0u8; 16];
+        a.fill_bytes(&mut buf_a).unwrap();
+        b.fill_bytes(&mut buf_b).unwrap();
+        assert_ne!(buf_a, buf_b);
+    }
+
+    #[test]
+    fn stream_is_stable_across_releases() {
+        // Pinned values: tests seeded with a constant rely on them not moving.
+        let mut rng = MockRng::new(0);
+        assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(rng.next_u32(), 0x6E78_9E6A);
+    }
+}
@@ -0,0 +1,225 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mock SPI Host Implementation
+//!
+//! Provides a stub SPI controller whose attached device answers from a canned
+//! response table keyed by the first (command) byte of each transaction.
+//! This is enough to develop flash drivers against: preload the JEDEC ID or
+//! a page of data and the driver reads it back.
+//!
+//! Commands without a table entry read as `0xFF`, as an idle MISO line does.
+
+use embedded_hal::spi::{ErrorKind, ErrorType};
+use openprot_hal_blocking::spi_host::SpiHost;
+
+/// Default number of entries in the response table
+pub const DEFAULT_RESPONSES: usize = 8;
+
+/// Default maximum length of one canned response, in bytes
+pub const DEFAULT_RESPONSE_LEN: usize = 256;
+
+/// Byte read back when no canned response covers a position
+const IDLE_BYTE: u8 = 0xFF;
+
+/// Mock SPI error type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockSpiError {
+    /// Response table is full
+    TableFull,
+    /// Canned response is longer than the table allows
+    ResponseTooLong,
+}
+
+impl embedded_hal::spi::Error for MockSpiError {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+/// One canned device response
+#[derive(Debug, Clone, Copy)]
+struct Response<const LEN: usize> {
+    command: u8,
+    bytes: [u8; LEN],
+    len: usize,
+}
+
+/// Mock SPI host with a canned device response table
+///
+/// The response for a command is what the device shifts out after the
+/// complete command (opcode plus any address or dummy bytes) has been sent.
+#[derive(Debug, Clone)]
+pub struct MockSpiHost<
+    const RESPONSES: usize = DEFAULT_RESPONSES,
+    const LEN: usize = DEFAULT_RESPONSE_LEN,
+> {
+    table: [Option<Response<LEN>>; RESPONSES],
+    last_command: Option<u8>,
+    transactions: usize,
+}
+
+impl<const RESPONSES: usize, const LEN: usize> MockSpiHost<RESPONSES, LEN> {
+    /// Create a host with an empty response table
+    pub const fn new() -> Self {
+        Self {
+            table: [None; RESPONSES],
+            last_command: None,
+            transactions: 0,
+        }
+    }
+
+    /// Set the response the device returns for `command`, replacing any
+    /// previous entry for it
+    pub fn set_response(&mut self, command: u8, bytes: &[u8]) -> Result<(), MockSpiError> {
+        if bytes.len() > LEN {
+            return Err(MockSpiError::ResponseTooLong);
+        }
+        let index = self
+            .table
+            .iter()
+            .position(|slot| slot.is_some_and(|r| r.command == command))
+            .or_else(|| self.table.iter().position(Option::is_none))
+            .ok_or(MockSpiError::TableFull)?;
+        let mut response = Response {
+            command,
+            bytes: [0; LEN],
+            len: bytes.len(),
+        };
+        response.bytes[..bytes.len()].copy_from_slice(bytes);
+        self.table[index] = Some(response);
+        Ok(())
+    }
+
+    /// Command byte of the most recent transaction
+    pub fn last_command(&self) -> Option<u8> {
+        self.last_command
+    }
+
+    /// Number of chip-select cycles performed
+    pub fn transactions(&self) -> usize {
+        self.transactions
+    }
+
+    /// Record a transaction and return the canned response for its command
+    fn begin(&mut self, tx: &[u8]) -> &[u8] {
+        self.transactions += 1;
+        self.last_command = tx.first().copied();
+        let Some(command) = self.last_command else {
+            return &[];
+        };
+        self.table
+            .iter()
+            .flatten()
+            .find(|r| r.command == command)
+            .map_or(&[], |r| &r.bytes[..r.len])
+    }
+}
+
+impl<const RESPONSES: usize, const LEN: usize> Default for MockSpiHost<RESPONSES, LEN> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const RESPONSES: usize, const LEN: usize> ErrorType for MockSpiHost<RESPONSES, LEN> {
+    type Error = MockSpiError;
+}
+
+impl<const RESPONSES: usize, const LEN: usize> SpiHost for MockSpiHost<RESPONSES, LEN> {
+    fn transfer(&mut self, tx: &[u8], rx: &mut [u8]) -> Result<(), Self::Error> {
+        let command_len = tx.len();
+        let response = self.begin(tx);
+        // The device is listening while the command shifts out.
+        for (i, byte) in rx.iter_mut().enumerate() {
+            *byte = i
+                .checked_sub(command_len)
+                .and_then(|at| response.get(at))
+                .copied()
+                .unwrap_or(IDLE_BYTE);
+        }
+        Ok(())
+    }
+
+    fn write_then_read(&mut self, tx: &[u8], rx: &mut [u8]) -> Result<(), Self::Error> {
+        let response = self.begin(tx);
+        for (i, byte) in rx.iter_mut().enumerate() {
+            *byte = response.get(i).copied().unwrap_or(IDLE_BYTE);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const READ_JEDEC_ID: u8 = 0x9F;
+    const READ_DATA: u8 = 0x03;
+
+    #[test]
+    fn jedec_id_read_returns_canned_bytes() {
+        let mut spi = MockSpiHost::<DEFAULT_RESPONSES, DEFAULT_RESPONSE_LEN>::new();
+        spi.set_response(READ_JEDEC_ID, &[0xEF, 0x40, 0x18])
+            .unwrap();
+
+        let mut id = [0u8; 3];
+        spi.write_then_read(&[READ_JEDEC_ID], &mut id).unwrap();
+        assert_eq!(id, [0xEF, 0x40, 0x18]);
+        assert_eq!(spi.last_command(), Some(READ_JEDEC_ID));
+        assert_eq!(spi.transactions(), 1);
+    }
+
+    #[test]
+    fn page_read_returns_canned_page() {
+        let mut spi = MockSpiHost::<DEFAULT_RESPONSES, DEFAULT_RESPONSE_LEN>::new();
+        let mut page = [0u8; 256];
+        for (i, byte) in page.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        spi.set_response(READ_DATA, &page).unwrap();
+
+        let mut rx = [0u8; 256];
+        spi.write_then_read(&[READ_DATA, 0x00, 0x10, 0x00], &mut rx)
+            .unwrap();
+        assert_eq!(rx, page);
+
+        // Full duplex: the page follows the four command bytes.
+        let mut rx = [0u8; 8];
+        spi.transfer(&[READ_DATA, 0x00, 0x10, 0x00], &mut rx)
+            .unwrap();
+        assert_eq!(rx, [0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x01, 0x02, 0x03]);
+    }
+
+    #[test]
+    fn unknown_command_reads_idle_bus() {
+        let mut spi = MockSpiHost::<DEFAULT_RESPONSES, DEFAULT_RESPONSE_LEN>::new();
+        spi.set_response(READ_JEDEC_ID, &[0xEF]).unwrap();
+
+        let mut rx = [0u8; 2];
+        spi.write_then_read(&[0x05], &mut rx).unwrap();
+        assert_eq!(rx, [0xFF, 0xFF]);
+
+        // Short canned responses are padded the same way.
+        spi.write_then_read(&[READ_JEDEC_ID], &mut rx).unwrap();
+        assert_eq!(rx, [0xEF, 0xFF]);
+    }
+
+    #[test]
+    fn response_table_limits() {
+        let mut spi = MockSpiHost::<2, 4>::new();
+        assert_eq!(
+            spi.set_response(0x01, &[0; 5]),
+            Err(MockSpiError::ResponseTooLong)
+        );
+        spi.set_response(0x01, &[1]).unwrap();
+        spi.set_response(0x02, &[2]).unwrap();
+        assert_eq!(spi.set_response(0x03, &[3]), Err(MockSpiError::TableFull));
+
+        // Replacing an existing entry needs no free slot.
+        spi.set_response(0x01, &[9]).unwrap();
+        let mut rx = [0u8; 1];
+        spi.write_then_read(&[0x01], &mut rx).unwrap();
+        assert_eq!(rx, [9]);
+    }
+}
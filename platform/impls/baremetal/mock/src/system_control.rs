@@ -13,6 +13,11 @@
 //! - **Reset Control**: Assert/deassert resets, pulse reset with timing
 //! - **Configurable Behavior**: Success/failure modes for testing error paths
 //! - **State Tracking**: Tracks clock and reset states for verification
+//! - **Reset Reason**: Presettable cause of the last reset for boot-flow testing
+//! - **Watchdog**: Virtual deadline driven by a test clock, recording missed pets
+//! - **Power States**: Active/Idle/DeepSleep/Off with enforced legal transitions
+//! - **Clock Tree**: Configurable per-domain frequencies for divider calculations
+//! - **Clock Gating**: Per-peripheral clock gates sharing state with `ClockControl`
 //! - **Realistic Simulation**: Provides reasonable default frequencies and timing
 //!
 //! # Examples
@@ -37,6 +42,85 @@
 //! sys_ctrl.reset_deassert(&reset_id).unwrap();
 //! ```
 //!
+//! ## Reset Reason
+//!
+//! ```text
+//! use openprot_platform_mock::system_control::MockSystemControl;
+//! use openprot_hal_blocking::system_control::{ResetReason, ResetReasonReport};
+//!
+//! let mut sys_ctrl = MockSystemControl::new();
+//! assert_eq!(sys_ctrl.last_reset_reason(), ResetReason::PowerOn);
+//!
+//! // Exercise the watchdog-recovery boot path
+//! sys_ctrl.set_reset_reason(ResetReason::Watchdog);
+//! assert_eq!(sys_ctrl.last_reset_reason(), ResetReason::Watchdog);
+//! ```
+//!
+//! ## Watchdog
+//!
+//! ```text
+//! use openprot_platform_mock::system_control::MockSystemControl;
+//! use openprot_hal_blocking::system_control::WatchdogControl;
+//!
+//! let mut sys_ctrl = MockSystemControl::new();
+//! sys_ctrl.wdt_enable(100).unwrap();
+//!
+//! sys_ctrl.advance_time(80);
+//! sys_ctrl.wdt_pet().unwrap();
+//! sys_ctrl.advance_time(80);
+//! assert_eq!(sys_ctrl.watchdog_resets(), 0);
+//!
+//! // Missed pet: the mock records the reset it would have performed
+//! sys_ctrl.advance_time(20);
+//! assert_eq!(sys_ctrl.watchdog_resets(), 1);
+//! ```
+//!
+//! ## Power States
+//!
+//! ```text
+//! use openprot_platform_mock::system_control::MockSystemControl;
+//! use openprot_hal_blocking::system_control::{PowerControl, PowerState};
+//!
+//! let mut sys_ctrl = MockSystemControl::new();
+//! sys_ctrl.set_power_state(PowerState::DeepSleep).unwrap();
+//! sys_ctrl.set_power_state(PowerState::Active).unwrap();
+//! sys_ctrl.set_power_state(PowerState::Off).unwrap();
+//!
+//! // Only a reset brings the system back from Off
+//! assert!(sys_ctrl.set_power_state(PowerState::Active).is_err());
+//! sys_ctrl.power_on_reset();
+//! assert_eq!(sys_ctrl.power_state(), PowerState::Active);
+//! ```
+//!
+//! ## Clock Tree
+//!
+//! ```text
+//! use openprot_platform_mock::system_control::MockSystemControl;
+//! use openprot_hal_blocking::system_control::{ClockDomain, ClockTree};
+//!
+//! let mut sys_ctrl = MockSystemControl::new();
+//! sys_ctrl.set_clock_hz(ClockDomain::Peripheral, 48_000_000);
+//!
+//! // A UART driver derives its divisor from the queried clock
+//! let divisor = sys_ctrl.clock_hz(ClockDomain::Peripheral) / (16 * 115_200);
+//! assert_eq!(divisor, 26);
+//! ```
+//!
+//! ## Clock Gating
+//!
+//! ```text
+//! use openprot_platform_mock::system_control::{MockClockId, MockSystemControl};
+//! use openprot_hal_blocking::system_control::{ClockControl, ClockGating, Peripheral};
+//!
+//! let mut sys_ctrl = MockSystemControl::new();
+//! sys_ctrl.set_clock_enabled(Peripheral::I2c(0), true);
+//!
+//! // The gate is the enable bit of the peripheral's clock
+//! assert_eq!(sys_ctrl.is_clock_enabled(&MockClockId::I2c1), Ok(true));
+//! sys_ctrl.disable(&MockClockId::I2c1).unwrap();
+//! assert!(!ClockGating::is_clock_enabled(&sys_ctrl, Peripheral::I2c(0)));
+//! ```
+//!
 //! ## Error Testing
 //!
 //! ```text
@@ -52,7 +136,8 @@
 
 use core::time::Duration;
 use openprot_hal_blocking::system_control::{
-    ClockControl, Error, ErrorKind, ErrorType, ResetControl,
+    ClockControl, ClockDomain, ClockGating, ClockTree, Error, ErrorKind, ErrorType, Peripheral,
+    PowerControl, PowerState, ResetControl, ResetReason, ResetReasonReport, WatchdogControl,
 };
 
 /// Mock error type for system control operations
@@ -66,6 +151,8 @@ pub enum MockSystemControlError {
     InvalidConfig,
     /// Hardware simulation failure
     HardwareFailure,
+    /// Invalid argument for the current state (e.g. illegal power transition)
+    BadArgument,
 }
 
 impl Error for MockSystemControlError {
@@ -75,6 +162,7 @@ impl Error for MockSystemControlError {
             MockSystemControlError::ResetError => ErrorKind::HardwareFailure,
             MockSystemControlError::InvalidConfig => ErrorKind::InvalidClockFrequency,
             MockSystemControlError::HardwareFailure => ErrorKind::HardwareFailure,
+            MockSystemControlError::BadArgument => ErrorKind::InvalidArgument,
         }
     }
 }
@@ -90,6 +178,34 @@ pub enum MockClockId {
     SystemClock,
     /// Peripheral clock
     PeripheralClock,
+    /// SPI controller 1 clock
+    Spi1,
+    /// UART 1 clock
+    Uart1,
+}
+
+impl MockClockId {
+    /// Peripheral whose clock gate this clock is, if any
+    pub fn peripheral(&self) -> Option<Peripheral> {
+        match self {
+            MockClockId::I2c1 => Some(Peripheral::I2c(0)),
+            MockClockId::I2c2 => Some(Peripheral::I2c(1)),
+            MockClockId::Spi1 => Some(Peripheral::Spi(0)),
+            MockClockId::Uart1 => Some(Peripheral::Uart(0)),
+            MockClockId::SystemClock | MockClockId::PeripheralClock => None,
+        }
+    }
+
+    /// Clock gating `peripheral`, if the mock models it
+    pub fn for_peripheral(peripheral: Peripheral) -> Option<Self> {
+        match peripheral {
+            Peripheral::I2c(0) => Some(MockClockId::I2c1),
+            Peripheral::I2c(1) => Some(MockClockId::I2c2),
+            Peripheral::Spi(0) => Some(MockClockId::Spi1),
+            Peripheral::Uart(0) => Some(MockClockId::Uart1),
+            _ => None,
+        }
+    }
 }
 
 impl From<u32> for MockClockId {
@@ -99,6 +215,8 @@ impl From<u32> for MockClockId {
             1 => MockClockId::I2c2,
             2 => MockClockId::SystemClock,
             3 => MockClockId::PeripheralClock,
+            4 => MockClockId::Spi1,
+            5 => MockClockId::Uart1,
             _ => MockClockId::I2c1, // Default fallback
         }
     }
@@ -201,18 +319,42 @@ pub struct MockSystemControl {
     /// Whether operations should succeed (for error testing)
     success_mode: bool,
     /// Clock states indexed by MockClockId
-    clock_states: [ClockState; 4],
+    clock_states: [ClockState; 6],
     /// Reset states indexed by MockResetId
     reset_states: [ResetState; 4],
+    /// Cause of the last reset reported by `last_reset_reason()`
+    reset_reason: ResetReason,
+    /// Virtual time in milliseconds, advanced by `advance_time()`
+    now_ms: u64,
+    /// Watchdog timeout window in milliseconds
+    wdt_timeout_ms: u32,
+    /// Virtual time at which the watchdog expires, `None` while disabled
+    wdt_deadline_ms: Option<u64>,
+    /// Number of resets the watchdog would have performed
+    watchdog_resets: u32,
+    /// Current power state
+    power_state: PowerState,
+    /// Clock tree frequencies in Hz, indexed by `ClockDomain`
+    domain_hz: [u32; 3],
 }
 
+/// Default clock tree frequencies in Hz: system, peripheral, always-on
+const DEFAULT_DOMAIN_HZ: [u32; 3] = [100_000_000, 24_000_000, 200_000];
+
 impl MockSystemControl {
     /// Create a new mock system control in success mode
     pub fn new() -> Self {
         Self {
             success_mode: true,
-            clock_states: [ClockState::default(); 4],
+            clock_states: [ClockState::default(); 6],
             reset_states: [ResetState::default(); 4],
+            reset_reason: ResetReason::PowerOn,
+            now_ms: 0,
+            wdt_timeout_ms: 0,
+            wdt_deadline_ms: None,
+            watchdog_resets: 0,
+            power_state: PowerState::Active,
+            domain_hz: DEFAULT_DOMAIN_HZ,
         }
     }
 
@@ -220,8 +362,15 @@ impl MockSystemControl {
     pub fn new_failing() -> Self {
         Self {
             success_mode: false,
-            clock_states: [ClockState::default(); 4],
+            clock_states: [ClockState::default(); 6],
             reset_states: [ResetState::default(); 4],
+            reset_reason: ResetReason::PowerOn,
+            now_ms: 0,
+            wdt_timeout_ms: 0,
+            wdt_deadline_ms: None,
+            watchdog_resets: 0,
+            power_state: PowerState::Active,
+            domain_hz: DEFAULT_DOMAIN_HZ,
         }
     }
 
@@ -241,6 +390,28 @@ impl MockSystemControl {
             MockClockId::I2c2 => 1,
             MockClockId::SystemClock => 2,
             MockClockId::PeripheralClock => 3,
+            MockClockId::Spi1 => 4,
+            MockClockId::Uart1 => 5,
+        }
+    }
+
+    /// Convert clock domain to array index
+    fn domain_index(&self, domain: ClockDomain) -> usize {
+        match domain {
+            ClockDomain::System => 0,
+            ClockDomain::Peripheral => 1,
+            ClockDomain::AlwaysOn => 2,
+        }
+    }
+
+    /// Set the frequency reported for a clock domain (for testing)
+    ///
+    /// A new mock reports 100 MHz for `System`, 24 MHz for `Peripheral` and
+    /// 200 kHz for `AlwaysOn`.
+    pub fn set_clock_hz(&mut self, domain: ClockDomain, hz: u32) {
+        let index = self.domain_index(domain);
+        if let Some(slot) = self.domain_hz.get_mut(index) {
+            *slot = hz;
         }
     }
 
@@ -266,6 +437,57 @@ impl MockSystemControl {
         }
     }
 
+    /// Preset the cause of the last reset (for testing)
+    ///
+    /// A new mock reports `ResetReason::PowerOn`.
+    pub fn set_reset_reason(&mut self, reason: ResetReason) {
+        self.reset_reason = reason;
+    }
+
+    /// Advance the virtual clock by `ms` milliseconds (for testing)
+    ///
+    /// If the watchdog deadline is reached without a pet, a watchdog reset
+    /// is recorded instead of resetting: `watchdog_resets()` is incremented,
+    /// the reset reason becomes `ResetReason::Watchdog`, the watchdog is
+    /// disabled and the system is `PowerState::Active`, as after a real reset.
+    /// The watchdog does not run while the system is `PowerState::Off`.
+    pub fn advance_time(&mut self, ms: u64) {
+        self.now_ms = self.now_ms.saturating_add(ms);
+        if self.power_state != PowerState::Off
+            && self
+                .wdt_deadline_ms
+                .is_some_and(|deadline| self.now_ms >= deadline)
+        {
+            self.watchdog_resets = self.watchdog_resets.saturating_add(1);
+            self.reset(ResetReason::Watchdog);
+        }
+    }
+
+    /// Simulate a power-on reset (for testing)
+    ///
+    /// The only way out of `PowerState::Off`: the system comes back
+    /// `Active` with the watchdog disabled and reports `ResetReason::PowerOn`.
+    pub fn power_on_reset(&mut self) {
+        self.reset(ResetReason::PowerOn);
+    }
+
+    /// Apply the effects of a system reset with the given cause
+    fn reset(&mut self, reason: ResetReason) {
+        self.reset_reason = reason;
+        self.wdt_deadline_ms = None;
+        self.power_state = PowerState::Active;
+    }
+
+    /// Number of resets the watchdog would have performed (for testing)
+    pub fn watchdog_resets(&self) -> u32 {
+        self.watchdog_resets
+    }
+
+    /// Check if the watchdog is currently enabled (for testing)
+    pub fn is_watchdog_enabled(&self) -> bool {
+        self.wdt_deadline_ms.is_some()
+    }
+
     /// Check if a reset is asserted (for testing)
     pub fn is_reset_asserted(
         &self,
@@ -425,6 +647,101 @@ impl ResetControl for MockSystemControl {
     }
 }
 
+impl WatchdogControl for MockSystemControl {
+    fn wdt_enable(&mut self, timeout_ms: u32) -> Result<(), Self::Error> {
+        self.check_success()?;
+        if timeout_ms == 0 {
+            return Err(MockSystemControlError::InvalidConfig);
+        }
+        self.wdt_timeout_ms = timeout_ms;
+        self.wdt_deadline_ms = Some(self.now_ms.saturating_add(u64::from(timeout_ms)));
+        Ok(())
+    }
+
+    fn wdt_pet(&mut self) -> Result<(), Self::Error> {
+        self.check_success()?;
+        if self.wdt_deadline_ms.is_some() {
+            self.wdt_deadline_ms = Some(self.now_ms.saturating_add(u64::from(self.wdt_timeout_ms)));
+        }
+        Ok(())
+    }
+
+    fn wdt_disable(&mut self) -> Result<(), Self::Error> {
+        self.check_success()?;
+        self.wdt_deadline_ms = None;
+        Ok(())
+    }
+}
+
+impl PowerControl for MockSystemControl {
+    /// Legal transitions are:
+    ///
+    /// - `Active` to any state
+    /// - `Idle` to `Active`, `DeepSleep` or `Off`
+    /// - `DeepSleep` to `Active` (wake-up)
+    /// - any state to itself (no-op)
+    ///
+    /// Leaving `Off` requires `power_on_reset()`. Other transitions return
+    /// `MockSystemControlError::BadArgument`.
+    fn set_power_state(&mut self, state: PowerState) -> Result<(), Self::Error> {
+        self.check_success()?;
+        let legal = match (self.power_state, state) {
+            (from, to) if from == to => true,
+            (PowerState::Active, _) => true,
+            (PowerState::Idle, _) => true,
+            (PowerState::DeepSleep, PowerState::Active) => true,
+            (PowerState::DeepSleep, _) | (PowerState::Off, _) => false,
+        };
+        if !legal {
+            return Err(MockSystemControlError::BadArgument);
+        }
+        self.power_state = state;
+        Ok(())
+    }
+
+    fn power_state(&self) -> PowerState {
+        self.power_state
+    }
+}
+
+impl ClockTree for MockSystemControl {
+    fn clock_hz(&self, domain: ClockDomain) -> u32 {
+        let index = self.domain_index(domain);
+        self.domain_hz.get(index).copied().unwrap_or(0)
+    }
+}
+
+/// Gates are the enable bits of the peripherals' `MockClockId` clocks, so
+/// `ClockControl::enable()`/`disable()` and these methods see the same state.
+/// Peripherals the mock does not model (see `MockClockId::for_peripheral()`)
+/// are reported gated, and ungating them has no effect. Gating works
+/// regardless of success mode.
+///
+/// The inherent `is_clock_enabled(&MockClockId)` shadows the trait method
+/// here; call it as `ClockGating::is_clock_enabled(&sys_ctrl, peripheral)`.
+impl ClockGating for MockSystemControl {
+    fn set_clock_enabled(&mut self, peripheral: Peripheral, on: bool) {
+        if let Some(clock_id) = MockClockId::for_peripheral(peripheral) {
+            let index = self.clock_index(&clock_id);
+            if let Some(state) = self.clock_states.get_mut(index) {
+                state.enabled = on;
+            }
+        }
+    }
+
+    fn is_clock_enabled(&self, peripheral: Peripheral) -> bool {
+        MockClockId::for_peripheral(peripheral)
+            .and_then(|clock_id| self.clock_states.get(self.clock_index(&clock_id)))
+            .is_some_and(|state| state.enabled)
+    }
+}
+
+impl ResetReasonReport for MockSystemControl {
+    fn last_reset_reason(&self) -> ResetReason {
+        self.reset_reason
+    }
+}
+
 // SystemControl is automatically implemented via blanket implementation
 // since MockSystemControl implements both ClockControl and ResetControl
 
@@ -562,6 +879,215 @@ mod tests {
         assert!(failing_ctrl.reset_is_asserted(&reset_id).is_err());
     }
 
+    #[test]
+    fn test_reset_reason() {
+        let mut sys_ctrl = MockSystemControl::new();
+        assert_eq!(sys_ctrl.last_reset_reason(), ResetReason::PowerOn);
+
+        for reason in [
+            ResetReason::PowerOn,
+            ResetReason::Watchdog,
+            ResetReason::Software,
+            ResetReason::Brownout,
+        ] {
+            sys_ctrl.set_reset_reason(reason);
+            assert_eq!(sys_ctrl.last_reset_reason(), reason);
+        }
+
+        // Reported regardless of success mode
+        let mut failing_ctrl = MockSystemControl::new_failing();
+        failing_ctrl.set_reset_reason(ResetReason::Brownout);
+        assert_eq!(failing_ctrl.last_reset_reason(), ResetReason::Brownout);
+    }
+
+    #[test]
+    fn test_watchdog_pet_before_timeout() {
+        let mut sys_ctrl = MockSystemControl::new();
+        assert!(sys_ctrl.wdt_enable(100).is_ok());
+        assert!(sys_ctrl.is_watchdog_enabled());
+
+        for _ in 0..5 {
+            sys_ctrl.advance_time(99);
+            assert!(sys_ctrl.wdt_pet().is_ok());
+        }
+        assert_eq!(sys_ctrl.watchdog_resets(), 0);
+        assert_eq!(sys_ctrl.last_reset_reason(), ResetReason::PowerOn);
+
+        // A disabled watchdog never fires
+        assert!(sys_ctrl.wdt_disable().is_ok());
+        sys_ctrl.advance_time(1_000);
+        assert_eq!(sys_ctrl.watchdog_resets(), 0);
+    }
+
+    #[test]
+    fn test_watchdog_missed_pet() {
+        let mut sys_ctrl = MockSystemControl::new();
+        assert!(sys_ctrl.wdt_enable(100).is_ok());
+
+        sys_ctrl.advance_time(60);
+        assert!(sys_ctrl.wdt_pet().is_ok());
+        sys_ctrl.advance_time(99);
+        assert_eq!(sys_ctrl.watchdog_resets(), 0);
+
+        sys_ctrl.advance_time(1);
+        assert_eq!(sys_ctrl.watchdog_resets(), 1);
+        assert_eq!(sys_ctrl.last_reset_reason(), ResetReason::Watchdog);
+        assert!(!sys_ctrl.is_watchdog_enabled());
+
+        // Disarmed by the reset: no further events until re-enabled
+        sys_ctrl.advance_time(1_000);
+        assert_eq!(sys_ctrl.watchdog_resets(), 1);
+    }
+
+    #[test]
+    fn test_watchdog_invalid_and_failing() {
+        let mut sys_ctrl = MockSystemControl::new();
+        assert_eq!(
+            sys_ctrl.wdt_enable(0),
+            Err(MockSystemControlError::InvalidConfig)
+        );
+        assert!(!sys_ctrl.is_watchdog_enabled());
+
+        let mut failing_ctrl = MockSystemControl::new_failing();
+        assert!(failing_ctrl.wdt_enable(100).is_err());
+        assert!(failing_ctrl.wdt_pet().is_err());
+        assert!(failing_ctrl.wdt_disable().is_err());
+    }
+
+    #[test]
+    fn test_power_state_valid_transitions() {
+        let mut sys_ctrl = MockSystemControl::new();
+        assert_eq!(sys_ctrl.power_state(), PowerState::Active);
+
+        for state in [
+            PowerState::Idle,
+            PowerState::Active,
+            PowerState::DeepSleep,
+            PowerState::Active,
+            PowerState::Idle,
+            PowerState::DeepSleep,
+            PowerState::DeepSleep,
+            PowerState::Active,
+            PowerState::Idle,
+            PowerState::Off,
+        ] {
+            assert!(sys_ctrl.set_power_state(state).is_ok(), "to {:?}", state);
+            assert_eq!(sys_ctrl.power_state(), state);
+        }
+
+        // Reset brings the system back from Off
+        sys_ctrl.power_on_reset();
+        assert_eq!(sys_ctrl.power_state(), PowerState::Active);
+        assert_eq!(sys_ctrl.last_reset_reason(), ResetReason::PowerOn);
+    }
+
+    #[test]
+    fn test_power_state_invalid_transitions() {
+        let mut sys_ctrl = MockSystemControl::new();
+
+        assert!(sys_ctrl.set_power_state(PowerState::DeepSleep).is_ok());
+        for state in [PowerState::Idle, PowerState::Off] {
+            assert_eq!(
+                sys_ctrl.set_power_state(state),
+                Err(MockSystemControlError::BadArgument)
+            );
+            assert_eq!(sys_ctrl.power_state(), PowerState::DeepSleep);
+        }
+
+        assert!(sys_ctrl.set_power_state(PowerState::Active).is_ok());
+        assert!(sys_ctrl.set_power_state(PowerState::Off).is_ok());
+        for state in [PowerState::Active, PowerState::Idle, PowerState::DeepSleep] {
+            assert_eq!(
+                sys_ctrl.set_power_state(state),
+                Err(MockSystemControlError::BadArgument)
+            );
+            assert_eq!(sys_ctrl.power_state(), PowerState::Off);
+        }
+        assert_eq!(
+            MockSystemControlError::BadArgument.kind(),
+            ErrorKind::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn test_power_state_watchdog_interaction() {
+        let mut sys_ctrl = MockSystemControl::new();
+
+        // A watchdog reset wakes the system from deep sleep
+        assert!(sys_ctrl.wdt_enable(100).is_ok());
+        assert!(sys_ctrl.set_power_state(PowerState::DeepSleep).is_ok());
+        sys_ctrl.advance_time(100);
+        assert_eq!(sys_ctrl.watchdog_resets(), 1);
+        assert_eq!(sys_ctrl.power_state(), PowerState::Active);
+
+        // The watchdog does not run while powered off
+        assert!(sys_ctrl.wdt_enable(100).is_ok());
+        assert!(sys_ctrl.set_power_state(PowerState::Off).is_ok());
+        sys_ctrl.advance_time(1_000);
+        assert_eq!(sys_ctrl.watchdog_resets(), 1);
+        assert_eq!(sys_ctrl.power_state(), PowerState::Off);
+    }
+
+    #[test]
+    fn test_clock_tree_frequencies() {
+        let mut sys_ctrl = MockSystemControl::new();
+        assert_eq!(sys_ctrl.clock_hz(ClockDomain::System), 100_000_000);
+        assert_eq!(sys_ctrl.clock_hz(ClockDomain::Peripheral), 24_000_000);
+        assert_eq!(sys_ctrl.clock_hz(ClockDomain::AlwaysOn), 200_000);
+
+        sys_ctrl.set_clock_hz(ClockDomain::Peripheral, 48_000_000);
+        assert_eq!(sys_ctrl.clock_hz(ClockDomain::Peripheral), 48_000_000);
+        // Other domains are unaffected
+        assert_eq!(sys_ctrl.clock_hz(ClockDomain::System), 100_000_000);
+
+        // 16x oversampled UART divisor computed against the queried clock
+        let divisor = sys_ctrl.clock_hz(ClockDomain::Peripheral) / (16 * 115_200);
+        assert_eq!(divisor, 26);
+
+        // Reported regardless of success mode
+        let mut failing_ctrl = MockSystemControl::new_failing();
+        failing_ctrl.set_clock_hz(ClockDomain::AlwaysOn, 32_768);
+        assert_eq!(failing_ctrl.clock_hz(ClockDomain::AlwaysOn), 32_768);
+    }
+
+    #[test]
+    fn test_clock_gating() {
+        let mut sys_ctrl = MockSystemControl::new();
+        let i2c = Peripheral::I2c(0);
+        assert!(!ClockGating::is_clock_enabled(&sys_ctrl, i2c));
+
+        sys_ctrl.set_clock_enabled(i2c, true);
+        assert!(ClockGating::is_clock_enabled(&sys_ctrl, i2c));
+        assert_eq!(sys_ctrl.is_clock_enabled(&MockClockId::I2c1), Ok(true));
+        // Gates are independent per peripheral
+        assert!(!ClockGating::is_clock_enabled(
+            &sys_ctrl,
+            Peripheral::I2c(1)
+        ));
+
+        // Shared with ClockControl
+        assert!(sys_ctrl.disable(&MockClockId::I2c1).is_ok());
+        assert!(!ClockGating::is_clock_enabled(&sys_ctrl, i2c));
+        assert!(sys_ctrl.enable(&MockClockId::Uart1).is_ok());
+        assert!(ClockGating::is_clock_enabled(
+            &sys_ctrl,
+            Peripheral::Uart(0)
+        ));
+
+        // Unmodelled peripherals stay gated
+        sys_ctrl.set_clock_enabled(Peripheral::Spi(3), true);
+        assert!(!ClockGating::is_clock_enabled(
+            &sys_ctrl,
+            Peripheral::Spi(3)
+        ));
+
+        for clock_id in [MockClockId::I2c2, MockClockId::Spi1] {
+            let peripheral = clock_id.peripheral().expect("gated clock");
+            assert_eq!(MockClockId::for_peripheral(peripheral), Some(clock_id));
+        }
+        assert_eq!(MockClockId::SystemClock.peripheral(), None);
+    }
+
     #[test]
     fn test_id_conversions() {
         // Test clock ID conversions
@@ -0,0 +1,79 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mock Monotonic Timer
+//!
+//! Provides a virtual clock that only moves when a test advances it, so
+//! timeout behavior can be exercised deterministically.
+
+use core::cell::Cell;
+use openprot_hal_blocking::timer::MonotonicTimer;
+
+/// Manually advanced virtual time, starting at zero
+///
+/// Advancing takes `&self`, so a test can keep a shared reference to the
+/// timer it handed to the code under test.
+#[derive(Debug, Default)]
+pub struct MockTimer {
+    micros: Cell<u64>,
+}
+
+impl MockTimer {
+    /// Create a timer reading zero
+    pub const fn new() -> Self {
+        Self {
+            micros: Cell::new(0),
+        }
+    }
+
+    /// Move virtual time forward by `micros` microseconds
+    pub fn advance_micros(&self, micros: u64) {
+        self.micros.set(self.micros.get().saturating_add(micros));
+    }
+
+    /// Move virtual time forward by `millis` milliseconds
+    pub fn advance_millis(&self, millis: u64) {
+        self.advance_micros(millis.saturating_mul(1000));
+    }
+}
+
+impl MonotonicTimer for MockTimer {
+    fn now_millis(&self) -> u64 {
+        self.micros.get() / 1000
+    }
+
+    fn now_micros(&self) -> u64 {
+        self.micros.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advancing_updates_both_resolutions() {
+        let timer = MockTimer::new();
+        assert_eq!(timer.now_millis(), 0);
+        assert_eq!(timer.now_micros(), 0);
+
+        timer.advance_millis(5);
+        assert_eq!(timer.now_millis(), 5);
+        assert_eq!(timer.now_micros(), 5_000);
+
+        timer.advance_micros(1_999);
+        assert_eq!(timer.now_micros(), 6_999);
+        // Milliseconds truncate; they only tick over on a full millisecond.
+        assert_eq!(timer.now_millis(), 6);
+        timer.advance_micros(1);
+        assert_eq!(timer.now_millis(), 7);
+    }
+
+    #[test]
+    fn shared_reference_observes_advances() {
+        let timer = MockTimer::new();
+        let consumer: &dyn MonotonicTimer = &timer;
+        timer.advance_millis(250);
+        assert_eq!(consumer.now_millis(), 250);
+    }
+}
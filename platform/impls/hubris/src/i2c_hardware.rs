@@ -0,0 +1,417 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hubris I2C Hardware Implementation
+//!
+//! Implements the I2C hardware traits by forwarding transfers to the Hubris
+//! I2C server task, with the same trait surface as the mock platform's
+//! `i2c_hardware` module so driver code is portable between the two.
+//!
+//! The server owns the controller: bus speed, pin muxing and recovery are
+//! set by the application configuration, not by this client. Transfers go
+//! through the [`I2cServer`] IPC seam, which an application binds to a
+//! `drv-i2c-api` device handle and tests replace with a recording stub.
+//!
+//! The Hubris server addresses targets with 7-bit addresses only, so
+//! [`I2cMaster`] is implemented for [`SevenBitAddress`] alone and untyped
+//! address literals resolve without annotations. The [`I2cMasterTenBit`]
+//! operations are rejected with [`HubrisI2cError::BadArgument`].
+
+use embedded_hal::i2c::{ErrorType, Operation, SevenBitAddress, TenBitAddress};
+use openprot_hal_blocking::i2c_hardware::{I2cHardwareCore, I2cMaster, I2cMasterTenBit};
+
+/// Response codes returned by the Hubris I2C server
+///
+/// Mirrors the `drv-i2c-api` `ResponseCode` variants a client transfer can
+/// observe; the IPC binding converts the server's code into this type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseCode {
+    /// Malformed response from the server
+    BadResponse,
+    /// Bad argument sent to the server
+    BadArg,
+    /// No device at the addressed location
+    NoDevice,
+    /// Device did not acknowledge the register address
+    NoRegister,
+    /// Address is reserved by the I2C specification
+    ReservedAddress,
+    /// Bus was reset by the server during the transfer
+    BusReset,
+    /// Bus is locked (SDA held low) and could not be recovered
+    BusLocked,
+    /// General bus error
+    BusError,
+    /// Controller is busy with another transfer
+    ControllerBusy,
+    /// Transfer exceeds what the server can lease in one operation
+    TooMuchData,
+    /// Operation is not supported by the controller
+    OperationNotSupported,
+}
+
+/// IPC seam to the Hubris I2C server
+///
+/// One implementation addresses one controller/port/segment; the target
+/// address is supplied per transfer.
+pub trait I2cServer {
+    /// Perform one server `WriteRead` operation: write `write` (if
+    /// non-empty), then with a repeated start read `read.len()` bytes (if
+    /// non-empty), as a single bus transaction.
+    fn write_read(
+        &mut self,
+        addr: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), ResponseCode>;
+}
+
+/// Hubris I2C error type
+///
+/// Each variant shares its name and [`embedded_hal::i2c::ErrorKind`] with the
+/// mock's error type, so callers matching on the platform error behave the
+/// same on both. The server does not report arbitration loss, PEC
+/// mismatches, timeouts or a gated clock as such, so those mock variants
+/// have no counterpart here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HubrisI2cError {
+    /// Bus error (line stuck, bus reset, controller busy)
+    Bus,
+    /// No acknowledge received from the addressed device
+    NoAcknowledge,
+    /// Invalid argument (address out of range, 10-bit address, oversized transfer)
+    BadArgument,
+    /// Other unspecified error
+    Other,
+}
+
+impl From<ResponseCode> for HubrisI2cError {
+    fn from(code: ResponseCode) -> Self {
+        match code {
+            ResponseCode::NoDevice | ResponseCode::NoRegister => HubrisI2cError::NoAcknowledge,
+            ResponseCode::BusReset
+            | ResponseCode::BusLocked
+            | ResponseCode::BusError
+            | ResponseCode::ControllerBusy => HubrisI2cError::Bus,
+            ResponseCode::BadArg | ResponseCode::ReservedAddress | ResponseCode::TooMuchData => {
+                HubrisI2cError::BadArgument
+            }
+            ResponseCode::BadResponse | ResponseCode::OperationNotSupported => {
+                HubrisI2cError::Other
+            }
+        }
+    }
+}
+
+impl embedded_hal::i2c::Error for HubrisI2cError {
+    fn kind(&self) -> embedded_hal::i2c::ErrorKind {
+        match self {
+            HubrisI2cError::Bus => embedded_hal::i2c::ErrorKind::Bus,
+            HubrisI2cError::NoAcknowledge => embedded_hal::i2c::ErrorKind::NoAcknowledge(
+                embedded_hal::i2c::NoAcknowledgeSource::Unknown,
+            ),
+            HubrisI2cError::BadArgument | HubrisI2cError::Other => {
+                embedded_hal::i2c::ErrorKind::Other
+            }
+        }
+    }
+}
+
+/// I2C master backed by the Hubris I2C server
+pub struct HubrisI2cHardware<S> {
+    server: S,
+}
+
+impl<S: I2cServer> HubrisI2cHardware<S> {
+    /// Create a master issuing transfers through `server`
+    pub fn new(server: S) -> Self {
+        Self { server }
+    }
+
+    /// Release the underlying server handle
+    pub fn into_inner(self) -> S {
+        self.server
+    }
+
+    fn transfer(
+        &mut self,
+        addr: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), HubrisI2cError> {
+        if addr > 0x7F {
+            return Err(HubrisI2cError::BadArgument);
+        }
+        Ok(self.server.write_read(addr, write, read)?)
+    }
+}
+
+impl<S> ErrorType for HubrisI2cHardware<S> {
+    type Error = HubrisI2cError;
+}
+
+impl<S: I2cServer> I2cHardwareCore for HubrisI2cHardware<S> {
+    type Config = ();
+    type I2cSpeed = u32; // Speed in Hz
+    type TimingConfig = ();
+
+    /// The server initializes the controller at boot; nothing to do here
+    fn init(&mut self, _config: &mut Self::Config) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Bus timing is fixed by the server's configuration and cannot be
+    /// changed by a client
+    fn configure_timing(
+        &mut self,
+        _speed: Self::I2cSpeed,
+        _timing: &Self::TimingConfig,
+    ) -> Result<u32, Self::Error> {
+        Err(HubrisI2cError::Other)
+    }
+
+    // Controller interrupts are owned and serviced by the server task.
+    fn enable_interrupts(&mut self, _mask: u32) {}
+
+    fn clear_interrupts(&mut self, _mask: u32) {}
+
+    fn handle_interrupt(&mut self) {}
+}
+
+impl<S: I2cServer> I2cMaster<SevenBitAddress> for HubrisI2cHardware<S> {
+    fn write(&mut self, addr: SevenBitAddress, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.transfer(addr, bytes, &mut [])
+    }
+
+    fn read(&mut self, addr: SevenBitAddress, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.transfer(addr, &[], buffer)
+    }
+
+    fn write_read(
+        &mut self,
+        addr: SevenBitAddress,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.transfer(addr, bytes, buffer)
+    }
+
+    /// The server chains at most one write and one read under a single
+    /// START, so a transaction may be a write, a read, or a write followed
+    /// by a read; it is issued as one server `WriteRead`. Any other sequence
+    /// is rejected with [`HubrisI2cError::BadArgument`] before reaching the
+    /// bus, since splitting it would release the bus between operations.
+    fn transaction_slice(
+        &mut self,
+        addr: SevenBitAddress,
+        ops_slice: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match ops_slice {
+            [] => Ok(()),
+            [Operation::Write(bytes)] => self.transfer(addr, bytes, &mut []),
+            [Operation::Read(buffer)] => self.transfer(addr, &[], buffer),
+            [Operation::Write(bytes), Operation::Read(buffer)] => {
+                self.transfer(addr, bytes, buffer)
+            }
+            _ => Err(HubrisI2cError::BadArgument),
+        }
+    }
+}
+
+// The Hubris server has no 10-bit addressing; reject rather than truncate.
+impl<S: I2cServer> I2cMasterTenBit for HubrisI2cHardware<S> {
+    fn write_ten_bit(&mut self, _addr: TenBitAddress, _bytes: &[u8]) -> Result<(), Self::Error> {
+        Err(HubrisI2cError::BadArgument)
+    }
+
+    fn read_ten_bit(
+        &mut self,
+        _addr: TenBitAddress,
+        _buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        Err(HubrisI2cError::BadArgument)
+    }
+
+    fn write_read_ten_bit(
+        &mut self,
+        _addr: TenBitAddress,
+        _bytes: &[u8],
+        _buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        Err(HubrisI2cError::BadArgument)
+    }
+
+    fn transaction_slice_ten_bit(
+        &mut self,
+        _addr: TenBitAddress,
+        _ops_slice: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        Err(HubrisI2cError::BadArgument)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_hal::i2c::Error as _;
+
+    const MAX_REQUESTS: usize = 8;
+    const MAX_WRITE: usize = 8;
+
+    /// One `WriteRead` request as seen by the fake server
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    struct Request {
+        addr: u8,
+        write: [u8; MAX_WRITE],
+        write_len: usize,
+        read_len: usize,
+    }
+
+    /// Fake Hubris I2C server that records every request
+    #[derive(Default)]
+    struct FakeServer {
+        requests: [Request; MAX_REQUESTS],
+        count: usize,
+        response: u8,
+        fail: Option<ResponseCode>,
+    }
+
+    impl FakeServer {
+        fn requests(&self) -> &[Request] {
+            &self.requests[..self.count]
+        }
+    }
+
+    impl I2cServer for FakeServer {
+        fn write_read(
+            &mut self,
+            addr: SevenBitAddress,
+            write: &[u8],
+            read: &mut [u8],
+        ) -> Result<(), ResponseCode> {
+            let slot = self
+                .requests
+                .get_mut(self.count)
+                .ok_or(ResponseCode::BadArg)?;
+            slot.addr = addr;
+            slot.write[..write.len()].copy_from_slice(write);
+            slot.write_len = write.len();
+            slot.read_len = read.len();
+            self.count += 1;
+            if let Some(code) = self.fail {
+                return Err(code);
+            }
+            for (i, byte) in read.iter_mut().enumerate() {
+                *byte = self.response.wrapping_add(i as u8);
+            }
+            Ok(())
+        }
+    }
+
+    fn request(addr: u8, write: &[u8], read_len: usize) -> Request {
+        let mut req = Request {
+            addr,
+            write_len: write.len(),
+            read_len,
+            ..Request::default()
+        };
+        req.write[..write.len()].copy_from_slice(write);
+        req
+    }
+
+    #[test]
+    fn write_read_forwards_one_request() {
+        let mut i2c = HubrisI2cHardware::new(FakeServer {
+            response: 0xA0,
+            ..FakeServer::default()
+        });
+        let mut buf = [0u8; 3];
+        i2c.write_read(0x50, &[0x10], &mut buf).unwrap();
+        i2c.write(0x50, &[0x20, 0x01]).unwrap();
+
+        assert_eq!(buf, [0xA0, 0xA1, 0xA2]);
+        let server = i2c.into_inner();
+        assert_eq!(
+            server.requests(),
+            [request(0x50, &[0x10], 3), request(0x50, &[0x20, 0x01], 0)]
+        );
+    }
+
+    #[test]
+    fn transaction_issued_as_one_write_read() {
+        let mut i2c = HubrisI2cHardware::new(FakeServer {
+            response: 0x30,
+            ..FakeServer::default()
+        });
+        let mut buf = [0u8; 2];
+        let mut ops = [Operation::Write(&[0x01]), Operation::Read(&mut buf)];
+        i2c.transaction_slice(0x22, &mut ops).unwrap();
+        i2c.transaction_slice(0x22, &mut []).unwrap();
+
+        assert_eq!(buf, [0x30, 0x31]);
+        let server = i2c.into_inner();
+        assert_eq!(server.requests(), [request(0x22, &[0x01], 2)]);
+    }
+
+    #[test]
+    fn transaction_beyond_one_write_read_rejected() {
+        let mut i2c = HubrisI2cHardware::new(FakeServer::default());
+        let mut first = [0u8; 2];
+        let mut second = [0u8; 1];
+        let mut sequences: [&mut [Operation<'_>]; 3] = [
+            &mut [Operation::Read(&mut first), Operation::Write(&[0x01])],
+            &mut [Operation::Write(&[0x02]), Operation::Write(&[0x03])],
+            &mut [
+                Operation::Write(&[0x04]),
+                Operation::Read(&mut second),
+                Operation::Write(&[0x05]),
+            ],
+        ];
+        for ops in &mut sequences {
+            assert_eq!(
+                i2c.transaction_slice(0x22, ops),
+                Err(HubrisI2cError::BadArgument)
+            );
+        }
+        // None of them reached the server.
+        assert!(i2c.into_inner().requests().is_empty());
+    }
+
+    #[test]
+    fn out_of_range_and_ten_bit_addresses_rejected() {
+        let mut i2c = HubrisI2cHardware::new(FakeServer::default());
+        assert_eq!(i2c.write(0x80, &[0x00]), Err(HubrisI2cError::BadArgument));
+        assert_eq!(
+            i2c.write_ten_bit(0x150, &[0x00]),
+            Err(HubrisI2cError::BadArgument)
+        );
+        // Neither reached the server.
+        assert!(i2c.into_inner().requests().is_empty());
+    }
+
+    #[test]
+    fn server_errors_map_to_platform_errors() {
+        let cases = [
+            (ResponseCode::NoDevice, HubrisI2cError::NoAcknowledge),
+            (ResponseCode::BusLocked, HubrisI2cError::Bus),
+            (ResponseCode::ControllerBusy, HubrisI2cError::Bus),
+            (ResponseCode::ReservedAddress, HubrisI2cError::BadArgument),
+            (ResponseCode::BadResponse, HubrisI2cError::Other),
+        ];
+        for (code, expected) in cases {
+            let mut i2c = HubrisI2cHardware::new(FakeServer {
+                fail: Some(code),
+                ..FakeServer::default()
+            });
+            let mut buf = [0u8; 1];
+            let err = i2c.read(0x40, &mut buf).unwrap_err();
+            assert_eq!(err, expected);
+        }
+        assert_eq!(
+            HubrisI2cError::NoAcknowledge.kind(),
+            embedded_hal::i2c::ErrorKind::NoAcknowledge(
+                embedded_hal::i2c::NoAcknowledgeSource::Unknown
+            )
+        );
+    }
+}
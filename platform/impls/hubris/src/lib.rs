@@ -6,3 +6,5 @@
 //! This crate provides Hubris OS-specific implementations of platform abstraction traits.
 
 #![no_std]
+
+pub mod i2c_hardware;
@@ -0,0 +1,367 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tock Hash/Digest Implementation
+//!
+//! Implements the digest traits on top of the Tock kernel SHA driver, with
+//! the same trait surface as the mock platform's `hash` module:
+//! - **Scoped API**: [`TockDigestDevice`] lends itself to a [`TockHasher`]
+//! - **Owned API**: [`owned::TockDigestController`] moves into an owned context
+//!
+//! All kernel access goes through [`ShaSyscalls`], so the same code runs
+//! against the real driver and against a host-side fake.
+
+use openprot_hal_blocking::digest::{
+    Digest, DigestAlgorithm, ErrorKind, ErrorType, Sha2_256, Sha2_384, Sha2_512,
+};
+
+use openprot_hal_blocking::digest::scoped::{DigestCtrlReset, DigestInit, DigestOp};
+
+use crate::syscall::{ErrorCode, ShaAlgorithm, ShaSyscalls};
+
+/// Largest digest produced by the kernel driver, in bytes.
+const MAX_DIGEST_LEN: usize = 64;
+
+/// Digest error carrying the kernel error code that caused it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TockDigestError(pub ErrorCode);
+
+impl openprot_hal_blocking::digest::Error for TockDigestError {
+    fn kind(&self) -> ErrorKind {
+        match self.0 {
+            ErrorCode::Busy => ErrorKind::Busy,
+            ErrorCode::NoSupport => ErrorKind::UnsupportedAlgorithm,
+            ErrorCode::Size | ErrorCode::Invalid => ErrorKind::InvalidInputLength,
+            ErrorCode::Reserve => ErrorKind::PermissionDenied,
+            _ => ErrorKind::HardwareFailure,
+        }
+    }
+}
+
+impl From<ErrorCode> for TockDigestError {
+    fn from(code: ErrorCode) -> Self {
+        Self(code)
+    }
+}
+
+/// Run the driver's `finish` command and pack the digest into words.
+///
+/// Words are read little-endian so that [`Digest::as_bytes`] yields the
+/// digest in its standard byte order on Tock's little-endian targets.
+fn finish<S: ShaSyscalls, const N: usize>(syscalls: &mut S) -> Result<Digest<N>, TockDigestError> {
+    let mut bytes = [0u8; MAX_DIGEST_LEN];
+    syscalls.finish(&mut bytes[..N * 4])?;
+    let mut value = [0u32; N];
+    for (word, chunk) in value.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    Ok(Digest::new(value))
+}
+
+/// Digest device backed by the Tock kernel SHA driver.
+pub struct TockDigestDevice<S> {
+    syscalls: S,
+}
+
+impl<S: ShaSyscalls> TockDigestDevice<S> {
+    /// Create a digest device issuing commands through `syscalls`.
+    pub fn new(syscalls: S) -> Self {
+        Self { syscalls }
+    }
+
+    /// Release the underlying syscall shim.
+    pub fn into_inner(self) -> S {
+        self.syscalls
+    }
+}
+
+impl<S> ErrorType for TockDigestDevice<S> {
+    type Error = TockDigestError;
+}
+
+impl<S: ShaSyscalls> DigestCtrlReset for TockDigestDevice<S> {
+    fn reset(&mut self) -> Result<(), Self::Error> {
+        // The driver keeps no state beyond the current operation, which the
+        // next `set_algorithm` discards.
+        Ok(())
+    }
+}
+
+//
+// SCOPED API IMPLEMENTATION
+//
+
+/// Hasher context borrowing the device for the duration of one operation.
+pub struct TockHasher<'a, S, T> {
+    hw: &'a mut TockDigestDevice<S>,
+    _alg: T,
+}
+
+impl<S, T> ErrorType for TockHasher<'_, S, T> {
+    type Error = TockDigestError;
+}
+
+/// Macro to implement scoped digest traits for each algorithm
+macro_rules! impl_scoped_sha2 {
+    ($algo:ident, $selector:expr) => {
+        impl<S: ShaSyscalls> DigestInit<$algo> for TockDigestDevice<S> {
+            type OpContext<'a>
+                = TockHasher<'a, S, $algo>
+            where
+                Self: 'a;
+
+            fn init(&mut self, algorithm: $algo) -> Result<Self::OpContext<'_>, Self::Error> {
+                self.syscalls.set_algorithm($selector)?;
+                Ok(TockHasher {
+                    hw: self,
+                    _alg: algorithm,
+                })
+            }
+        }
+
+        impl<S: ShaSyscalls> DigestOp for TockHasher<'_, S, $algo> {
+            type Output = <$algo as DigestAlgorithm>::Digest;
+
+            fn update(&mut self, input: &[u8]) -> Result<(), Self::Error> {
+                Ok(self.hw.syscalls.update(input)?)
+            }
+
+            fn finalize(self) -> Result<Self::Output, Self::Error> {
+                finish(&mut self.hw.syscalls)
+            }
+        }
+    };
+}
+
+impl_scoped_sha2!(Sha2_256, ShaAlgorithm::Sha256);
+impl_scoped_sha2!(Sha2_384, ShaAlgorithm::Sha384);
+impl_scoped_sha2!(Sha2_512, ShaAlgorithm::Sha512);
+
+//
+// OWNED API IMPLEMENTATION (Move-based Resource Management)
+//
+
+/// Owned digest API for sessions that outlive a single call.
+pub mod owned {
+    use super::*;
+    use openprot_hal_blocking::digest::owned::{DigestInit, DigestOp};
+
+    /// Controller for owned digest operations on the kernel SHA driver.
+    pub struct TockDigestController<S> {
+        syscalls: S,
+    }
+
+    impl<S: ShaSyscalls> TockDigestController<S> {
+        /// Create a controller issuing commands through `syscalls`.
+        pub fn new(syscalls: S) -> Self {
+            Self { syscalls }
+        }
+
+        /// Release the underlying syscall shim.
+        pub fn into_inner(self) -> S {
+            self.syscalls
+        }
+    }
+
+    impl<S> ErrorType for TockDigestController<S> {
+        type Error = TockDigestError;
+    }
+
+    /// Owned digest context holding the controller until finalized.
+    pub struct TockOwnedContext<S, T> {
+        controller: TockDigestController<S>,
+        _alg: T,
+    }
+
+    impl<S, T> ErrorType for TockOwnedContext<S, T> {
+        type Error = TockDigestError;
+    }
+
+    /// Macro to implement owned digest traits for each algorithm
+    macro_rules! impl_owned_sha2 {
+        ($algo:ident, $selector:expr) => {
+            impl<S: ShaSyscalls> DigestInit<$algo> for TockDigestController<S> {
+                type Context = TockOwnedContext<S, $algo>;
+
+                fn init(mut self, algorithm: $algo) -> Result<Self::Context, Self::Error> {
+                    self.syscalls.set_algorithm($selector)?;
+                    Ok(TockOwnedContext {
+                        controller: self,
+                        _alg: algorithm,
+                    })
+                }
+            }
+
+            impl<S: ShaSyscalls> DigestOp for TockOwnedContext<S, $algo> {
+                type Output = <$algo as DigestAlgorithm>::Digest;
+                type Controller = TockDigestController<S>;
+
+                fn update(mut self, data: &[u8]) -> Result<Self, Self::Error> {
+                    self.controller.syscalls.update(data)?;
+                    Ok(self)
+                }
+
+                fn finalize(mut self) -> Result<(Self::Output, Self::Controller), Self::Error> {
+                    let digest = finish(&mut self.controller.syscalls)?;
+                    Ok((digest, self.controller))
+                }
+
+                fn cancel(mut self) -> Self::Controller {
+                    // Close the kernel operation; the digest is discarded and
+                    // any error leaves state the next `init` resets anyway.
+                    let mut scratch = [0u8; MAX_DIGEST_LEN];
+                    let _ = self.controller.syscalls.finish(&mut scratch);
+                    self.controller
+                }
+            }
+        };
+    }
+
+    impl_owned_sha2!(Sha2_256, ShaAlgorithm::Sha256);
+    impl_owned_sha2!(Sha2_384, ShaAlgorithm::Sha384);
+    impl_owned_sha2!(Sha2_512, ShaAlgorithm::Sha512);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::owned::TockDigestController;
+    use super::*;
+    use openprot_hal_blocking::digest::Error as _;
+    use sha2::{Digest as _, Sha256, Sha384, Sha512};
+
+    /// Host-side stand-in for the kernel SHA driver.
+    #[derive(Default)]
+    struct FakeSha {
+        state: Option<FakeState>,
+        updates: usize,
+        fail_update: Option<ErrorCode>,
+    }
+
+    enum FakeState {
+        Sha256(Sha256),
+        Sha384(Sha384),
+        Sha512(Sha512),
+    }
+
+    impl ShaSyscalls for FakeSha {
+        fn set_algorithm(&mut self, algorithm: ShaAlgorithm) -> Result<(), ErrorCode> {
+            self.state = Some(match algorithm {
+                ShaAlgorithm::Sha256 => FakeState::Sha256(Sha256::new()),
+                ShaAlgorithm::Sha384 => FakeState::Sha384(Sha384::new()),
+                ShaAlgorithm::Sha512 => FakeState::Sha512(Sha512::new()),
+            });
+            Ok(())
+        }
+
+        fn update(&mut self, data: &[u8]) -> Result<(), ErrorCode> {
+            if let Some(code) = self.fail_update {
+                return Err(code);
+            }
+            self.updates += 1;
+            match self.state.as_mut().ok_or(ErrorCode::Reserve)? {
+                FakeState::Sha256(h) => h.update(data),
+                FakeState::Sha384(h) => h.update(data),
+                FakeState::Sha512(h) => h.update(data),
+            }
+            Ok(())
+        }
+
+        fn finish(&mut self, digest: &mut [u8]) -> Result<(), ErrorCode> {
+            let mut copy = |out: &[u8]| {
+                digest
+                    .get_mut(..out.len())
+                    .ok_or(ErrorCode::Size)?
+                    .copy_from_slice(out);
+                Ok(())
+            };
+            match self.state.take().ok_or(ErrorCode::Reserve)? {
+                FakeState::Sha256(h) => copy(&h.finalize()),
+                FakeState::Sha384(h) => copy(&h.finalize()),
+                FakeState::Sha512(h) => copy(&h.finalize()),
+            }
+        }
+    }
+
+    fn hex(s: &str) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        for (i, byte) in out.iter_mut().take(s.len() / 2).enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    const SHA256_ABC: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    const SHA384_ABC: &str = "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed\
+                              8086072ba1e7cc2358baeca134c825a7";
+    const SHA512_ABC: &str = "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+                              2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f";
+
+    #[test]
+    fn scoped_sha256_known_vector() {
+        let mut device = TockDigestDevice::new(FakeSha::default());
+        let mut ctx = device.init(Sha2_256).unwrap();
+        ctx.update(b"a").unwrap();
+        ctx.update(b"bc").unwrap();
+        let digest = ctx.finalize().unwrap();
+        assert_eq!(digest.as_bytes(), &hex(SHA256_ABC)[..32]);
+        assert_eq!(device.into_inner().updates, 2);
+    }
+
+    #[test]
+    fn scoped_sha384_and_sha512_known_vectors() {
+        let mut device = TockDigestDevice::new(FakeSha::default());
+        let mut ctx = device.init(Sha2_384).unwrap();
+        ctx.update(b"abc").unwrap();
+        assert_eq!(ctx.finalize().unwrap().as_bytes(), &hex(SHA384_ABC)[..48]);
+
+        let mut ctx = device.init(Sha2_512).unwrap();
+        ctx.update(b"abc").unwrap();
+        assert_eq!(ctx.finalize().unwrap().as_bytes(), &hex(SHA512_ABC)[..]);
+    }
+
+    #[test]
+    fn owned_sha256_known_vector_returns_controller() {
+        use openprot_hal_blocking::digest::owned::{DigestInit as _, DigestOp as _};
+
+        let controller = TockDigestController::new(FakeSha::default());
+        let ctx = controller.init(Sha2_256).unwrap();
+        let (digest, controller) = ctx.update(b"abc").unwrap().finalize().unwrap();
+        assert_eq!(digest.as_bytes(), &hex(SHA256_ABC)[..32]);
+
+        // The returned controller starts a fresh operation.
+        let ctx = controller.init(Sha2_512).unwrap();
+        let (digest, _) = ctx.update(b"abc").unwrap().finalize().unwrap();
+        assert_eq!(digest.as_bytes(), &hex(SHA512_ABC)[..]);
+    }
+
+    #[test]
+    fn owned_cancel_closes_kernel_operation() {
+        use openprot_hal_blocking::digest::owned::{DigestInit as _, DigestOp as _};
+
+        let controller = TockDigestController::new(FakeSha::default());
+        let ctx = controller.init(Sha2_384).unwrap();
+        let controller = ctx.update(b"partial").unwrap().cancel();
+        assert!(controller.into_inner().state.is_none());
+    }
+
+    #[test]
+    fn kernel_errors_map_to_error_kinds() {
+        let mut device = TockDigestDevice::new(FakeSha {
+            fail_update: Some(ErrorCode::Busy),
+            ..FakeSha::default()
+        });
+        let mut ctx = device.init(Sha2_256).unwrap();
+        let err = ctx.update(b"abc").unwrap_err();
+        assert_eq!(err, TockDigestError(ErrorCode::Busy));
+        assert_eq!(err.kind(), ErrorKind::Busy);
+
+        assert_eq!(
+            TockDigestError(ErrorCode::NoSupport).kind(),
+            ErrorKind::UnsupportedAlgorithm
+        );
+        assert_eq!(
+            TockDigestError(ErrorCode::Fail).kind(),
+            ErrorKind::HardwareFailure
+        );
+    }
+}
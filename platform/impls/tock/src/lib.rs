@@ -6,3 +6,6 @@
 //! This crate provides Tock OS-specific implementations of platform abstraction traits.
 
 #![no_std]
+
+pub mod hash;
+pub mod syscall;
@@ -0,0 +1,82 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tock syscall shim
+//!
+//! The platform implementations in this crate do not issue system calls
+//! directly. They talk to the kernel through the small blocking traits
+//! defined here, which an application binds to the real driver (e.g. via
+//! `libtock` allow/subscribe/command/yield) and tests replace with a fake.
+
+/// Tock kernel error codes, as returned in a syscall failure variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ErrorCode {
+    /// Generic failure condition
+    Fail = 1,
+    /// Underlying system is busy; retry
+    Busy = 2,
+    /// The state requested is already set
+    Already = 3,
+    /// The component is powered down
+    Off = 4,
+    /// Reservation required before use
+    Reserve = 5,
+    /// An invalid parameter was passed
+    Invalid = 6,
+    /// Parameter passed was too large
+    Size = 7,
+    /// Operation canceled by a call
+    Cancel = 8,
+    /// Memory required not available
+    NoMem = 9,
+    /// Operation is not supported
+    NoSupport = 10,
+    /// Device is not available
+    NoDevice = 11,
+    /// Device is not physically installed
+    Uninstalled = 12,
+    /// Packet transmission not acknowledged
+    NoAck = 13,
+}
+
+/// Hash algorithms selectable on the kernel SHA driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShaAlgorithm {
+    /// SHA-256 (32-byte digest)
+    Sha256,
+    /// SHA-384 (48-byte digest)
+    Sha384,
+    /// SHA-512 (64-byte digest)
+    Sha512,
+}
+
+impl ShaAlgorithm {
+    /// Digest length in bytes.
+    pub const fn digest_len(self) -> usize {
+        match self {
+            Self::Sha256 => 32,
+            Self::Sha384 => 48,
+            Self::Sha512 => 64,
+        }
+    }
+}
+
+/// Blocking view of the kernel SHA driver.
+///
+/// Each method corresponds to one driver command: the implementation shares
+/// the buffer with the kernel, issues the command and yields until the
+/// completion upcall arrives. A hash operation is `set_algorithm`, any
+/// number of `update` calls, then `finish`.
+pub trait ShaSyscalls {
+    /// Select the algorithm and start a new operation, discarding any
+    /// operation still in progress.
+    fn set_algorithm(&mut self, algorithm: ShaAlgorithm) -> Result<(), ErrorCode>;
+
+    /// Absorb `data` into the current operation.
+    fn update(&mut self, data: &[u8]) -> Result<(), ErrorCode>;
+
+    /// End the current operation and write the digest into `digest`, which
+    /// must be at least the algorithm's digest length.
+    fn finish(&mut self, digest: &mut [u8]) -> Result<(), ErrorCode>;
+}
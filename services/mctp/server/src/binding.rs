@@ -0,0 +1,85 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Frame check validation for transport bindings.
+//!
+//! [`Server::inbound`] expects a bare MCTP packet. Bindings whose frames end
+//! in a frame check sequence can hand the whole frame to
+//! [`Server::inbound_with_fcs`] instead, which verifies and strips the
+//! binding's framing before the packet reaches the router.
+
+use openprot_hal_blocking::i2c_hardware::smbus_pec;
+use openprot_mctp_api::{MctpError, ResponseCode};
+
+use crate::{DropReason, InboundDisposition, Sender, Server};
+
+/// SMBus command code for MCTP (DSP0237).
+const SMBUS_MCTP_COMMAND: u8 = 0x0F;
+
+/// SMBus frame bytes before the MCTP packet: destination address, command
+/// code, byte count and source address.
+const SMBUS_HEADER_LEN: usize = 4;
+
+/// SMBus frame bytes counted by the byte count field besides the packet:
+/// the source address.
+const SMBUS_COUNTED_HEADER_LEN: usize = 1;
+
+/// Transport framing around an inbound MCTP packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Binding {
+    /// The frame is the bare MCTP packet, with no frame check.
+    Raw,
+    /// MCTP over SMBus/I2C (DSP0237): destination address, command code,
+    /// byte count and source address, then the packet and a trailing PEC
+    /// computed over every preceding byte.
+    Smbus,
+}
+
+impl Binding {
+    /// Verify the frame check and return the MCTP packet it carries.
+    fn strip<'a>(&self, frame: &'a [u8]) -> Option<&'a [u8]> {
+        match self {
+            Binding::Raw => Some(frame),
+            Binding::Smbus => {
+                let (&pec, body) = frame.split_last()?;
+                let &[_, command, count, ..] = body else {
+                    return None;
+                };
+                if command != SMBUS_MCTP_COMMAND
+                    || body.len() < SMBUS_HEADER_LEN
+                    || count as usize != body.len() - SMBUS_HEADER_LEN + SMBUS_COUNTED_HEADER_LEN
+                    || smbus_pec(0, body) != pec
+                {
+                    return None;
+                }
+                Some(&body[SMBUS_HEADER_LEN..])
+            }
+        }
+    }
+}
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Feed a transport frame to the router after checking its framing.
+    ///
+    /// Verifies and strips the framing described by `binding`, then passes
+    /// the packet to [`inbound`](Self::inbound). A frame whose frame check
+    /// fails, or whose framing is malformed, is rejected with `BadArgument`
+    /// and counted in [`ServerStats::inbound_errors`](crate::ServerStats).
+    pub fn inbound_with_fcs(
+        &mut self,
+        frame: &[u8],
+        binding: Binding,
+    ) -> Result<InboundDisposition, MctpError> {
+        match binding.strip(frame) {
+            Some(pkt) => self.inbound(pkt),
+            None => {
+                self.log_drop(DropReason::FrameCheck, frame);
+                self.count_inbound_rejection();
+                Err(MctpError::from_code(ResponseCode::BadArgument))
+            }
+        }
+    }
+}
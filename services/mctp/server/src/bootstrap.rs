@@ -0,0 +1,90 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bootstrap mode for endpoints awaiting an EID assignment.
+//!
+//! Before the bus owner assigns an EID, an endpoint only needs to answer
+//! the control protocol, which the bus owner addresses to the null EID
+//! during enumeration. [`Server::set_bootstrap`] drops all other inbound
+//! traffic before it reaches the router until [`Server::set_eid`] assigns
+//! a real EID. From then on packets addressed to the null EID are dropped
+//! as well, leaving only the assigned and broadcast EIDs.
+//!
+//! The mode is a filter in front of the router: it only ever drops
+//! packets. Whatever passes is still subject to the router's own
+//! destination matching, which decides whether it is delivered.
+//!
+//! Physical addressing is handled by the transport binding, which only
+//! hands the server packets it received on this endpoint's bus address.
+
+use crate::control::MCTP_CTRL_MSG_TYPE;
+use crate::server::{EID_BROADCAST, EID_NULL, MCTP_FLAG_SOM, MCTP_HEADER_LEN};
+use crate::{DropReason, Sender, Server};
+
+/// Message type mask in the first payload byte (the top bit is IC).
+const MSG_TYPE_MASK: u8 = 0x7F;
+
+/// How inbound destination EIDs are matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EidMode {
+    /// Drop nothing; leave destination matching to the router.
+    Router,
+    /// Awaiting an EID: drop everything but control messages to the null,
+    /// broadcast or own EID.
+    Bootstrap,
+    /// An EID was assigned after bootstrap: drop everything not addressed
+    /// to the own or broadcast EID.
+    Strict,
+}
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Enter or leave bootstrap mode.
+    ///
+    /// In bootstrap mode [`inbound`](Self::inbound) drops every packet that
+    /// is not a control protocol message addressed to the null, broadcast
+    /// or own EID; the rest go to the router as usual. The mode ends by
+    /// itself the first time [`set_eid`](Self::set_eid) assigns a non-null
+    /// EID, for example from a Set Endpoint ID request answered by
+    /// [`handle_control`](Self::handle_control); packets to the null EID are
+    /// dropped from then on. Leaving the mode explicitly stops the
+    /// filtering and leaves matching to the router alone. Off by default.
+    pub fn set_bootstrap(&mut self, enabled: bool) {
+        self.eid_mode = if enabled {
+            EidMode::Bootstrap
+        } else {
+            EidMode::Router
+        };
+    }
+
+    /// Whether the server is in bootstrap mode, awaiting an EID.
+    pub fn in_bootstrap(&self) -> bool {
+        self.eid_mode == EidMode::Bootstrap
+    }
+
+    /// End bootstrap mode once `eid` has been assigned.
+    pub(crate) fn eid_assigned(&mut self, eid: u8) {
+        if self.eid_mode == EidMode::Bootstrap && eid != EID_NULL {
+            self.eid_mode = EidMode::Strict;
+        }
+    }
+
+    /// Why the destination matching of the current mode drops `pkt`, if it
+    /// does. `pkt` must be well formed.
+    pub(crate) fn eid_mode_drop(&self, pkt: &[u8]) -> Option<DropReason> {
+        let dest = pkt[1];
+        let own = dest == self.get_eid() || dest == EID_BROADCAST;
+        match self.eid_mode {
+            EidMode::Router => None,
+            EidMode::Strict if own => None,
+            EidMode::Strict => Some(DropReason::ForeignEid),
+            EidMode::Bootstrap if !own && dest != EID_NULL => Some(DropReason::ForeignEid),
+            EidMode::Bootstrap => {
+                let control = pkt[3] & MCTP_FLAG_SOM != 0
+                    && pkt[MCTP_HEADER_LEN] & MSG_TYPE_MASK == MCTP_CTRL_MSG_TYPE;
+                (!control).then_some(DropReason::Bootstrap)
+            }
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Packet capture for golden-trace testing.
+//!
+//! A [`PacketSink`] observes raw MCTP packets, header included, without
+//! taking part in delivery. Outbound packets are captured by the
+//! [`FnSender`](crate::FnSender) built with
+//! [`FnSender::with_sink`](crate::FnSender::with_sink), just before each
+//! one is transmitted; inbound packets by
+//! [`Server::inbound_captured`] before they are checked. Giving both the
+//! same sink through a shared [`RefCell`] records one on-wire trace in
+//! order, ready to diff against a golden file.
+
+use core::cell::RefCell;
+
+use openprot_mctp_api::MctpError;
+
+use crate::{InboundDisposition, Sender, Server};
+
+/// Which way a captured packet was travelling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Fed to [`Server::inbound`].
+    Inbound,
+    /// Emitted by the router for the outbound port.
+    Outbound,
+}
+
+/// Observer of raw MCTP packets.
+///
+/// Capture cannot fail and cannot alter or drop a packet; a sink that runs
+/// out of room should drop what it cannot keep.
+pub trait PacketSink {
+    /// Records one packet travelling in `direction`.
+    fn capture(&mut self, direction: Direction, pkt: &[u8]);
+}
+
+/// Captures nothing; the sink of [`FnSender::new`](crate::FnSender::new).
+impl PacketSink for () {
+    fn capture(&mut self, _direction: Direction, _pkt: &[u8]) {}
+}
+
+impl<P: PacketSink + ?Sized> PacketSink for &mut P {
+    fn capture(&mut self, direction: Direction, pkt: &[u8]) {
+        (**self).capture(direction, pkt);
+    }
+}
+
+/// Shares one sink between the outbound port and the inbound path.
+impl<P: PacketSink + ?Sized> PacketSink for &RefCell<P> {
+    fn capture(&mut self, direction: Direction, pkt: &[u8]) {
+        self.borrow_mut().capture(direction, pkt);
+    }
+}
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Feed an inbound MCTP packet to the router, as by
+    /// [`inbound`](Self::inbound), after passing it to `sink`.
+    ///
+    /// Every packet is captured, including those `inbound` goes on to
+    /// reject or drop.
+    pub fn inbound_captured(
+        &mut self,
+        pkt: &[u8],
+        mut sink: impl PacketSink,
+    ) -> Result<InboundDisposition, MctpError> {
+        sink.capture(Direction::Inbound, pkt);
+        self.inbound(pkt)
+    }
+}
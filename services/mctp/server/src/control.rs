@@ -0,0 +1,211 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! MCTP control protocol responder.
+//!
+//! Answers the endpoint-side MCTP control commands (DSP0236, message type 0)
+//! that an OpenPRoT endpoint must support during discovery:
+//!
+//! - Set Endpoint ID
+//! - Get Endpoint ID
+//! - Get MCTP Version Support
+//! - Get Message Type Support
+//!
+//! The platform registers a listener for [`MCTP_CTRL_MSG_TYPE`], passes each
+//! received payload to [`Server::handle_control`] and sends the encoded
+//! response back with the request's EID and tag.
+
+use crate::server::{EID_BROADCAST, EID_NULL};
+use crate::{Sender, Server};
+
+/// MCTP message type for control messages.
+pub const MCTP_CTRL_MSG_TYPE: u8 = 0x00;
+
+/// Size of the control message header (Rq/D/Instance ID byte + command code).
+const CTRL_HEADER_SIZE: usize = 2;
+
+/// Request bit in the first control header byte.
+const CTRL_RQ: u8 = 0x80;
+
+/// Instance ID mask in the first control header byte.
+const CTRL_IID_MASK: u8 = 0x1F;
+
+/// MCTP control command codes.
+pub mod command {
+    /// Set Endpoint ID.
+    pub const SET_ENDPOINT_ID: u8 = 0x01;
+    /// Get Endpoint ID.
+    pub const GET_ENDPOINT_ID: u8 = 0x02;
+    /// Get MCTP Version Support.
+    pub const GET_VERSION_SUPPORT: u8 = 0x04;
+    /// Get Message Type Support.
+    pub const GET_MESSAGE_TYPE_SUPPORT: u8 = 0x05;
+}
+
+/// MCTP control completion codes.
+pub mod completion {
+    /// Request completed normally.
+    pub const SUCCESS: u8 = 0x00;
+    /// Generic failure.
+    pub const ERROR: u8 = 0x01;
+    /// Request data was invalid.
+    pub const ERROR_INVALID_DATA: u8 = 0x02;
+    /// Request length was invalid.
+    pub const ERROR_INVALID_LENGTH: u8 = 0x03;
+    /// Command code is not supported.
+    pub const ERROR_UNSUPPORTED_CMD: u8 = 0x05;
+    /// Get MCTP Version Support: message type number not supported.
+    pub const MSG_TYPE_NOT_SUPPORTED: u8 = 0x80;
+}
+
+/// Message type number selecting the MCTP base specification version.
+const VERSION_BASE_SPEC: u8 = 0xFF;
+
+/// Supported MCTP version (1.3.1), encoded per DSP0236 version entry format.
+const MCTP_VERSION_1_3_1: [u8; 4] = [0xF1, 0xF3, 0xF1, 0x00];
+
+/// Set Endpoint ID operation: set EID.
+const SET_EID_OP_SET: u8 = 0x00;
+/// Set Endpoint ID operation: force EID.
+const SET_EID_OP_FORCE: u8 = 0x01;
+
+/// Set Endpoint ID response: EID accepted, no EID pool.
+const SET_EID_ACCEPTED_NO_POOL: u8 = 0x00;
+/// Set Endpoint ID response: EID accepted, EID pool already allocated.
+const SET_EID_ACCEPTED_POOL: u8 = 0x02;
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Handle an MCTP control request and encode the response.
+    ///
+    /// `request` is the control message payload as received on a
+    /// [`MCTP_CTRL_MSG_TYPE`] listener (i.e. starting at the Rq/D/Instance ID
+    /// byte). Returns the number of response bytes written to `response`,
+    /// or `None` when no response must be sent: the payload is empty, is
+    /// itself a response (Rq bit clear), or `response` is too small.
+    ///
+    /// Malformed or unsupported requests are answered with the matching
+    /// error completion code rather than being dropped.
+    pub fn handle_control(&mut self, request: &[u8], response: &mut [u8]) -> Option<usize> {
+        let &hdr = request.first()?;
+        if hdr & CTRL_RQ == 0 {
+            return None;
+        }
+        let cmd = request.get(1).copied().unwrap_or(0);
+        let data = request.get(CTRL_HEADER_SIZE..).unwrap_or(&[]);
+
+        let mut out = ControlWriter::new(response, hdr & CTRL_IID_MASK, cmd)?;
+        if request.len() < CTRL_HEADER_SIZE {
+            return out.finish(completion::ERROR_INVALID_LENGTH);
+        }
+
+        match cmd {
+            command::SET_ENDPOINT_ID => {
+                let &[op, eid] = data else {
+                    return out.finish(completion::ERROR_INVALID_LENGTH);
+                };
+                let op = op & 0x03;
+                if (op != SET_EID_OP_SET && op != SET_EID_OP_FORCE)
+                    || eid == EID_NULL
+                    || eid == EID_BROADCAST
+                {
+                    return out.finish(completion::ERROR_INVALID_DATA);
+                }
+                if self.set_eid(eid).is_err() {
+                    return out.finish(completion::ERROR);
+                }
+                // Report the pool configured with `set_eid_pool`, if any.
+                let pool_size = self.eid_pool.size();
+                out.push(if pool_size == 0 {
+                    SET_EID_ACCEPTED_NO_POOL
+                } else {
+                    SET_EID_ACCEPTED_POOL
+                })?;
+                out.push(self.get_eid())?;
+                out.push(pool_size)?;
+                out.finish(completion::SUCCESS)
+            }
+            command::GET_ENDPOINT_ID => {
+                if !data.is_empty() {
+                    return out.finish(completion::ERROR_INVALID_LENGTH);
+                }
+                // Simple endpoint with a dynamic EID; no medium-specific info.
+                out.push(self.get_eid())?;
+                out.push(0x00)?;
+                out.push(0x00)?;
+                out.finish(completion::SUCCESS)
+            }
+            command::GET_VERSION_SUPPORT => {
+                let &[msg_type] = data else {
+                    return out.finish(completion::ERROR_INVALID_LENGTH);
+                };
+                if msg_type != VERSION_BASE_SPEC && msg_type != MCTP_CTRL_MSG_TYPE {
+                    return out.finish(completion::MSG_TYPE_NOT_SUPPORTED);
+                }
+                out.push(1)?;
+                for b in MCTP_VERSION_1_3_1 {
+                    out.push(b)?;
+                }
+                out.finish(completion::SUCCESS)
+            }
+            command::GET_MESSAGE_TYPE_SUPPORT => {
+                if !data.is_empty() {
+                    return out.finish(completion::ERROR_INVALID_LENGTH);
+                }
+                // The control message type itself is not reported.
+                let count_at = out.len;
+                out.push(0)?;
+                let mut count = 0u8;
+                for typ in self.listener_types() {
+                    if typ != MCTP_CTRL_MSG_TYPE {
+                        out.push(typ)?;
+                        count += 1;
+                    }
+                }
+                out.buf[count_at] = count;
+                out.finish(completion::SUCCESS)
+            }
+            _ => out.finish(completion::ERROR_UNSUPPORTED_CMD),
+        }
+    }
+}
+
+/// Encodes a control response: header, completion code, then response data.
+struct ControlWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> ControlWriter<'a> {
+    /// Offset of the completion code in an encoded response.
+    const COMPLETION_OFFSET: usize = CTRL_HEADER_SIZE;
+
+    fn new(buf: &'a mut [u8], iid: u8, cmd: u8) -> Option<Self> {
+        if buf.len() < CTRL_HEADER_SIZE + 1 {
+            return None;
+        }
+        buf[0] = iid;
+        buf[1] = cmd;
+        Some(Self {
+            buf,
+            len: CTRL_HEADER_SIZE + 1,
+        })
+    }
+
+    fn push(&mut self, byte: u8) -> Option<()> {
+        *self.buf.get_mut(self.len)? = byte;
+        self.len += 1;
+        Some(())
+    }
+
+    /// Write the completion code. Error completions carry no response data.
+    fn finish(self, code: u8) -> Option<usize> {
+        self.buf[Self::COMPLETION_OFFSET] = code;
+        if code == completion::SUCCESS {
+            Some(self.len)
+        } else {
+            Some(Self::COMPLETION_OFFSET + 1)
+        }
+    }
+}
@@ -35,10 +35,10 @@ pub enum DispatchOutcome {
 /// Returns `DispatchOutcome::Reply(n)` when a response is immediately
 /// available, or `DispatchOutcome::Pending { handle }` when a `Recv`
 /// has been registered and will be fulfilled later by [`drive_pending`].
-pub fn dispatch_mctp_op<S: Sender, const N: usize>(
+pub fn dispatch_mctp_op<S: Sender, const N: usize, const L: usize, const R: usize>(
     request: &[u8],
     response: &mut [u8],
-    server: &mut Server<S, N>,
+    server: &mut Server<S, N, L, R>,
     recv_buf: &mut [u8],
     now_millis: u64,
 ) -> DispatchOutcome {
@@ -144,8 +144,8 @@ pub fn dispatch_mctp_op<S: Sender, const N: usize>(
 /// `on_ready(handle, response_len)` is called with `response` filled.
 /// The platform must look up its stored reply token for `handle` and send
 /// the response through it.
-pub fn drive_pending<S: Sender, const N: usize>(
-    server: &mut Server<S, N>,
+pub fn drive_pending<S: Sender, const N: usize, const L: usize, const R: usize>(
+    server: &mut Server<S, N, L, R>,
     now_millis: u64,
     recv_buf: &mut [u8],
     response: &mut [u8],
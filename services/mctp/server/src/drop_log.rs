@@ -0,0 +1,286 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Diagnostics for inbound packets the server drops.
+//!
+//! With the `log` crate feature enabled, every packet dropped on the inbound
+//! path is reported through `pw_log::info!` with its [`DropReason`] and
+//! destination EID, and every stale response dropped on receive with the
+//! cookie of the handle it was queued for. Without the feature the logging
+//! calls compile to nothing, so production images pay no code size for
+//! them.
+//!
+//! Drops decided inside the router (no listener, no matching request) are
+//! not visible to the server and are not logged.
+
+use crate::server::{EID_BROADCAST, EID_NULL};
+use crate::{Sender, Server};
+
+/// Why an inbound packet was dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropReason {
+    /// The MCTP transport header is truncated or has an unknown version.
+    Malformed,
+    /// The binding's frame check failed (see
+    /// [`Server::inbound_with_fcs`]).
+    FrameCheck,
+    /// The packet is addressed to another endpoint.
+    ForeignEid,
+    /// The packet is not a control message and the server is in
+    /// [bootstrap mode](Server::set_bootstrap).
+    Bootstrap,
+    /// The router rejected the packet with an error.
+    Rejected,
+    /// A response whose instance ID did not match its request (see
+    /// [`Server::send_with_instance_id`]), dropped when received.
+    StaleResponse,
+}
+
+/// One reported drop: the reason, destination EID and router cookie.
+#[cfg_attr(not(feature = "log"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DropRecord {
+    /// Why the packet was dropped.
+    pub(crate) reason: DropReason,
+    /// Destination EID from the transport header; `None` for frames that
+    /// failed their frame check, packets too short to carry one, and
+    /// dropped responses.
+    pub(crate) dest_eid: Option<u8>,
+    /// Router cookie (handle value) the message was queued for; `None` for
+    /// packets dropped before reaching the router.
+    pub(crate) cookie: Option<usize>,
+}
+
+impl DropRecord {
+    fn new(reason: DropReason, pkt: &[u8]) -> Self {
+        let dest_eid = match reason {
+            DropReason::FrameCheck => None,
+            _ => pkt.get(1).copied(),
+        };
+        Self {
+            reason,
+            dest_eid,
+            cookie: None,
+        }
+    }
+
+    /// Report the drop through `pw_log`.
+    #[cfg(feature = "log")]
+    fn log(&self) {
+        let dest = self.dest_eid.unwrap_or(EID_NULL) as u32;
+        let cookie = self.cookie.unwrap_or(0) as u32;
+        match self.reason {
+            DropReason::Malformed => {
+                pw_log::info!("mctp: drop malformed packet, dest eid {}", dest)
+            }
+            DropReason::FrameCheck => pw_log::info!("mctp: drop frame with bad fcs"),
+            DropReason::ForeignEid => pw_log::info!("mctp: drop packet for foreign eid {}", dest),
+            DropReason::Bootstrap => {
+                pw_log::info!("mctp: drop non-control packet before eid assignment")
+            }
+            DropReason::Rejected => {
+                pw_log::info!("mctp: drop packet rejected by router, dest eid {}", dest)
+            }
+            DropReason::StaleResponse => {
+                pw_log::info!("mctp: drop stale response for cookie {}", cookie)
+            }
+        }
+    }
+}
+
+/// Where the server reports drops.
+///
+/// With the `log` feature each record goes to `pw_log`; the crate's unit
+/// tests also keep the last one. Otherwise the log is zero-sized and
+/// reporting compiles to nothing.
+#[derive(Debug, Default)]
+pub(crate) struct DropLog {
+    #[cfg(test)]
+    last: Option<DropRecord>,
+}
+
+impl DropLog {
+    #[inline(always)]
+    pub(crate) fn report(&mut self, record: DropRecord) {
+        #[cfg(feature = "log")]
+        record.log();
+        #[cfg(test)]
+        {
+            self.last = Some(record);
+        }
+        #[cfg(not(any(feature = "log", test)))]
+        let _ = record;
+    }
+
+    /// Report a response dropped on receive for the handle `cookie`.
+    pub(crate) fn report_stale(&mut self, cookie: usize) {
+        self.report(DropRecord {
+            reason: DropReason::StaleResponse,
+            dest_eid: None,
+            cookie: Some(cookie),
+        });
+    }
+}
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Report why [`inbound`](Self::inbound) drops `pkt` before it reaches
+    /// a listener or request, as far as the server can tell from the
+    /// transport header.
+    ///
+    /// Returns `None` for packets the server passes on to the router, which
+    /// may still drop them.
+    pub fn drop_reason(&self, pkt: &[u8]) -> Option<DropReason> {
+        if !crate::server::is_well_formed(pkt) {
+            return Some(DropReason::Malformed);
+        }
+        if let Some(reason) = self.eid_mode_drop(pkt) {
+            return Some(reason);
+        }
+        let dest = pkt[1];
+        if dest != self.get_eid() && dest != EID_NULL && dest != EID_BROADCAST {
+            return Some(DropReason::ForeignEid);
+        }
+        None
+    }
+
+    /// Report a dropped inbound packet to the drop log.
+    pub(crate) fn log_drop(&mut self, reason: DropReason, pkt: &[u8]) {
+        self.drop_log.report(DropRecord::new(reason, pkt));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::RefCell;
+
+    use mctp::Eid;
+
+    use super::*;
+    use crate::{Binding, FnSender};
+
+    /// The last packet a capturing sender transmitted.
+    type Packet = RefCell<heapless::Vec<u8, 64>>;
+
+    /// Single-packet message of type 1 from EID 42 to `dest`.
+    fn packet(dest: u8) -> [u8; 6] {
+        [0x01, dest, 42, 0xC8, 0x01, 0xAB]
+    }
+
+    fn server(eid: u8) -> Server<impl Sender, 16> {
+        Server::new(Eid(eid), 0, FnSender::new(64, |_| Ok(())))
+    }
+
+    /// A sender that keeps the last packet it transmitted in `last`.
+    fn capturing(last: &Packet) -> impl Sender + '_ {
+        FnSender::new(64, move |pkt| {
+            let mut last = last.borrow_mut();
+            last.clear();
+            last.extend_from_slice(pkt)
+                .map_err(|_| mctp::Error::NoSpace)
+        })
+    }
+
+    /// A packet for another endpoint is reported as a foreign-EID drop and
+    /// is not delivered.
+    #[test]
+    fn foreign_eid_packet_dropped() {
+        let mut server = server(8);
+        let listener = server.listener(1).unwrap();
+
+        let foreign = packet(9);
+        assert_eq!(server.drop_reason(&foreign), Some(DropReason::ForeignEid));
+        server.inbound(&foreign).unwrap();
+        assert_eq!(
+            server.drop_log.last,
+            Some(DropRecord {
+                reason: DropReason::ForeignEid,
+                dest_eid: Some(9),
+                cookie: None,
+            })
+        );
+        assert!(server.try_recv(listener, &mut [0u8; 64]).is_none());
+    }
+
+    /// Delivered packets are not reported as drops.
+    #[test]
+    fn delivered_packet_not_logged() {
+        let mut server = server(8);
+        let listener = server.listener(1).unwrap();
+
+        server.inbound(&packet(8)).unwrap();
+        assert_eq!(server.drop_log.last, None);
+        assert!(server.try_recv(listener, &mut [0u8; 64]).is_some());
+    }
+
+    /// Packets for this endpoint, and broadcast or null destinations, are
+    /// not dropped by the server; malformed ones are.
+    #[test]
+    fn drop_reason_classification() {
+        let server = server(8);
+        for dest in [8, EID_NULL, EID_BROADCAST] {
+            assert_eq!(server.drop_reason(&packet(dest)), None);
+        }
+        assert_eq!(server.drop_reason(&[0x01]), Some(DropReason::Malformed));
+    }
+
+    /// Malformed packets and bad frame checks still fail when reported.
+    #[test]
+    fn reported_rejections_still_fail() {
+        let mut server = server(8);
+        assert!(server.inbound(&[0x01, 0x08]).is_err());
+        assert_eq!(
+            server.drop_log.last,
+            Some(DropRecord {
+                reason: DropReason::Malformed,
+                dest_eid: Some(8),
+                cookie: None,
+            })
+        );
+
+        assert!(server
+            .inbound_with_fcs(&[0x20, 0x0F, 0x01, 0x85, 0x00], Binding::Smbus)
+            .is_err());
+        assert_eq!(
+            server.drop_log.last,
+            Some(DropRecord {
+                reason: DropReason::FrameCheck,
+                dest_eid: None,
+                cookie: None,
+            })
+        );
+        assert_eq!(server.stats().inbound_errors, 2);
+    }
+
+    /// A response with a mismatched instance ID is reported with the cookie
+    /// of the request handle it was queued for.
+    #[test]
+    fn stale_response_reports_cookie() {
+        let request = RefCell::new(heapless::Vec::new());
+        let response = RefCell::new(heapless::Vec::new());
+        let mut requester: Server<_, 16> = Server::new(Eid(42), 0, capturing(&request));
+        let mut responder: Server<_, 16> = Server::new(Eid(8), 0, capturing(&response));
+        let req = requester.req(8).unwrap();
+        let listener = responder.listener(0x7E).unwrap();
+
+        requester
+            .send_with_instance_id(req, 0x7E, 3, false, &mut [0x80])
+            .unwrap();
+        responder.inbound(request.borrow().as_slice()).unwrap();
+        let mut buf = [0u8; 16];
+        let meta = responder.try_recv(listener, &mut buf).unwrap();
+
+        responder.respond(&meta, &[0x04]).unwrap();
+        requester.inbound(response.borrow().as_slice()).unwrap();
+        assert!(requester.try_recv(req, &mut buf).is_none());
+        assert_eq!(
+            requester.drop_log.last,
+            Some(DropRecord {
+                reason: DropReason::StaleResponse,
+                dest_eid: None,
+                cookie: Some(req.0 as usize),
+            })
+        );
+    }
+}
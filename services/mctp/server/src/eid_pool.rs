@@ -0,0 +1,94 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Downstream EID allocation for the bus-owner role.
+//!
+//! When the RoT acts as MCTP bus owner it hands out EIDs to endpoints it
+//! discovers (via Set Endpoint ID). [`EidPool`] tracks which EIDs of a
+//! contiguous assignable range are currently in use.
+
+use openprot_mctp_api::{MctpError, ResponseCode};
+
+use crate::server::EID_BROADCAST;
+
+/// Lowest EID that may be assigned; 0 is null and 1..=7 are reserved.
+const MIN_ASSIGNABLE_EID: u8 = 8;
+
+/// A contiguous range of assignable EIDs with per-EID allocation state.
+#[derive(Debug, Clone)]
+pub struct EidPool {
+    first: u8,
+    last: u8,
+    /// One bit per EID value, set while the EID is assigned.
+    allocated: [u32; 8],
+}
+
+impl EidPool {
+    /// A pool with no assignable EIDs.
+    pub const fn empty() -> Self {
+        Self {
+            first: 1,
+            last: 0,
+            allocated: [0; 8],
+        }
+    }
+
+    /// Create a pool assigning EIDs from `first` to `last`, inclusive.
+    ///
+    /// Returns `BadArgument` if the range is empty or includes the null,
+    /// reserved (1..=7) or broadcast EIDs.
+    pub fn new(first: u8, last: u8) -> Result<Self, MctpError> {
+        if first < MIN_ASSIGNABLE_EID || last == EID_BROADCAST || first > last {
+            return Err(MctpError::from_code(ResponseCode::BadArgument));
+        }
+        Ok(Self {
+            first,
+            last,
+            allocated: [0; 8],
+        })
+    }
+
+    /// Returns `true` if `eid` is within the pool's range.
+    pub fn contains(&self, eid: u8) -> bool {
+        (self.first..=self.last).contains(&eid)
+    }
+
+    /// Number of EIDs in the pool's range; 0 for an empty pool.
+    pub fn size(&self) -> u8 {
+        (self.last + 1).saturating_sub(self.first)
+    }
+
+    /// Returns `true` if `eid` is currently assigned from this pool.
+    pub fn is_assigned(&self, eid: u8) -> bool {
+        self.allocated[usize::from(eid / 32)] & (1 << (eid % 32)) != 0
+    }
+
+    /// Assign the lowest free EID in the range, never handing out `exclude`
+    /// (the server's own EID).
+    ///
+    /// Returns `NoSpace` when every EID in the range is assigned.
+    pub fn assign(&mut self, exclude: u8) -> Result<u8, MctpError> {
+        let eid = (self.first..=self.last)
+            .find(|&eid| eid != exclude && !self.is_assigned(eid))
+            .ok_or(MctpError::from_code(ResponseCode::NoSpace))?;
+        self.allocated[usize::from(eid / 32)] |= 1 << (eid % 32);
+        Ok(eid)
+    }
+
+    /// Return a previously assigned EID to the pool.
+    ///
+    /// Returns `BadArgument` if `eid` is not currently assigned.
+    pub fn release(&mut self, eid: u8) -> Result<(), MctpError> {
+        if !self.is_assigned(eid) {
+            return Err(MctpError::from_code(ResponseCode::BadArgument));
+        }
+        self.allocated[usize::from(eid / 32)] &= !(1 << (eid % 32));
+        Ok(())
+    }
+}
+
+impl Default for EidPool {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
@@ -0,0 +1,101 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Instance-ID request/response matching.
+//!
+//! MCTP tags only say which request channel a response arrived on. Protocols
+//! layered on MCTP, such as the control protocol and PLDM, additionally carry
+//! a 5-bit instance ID in the low bits of the first payload byte so that a
+//! late response to an earlier request is not mistaken for the current one.
+//!
+//! This is opt-in per send: [`Server::send_with_instance_id`] stamps the ID
+//! into the request and remembers it for the handle. The receive calls then
+//! drop a response whose ID differs, counting it in
+//! [`ServerStats::stale_responses`](crate::ServerStats), and
+//! [`Server::check_instance_id`] applies the same check by hand.
+
+use heapless::LinearMap;
+use openprot_mctp_api::{Handle, MctpError, ResponseCode};
+
+use crate::{HandleKind, Sender, Server};
+
+/// Instance ID mask in the first payload byte.
+const IID_MASK: u8 = 0x1F;
+
+/// Outcome of [`Server::check_instance_id`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceCheck {
+    /// No instance ID is being tracked for the handle.
+    Untracked,
+    /// The response carries the expected instance ID. Tracking ends.
+    Matched,
+    /// The response carries a different instance ID, or none at all.
+    /// The expected ID stays outstanding.
+    Mismatched {
+        /// Instance ID of the outstanding request.
+        expected: u8,
+    },
+}
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Send a request stamped with `instance_id` and track it for `handle`.
+    ///
+    /// The ID is written into the low five bits of `buf[0]`, the layout used
+    /// by the control protocol and PLDM headers. Any previously tracked ID
+    /// for the handle is replaced once the send succeeds. Returns
+    /// `BadArgument` if `handle` is not a bound request handle, `buf` is
+    /// empty, or `instance_id` does not fit in five bits.
+    pub fn send_with_instance_id(
+        &mut self,
+        handle: Handle,
+        typ: u8,
+        instance_id: u8,
+        ic: bool,
+        buf: &mut [u8],
+    ) -> Result<u8, MctpError> {
+        let bad_argument = MctpError::from_code(ResponseCode::BadArgument);
+        if instance_id & !IID_MASK != 0 {
+            return Err(bad_argument);
+        }
+        if !matches!(self.handle_kind(handle), Some(HandleKind::Request(_))) {
+            return Err(bad_argument);
+        }
+        let first = buf.first_mut().ok_or(bad_argument)?;
+        *first = (*first & !IID_MASK) | instance_id;
+
+        let tag = self.send(Some(handle), typ, None, None, ic, buf)?;
+        // One entry per bound request handle, so this cannot overflow.
+        let _ = self.instance_ids.insert(handle.0, instance_id);
+        Ok(tag)
+    }
+
+    /// Check a response received on `handle` against its tracked instance ID.
+    ///
+    /// `payload` is the response as copied out by a receive call. The
+    /// receive calls already run this check, so a response they return has
+    /// either matched or was untracked.
+    pub fn check_instance_id(&mut self, handle: Handle, payload: &[u8]) -> InstanceCheck {
+        check(&mut self.instance_ids, handle.0, payload)
+    }
+}
+
+/// Check `payload` against the instance ID tracked for `handle_val` in
+/// `ids`, ending tracking on a match.
+pub(crate) fn check<const REQUESTS: usize>(
+    ids: &mut LinearMap<u32, u8, REQUESTS>,
+    handle_val: u32,
+    payload: &[u8],
+) -> InstanceCheck {
+    let Some(&expected) = ids.get(&handle_val) else {
+        return InstanceCheck::Untracked;
+    };
+    match payload.first() {
+        Some(&byte) if byte & IID_MASK == expected => {
+            ids.remove(&handle_val);
+            InstanceCheck::Matched
+        }
+        _ => InstanceCheck::Mismatched { expected },
+    }
+}
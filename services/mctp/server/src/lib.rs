@@ -7,29 +7,72 @@
 //!
 //! This crate provides the core MCTP server logic that manages:
 //! - Listener and request handle allocation (via `mctp-lib` [`Router`](mctp_lib::Router))
-//! - Inbound message routing to registered listeners
+//! - Inbound message routing to registered listeners, with the payload
+//!   copied out or borrowed for forwarding (see [`Server::try_recv_borrowed`])
 //! - Outbound message fragmentation and sending
-//! - Timeout management for pending receive calls
+//! - Timeout management for pending receive calls, and retries of
+//!   requests whose response timed out (see [`Server::retry`])
+//! - MCTP control protocol responses (see [`control`])
+//! - PLDM message header framing (see [`pldm`])
+//! - Opt-in instance-ID matching of responses (see [`InstanceCheck`])
+//! - Downstream EID assignment when acting as bus owner (see [`EidPool`])
+//! - A bootstrap mode for endpoints awaiting their EID (see
+//!   [`Server::set_bootstrap`])
+//! - Bridge forwarding of packets for EIDs behind other ports (see
+//!   [`Server::add_route`])
+//! - Packet capture of both directions for golden-trace tests (see
+//!   [`PacketSink`])
 //!
 //! ## Transport Bindings
 //!
 //! The server is generic over the `mctp-lib` [`Sender`](mctp_lib::Sender) trait
 //! for outbound transport. Transport-specific bindings (I2C, serial) implement
-//! this trait and feed inbound packets via [`Server::inbound`].
+//! this trait and feed inbound packets via [`Server::inbound`], or whole
+//! frames via [`Server::inbound_with_fcs`] when the frame check is left to
+//! the server. Bindings that only need to write raw packets can wrap a
+//! closure in [`FnSender`].
 //!
 //! ## Platform Integration
 //!
 //! The server does not depend on any OS primitives. The platform layer
 //! is responsible for:
 //! - Driving the event loop (notifications, IPC dispatch)
-//! - Providing a time source via [`Server::update`]
+//! - Providing a time source via [`Server::update`], or a `MonotonicTimer`
+//!   via [`Server::update_at`]
 //! - Wiring up transport bindings
+//!
+//! ## Features
+//!
+//! - `log`: report dropped inbound packets through `pw_log` (see
+//!   [`DropReason`]). Off by default; without it the logging compiles away.
 
 #![no_std]
 #![warn(missing_docs)]
 
+mod binding;
+mod bootstrap;
+mod capture;
+pub mod control;
 pub mod dispatch;
+mod drop_log;
+mod eid_pool;
+mod instance;
+pub mod pldm;
+mod retry;
+mod route;
+mod sender;
 mod server;
+mod timer;
 
+pub use binding::Binding;
+pub use capture::{Direction, PacketSink};
+pub use drop_log::DropReason;
+pub use eid_pool::EidPool;
+pub use instance::InstanceCheck;
 pub use mctp_lib::Sender;
-pub use server::{RecvResult, Server, ServerConfig};
+pub use route::{OutboundPort, PortId};
+pub use sender::{FnSender, FN_SENDER_MAX_MTU, FN_SENDER_MIN_MTU};
+pub use server::{
+    DefaultServer, HandleKind, InboundDisposition, RecvResult, SendItem, Server, ServerConfig,
+    ServerStats,
+};
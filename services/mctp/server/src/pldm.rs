@@ -0,0 +1,142 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! PLDM message header framing.
+//!
+//! Encodes and decodes the PLDM base header (DSP0240) carried in MCTP
+//! message type [`PLDM_MSG_TYPE`]: Rq/D/Instance ID, header version and
+//! PLDM type, command code and, for responses, the completion code. Only
+//! the header is handled here; command bodies are left to the caller.
+//!
+//! A responder registers a listener for [`PLDM_MSG_TYPE`], passes each
+//! received payload to [`PldmHeader::decode`], encodes the reply header
+//! from [`PldmHeader::response`] followed by its body, and sends it with
+//! [`Server::respond`](crate::Server::respond).
+
+/// MCTP message type for PLDM messages.
+pub const PLDM_MSG_TYPE: u8 = 0x01;
+
+/// Size of a PLDM request header (Rq/D/Instance ID, type, command).
+pub const PLDM_REQUEST_HEADER_SIZE: usize = 3;
+
+/// Size of a PLDM response header (request header plus completion code).
+pub const PLDM_RESPONSE_HEADER_SIZE: usize = PLDM_REQUEST_HEADER_SIZE + 1;
+
+/// Request bit in the first header byte.
+const PLDM_RQ: u8 = 0x80;
+/// Datagram bit in the first header byte.
+const PLDM_D: u8 = 0x40;
+/// Instance ID mask in the first header byte.
+const PLDM_IID_MASK: u8 = 0x1F;
+/// Header version mask in the second header byte.
+const PLDM_HDR_VER_MASK: u8 = 0xC0;
+/// PLDM type mask in the second header byte.
+const PLDM_TYPE_MASK: u8 = 0x3F;
+
+/// PLDM base completion codes.
+pub mod completion {
+    /// Request completed normally.
+    pub const SUCCESS: u8 = 0x00;
+    /// Generic failure.
+    pub const ERROR: u8 = 0x01;
+    /// Request data was invalid.
+    pub const ERROR_INVALID_DATA: u8 = 0x02;
+    /// Request length was invalid.
+    pub const ERROR_INVALID_LENGTH: u8 = 0x03;
+    /// The responder is temporarily unable to complete the request.
+    pub const ERROR_NOT_READY: u8 = 0x04;
+    /// Command code is not supported for this PLDM type.
+    pub const ERROR_UNSUPPORTED_PLDM_CMD: u8 = 0x05;
+    /// PLDM type is not supported.
+    pub const ERROR_INVALID_PLDM_TYPE: u8 = 0x20;
+}
+
+/// A decoded PLDM message header.
+///
+/// Unacknowledged (datagram) requests are not supported; decoding one
+/// fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PldmHeader {
+    /// Instance ID matching a response to its request (0..=31).
+    pub instance_id: u8,
+    /// PLDM type (0..=63).
+    pub pldm_type: u8,
+    /// Command code.
+    pub command: u8,
+    /// Completion code for a response, `None` for a request.
+    pub completion_code: Option<u8>,
+}
+
+impl PldmHeader {
+    /// Header for a request.
+    pub fn request(instance_id: u8, pldm_type: u8, command: u8) -> Self {
+        Self {
+            instance_id,
+            pldm_type,
+            command,
+            completion_code: None,
+        }
+    }
+
+    /// Header answering this request with `completion_code`, keeping its
+    /// instance ID, PLDM type and command.
+    pub fn response(&self, completion_code: u8) -> Self {
+        Self {
+            completion_code: Some(completion_code),
+            ..*self
+        }
+    }
+
+    /// Whether this is a request header.
+    pub fn is_request(&self) -> bool {
+        self.completion_code.is_none()
+    }
+
+    /// Encoded size of this header in bytes.
+    pub fn encoded_len(&self) -> usize {
+        if self.is_request() {
+            PLDM_REQUEST_HEADER_SIZE
+        } else {
+            PLDM_RESPONSE_HEADER_SIZE
+        }
+    }
+
+    /// Encode the header at the start of `buf`.
+    ///
+    /// Returns the number of bytes written, or `None` if `buf` is too small
+    /// or the instance ID or PLDM type does not fit its field.
+    pub fn encode(&self, buf: &mut [u8]) -> Option<usize> {
+        if self.instance_id & !PLDM_IID_MASK != 0 || self.pldm_type & !PLDM_TYPE_MASK != 0 {
+            return None;
+        }
+        let out = buf.get_mut(..self.encoded_len())?;
+        let rq = if self.is_request() { PLDM_RQ } else { 0 };
+        out[0] = rq | self.instance_id;
+        // Header version 0.
+        out[1] = self.pldm_type;
+        out[2] = self.command;
+        if let Some(code) = self.completion_code {
+            out[3] = code;
+        }
+        Some(out.len())
+    }
+
+    /// Decode the header at the start of a PLDM message payload.
+    ///
+    /// Returns the header and the command body that follows it, or `None`
+    /// when the payload is truncated, uses an unknown header version, or is
+    /// a datagram.
+    pub fn decode(msg: &[u8]) -> Option<(Self, &[u8])> {
+        let &[hdr, ver_type, command, ..] = msg else {
+            return None;
+        };
+        if hdr & PLDM_D != 0 || ver_type & PLDM_HDR_VER_MASK != 0 {
+            return None;
+        }
+        let mut header = Self::request(hdr & PLDM_IID_MASK, ver_type & PLDM_TYPE_MASK, command);
+        if hdr & PLDM_RQ == 0 {
+            header.completion_code = Some(*msg.get(PLDM_REQUEST_HEADER_SIZE)?);
+        }
+        Some((header, &msg[header.encoded_len()..]))
+    }
+}
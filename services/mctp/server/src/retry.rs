@@ -0,0 +1,98 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Retrying requests whose response timed out.
+//!
+//! When a pending receive on a request handle times out in
+//! [`Server::update`], the request is remembered as retryable.
+//! [`Server::retry`] then sends it again on the same handle, to the same
+//! EID and with the same message type and IC bit, under a freshly
+//! allocated tag. Each request may be retried up to
+//! [`Server::set_max_retries`] times before `retry` gives up with
+//! `TimedOut`; a new [`send`](Server::send) on the handle starts the count
+//! over.
+//!
+//! The server keeps no copy of the payload: the caller passes it again,
+//! which it usually still holds to match the response against.
+
+use openprot_mctp_api::{Handle, MctpError, ResponseCode};
+
+use crate::{Sender, Server};
+
+/// What [`Server::retry`] needs to know about the last request sent on a
+/// handle.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SentRequest {
+    /// MCTP message type of the request.
+    typ: u8,
+    /// Integrity check bit of the request.
+    ic: bool,
+    /// Times the request has been retried.
+    retries: u8,
+    /// Whether a pending receive for the response timed out since the
+    /// request was last sent.
+    timed_out: bool,
+}
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Set how many times [`retry`](Self::retry) re-sends one request.
+    ///
+    /// Defaults to [`ServerConfig::MAX_RETRIES`](crate::ServerConfig::MAX_RETRIES). Zero
+    /// disables retries.
+    pub fn set_max_retries(&mut self, max: u8) {
+        self.max_retries = max;
+    }
+
+    /// Re-send the request last sent on `handle` after its response timed
+    /// out, and return the new tag.
+    ///
+    /// `buf` is the request payload, sent as by [`send`](Self::send) with
+    /// the original message type and IC bit. Fails with `BadArgument` if no
+    /// receive for the request's response has timed out since it was last
+    /// sent, and with `TimedOut` once the request has been retried
+    /// [`set_max_retries`](Self::set_max_retries) times. A failed send
+    /// leaves the request retryable without using up a retry.
+    pub fn retry(&mut self, handle: Handle, buf: &[u8]) -> Result<u8, MctpError> {
+        let sent = match self.sent_requests.get(&handle.0) {
+            Some(sent) if sent.timed_out => *sent,
+            _ => return Err(MctpError::from_code(ResponseCode::BadArgument)),
+        };
+        if sent.retries >= self.max_retries {
+            return Err(MctpError::from_code(ResponseCode::TimedOut));
+        }
+        let tag = self.send(Some(handle), sent.typ, None, None, sent.ic, buf)?;
+        if let Some(entry) = self.sent_requests.get_mut(&handle.0) {
+            entry.retries = sent.retries + 1;
+        }
+        self.count_retry();
+        Ok(tag)
+    }
+
+    /// Times the request last sent on `handle` has been retried.
+    pub fn retry_count(&self, handle: Handle) -> u8 {
+        self.sent_requests
+            .get(&handle.0)
+            .map_or(0, |sent| sent.retries)
+    }
+
+    /// Remember a request just sent on `handle`, resetting its retries.
+    pub(crate) fn request_sent(&mut self, handle: Handle, typ: u8, ic: bool) {
+        let sent = SentRequest {
+            typ,
+            ic,
+            retries: 0,
+            timed_out: false,
+        };
+        // One entry per bound request handle, so this cannot overflow.
+        let _ = self.sent_requests.insert(handle.0, sent);
+    }
+
+    /// Mark the request last sent on `handle` as retryable.
+    pub(crate) fn request_timed_out(&mut self, handle: Handle) {
+        if let Some(sent) = self.sent_requests.get_mut(&handle.0) {
+            sent.timed_out = true;
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bridge routing of packets addressed to other endpoints.
+//!
+//! An endpoint acting as an MCTP bridge forwards packets for EIDs behind
+//! its other ports instead of dropping them. [`Server::add_route`] maps a
+//! range of destination EIDs to a [`PortId`], and
+//! [`Server::inbound_bridged`] transmits matching packets, unchanged, on an
+//! [`OutboundPort`] rather than handing them to the router. Packets for this
+//! endpoint's own, the null and the broadcast EIDs are never forwarded.
+
+use core::ops::RangeInclusive;
+
+use openprot_mctp_api::{MctpError, ResponseCode};
+
+use crate::server::{is_well_formed, EID_BROADCAST, EID_NULL};
+use crate::{InboundDisposition, Sender, Server};
+
+/// Identifies one port of a bridge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PortId(pub u8);
+
+/// Transmits raw MCTP packets on the ports of a bridge.
+///
+/// Closures `FnMut(PortId, &[u8]) -> mctp::Result<()>` implement this
+/// trait, so bindings that only need to write bytes do not define a type.
+pub trait OutboundPort {
+    /// Transmits `pkt`, one complete MCTP packet (header included), on
+    /// `port`.
+    fn transmit(&mut self, port: PortId, pkt: &[u8]) -> mctp::Result<()>;
+}
+
+impl<F> OutboundPort for F
+where
+    F: FnMut(PortId, &[u8]) -> mctp::Result<()>,
+{
+    fn transmit(&mut self, port: PortId, pkt: &[u8]) -> mctp::Result<()> {
+        self(port, pkt)
+    }
+}
+
+/// One entry of the route table: destination EIDs `first..=last` are
+/// forwarded on `port`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Route {
+    first: u8,
+    last: u8,
+    port: PortId,
+}
+
+impl Route {
+    fn contains(&self, eid: u8) -> bool {
+        (self.first..=self.last).contains(&eid)
+    }
+}
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Forward packets addressed to the EIDs in `eids` on `port`.
+    ///
+    /// Returns `BadArgument` if the range is empty, includes the null or
+    /// broadcast EID, or overlaps an existing route, and `NoSpace` when the
+    /// table already holds [`ServerConfig::MAX_ROUTES`](crate::ServerConfig)
+    /// routes. Routes are kept across [`reset`](Self::reset).
+    pub fn add_route(&mut self, eids: RangeInclusive<u8>, port: PortId) -> Result<(), MctpError> {
+        let (first, last) = (*eids.start(), *eids.end());
+        if first > last
+            || first == EID_NULL
+            || last == EID_BROADCAST
+            || self
+                .routes
+                .iter()
+                .any(|r| first <= r.last && r.first <= last)
+        {
+            return Err(MctpError::from_code(ResponseCode::BadArgument));
+        }
+        self.routes
+            .push(Route { first, last, port })
+            .map_err(|_| MctpError::from_code(ResponseCode::NoSpace))
+    }
+
+    /// The port packets addressed to `dest` are forwarded on, if any.
+    ///
+    /// Always `None` for this endpoint's own EID, whatever the routes say.
+    pub fn route(&self, dest: u8) -> Option<PortId> {
+        if dest == self.get_eid() {
+            return None;
+        }
+        self.routes
+            .iter()
+            .find(|r| r.contains(dest))
+            .map(|r| r.port)
+    }
+
+    /// Feed an inbound MCTP packet, forwarding it on `ports` if it is
+    /// addressed to a [routed](Self::add_route) EID.
+    ///
+    /// A well-formed packet for a routed destination is transmitted
+    /// unchanged on the route's port, counted in
+    /// [`ServerStats::packets_forwarded`](crate::ServerStats), and never
+    /// reaches the router, and [`InboundDisposition::Forwarded`] is returned;
+    /// a transmit error is returned to the caller. Every other packet goes
+    /// to [`inbound`](Self::inbound).
+    pub fn inbound_bridged(
+        &mut self,
+        pkt: &[u8],
+        ports: &mut impl OutboundPort,
+    ) -> Result<InboundDisposition, MctpError> {
+        let port = if is_well_formed(pkt) {
+            self.route(pkt[1])
+        } else {
+            None
+        };
+        let Some(port) = port else {
+            return self.inbound(pkt);
+        };
+        self.count_forwarded();
+        ports
+            .transmit(port, pkt)
+            .map_err(crate::server::mctp_error_to_server_error)?;
+        Ok(InboundDisposition::Forwarded(port))
+    }
+}
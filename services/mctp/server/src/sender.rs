@@ -0,0 +1,98 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Closure-backed outbound transport.
+//!
+//! The `mctp-lib` [`Router`](mctp_lib::Router) already stores its outbound
+//! port as a [`Sender`] implementor and hands every fragmented packet to it.
+//! Transports that only need "write these bytes" can use [`FnSender`]
+//! instead of implementing the fragmentation loop themselves.
+//! [`FnSender::with_sink`] also copies every packet to a [`PacketSink`].
+
+use mctp::Tag;
+use mctp_lib::fragment::{Fragmenter, SendOutput};
+use mctp_lib::Sender;
+
+use crate::{Direction, PacketSink};
+
+/// Largest MTU supported by [`FnSender`] (MCTP payload, without header).
+pub const FN_SENDER_MAX_MTU: usize = 255;
+
+/// Smallest MTU used by [`FnSender`]: the MCTP baseline transmission unit
+/// (DSP0236), which every binding must carry.
+pub const FN_SENDER_MIN_MTU: usize = 64;
+
+/// MCTP transport header size in bytes.
+const MCTP_HEADER_SIZE: usize = 4;
+
+/// A [`Sender`] that passes each outbound MCTP packet to a closure.
+///
+/// The closure receives one complete MCTP packet (header included) per
+/// call. Returning an error aborts the message and is propagated to the
+/// caller of [`Server::send`](crate::Server::send).
+///
+/// `P` is the [`PacketSink`] every packet is captured to before it is
+/// transmitted; the default `()` captures nothing.
+pub struct FnSender<F, P = ()> {
+    transmit: F,
+    sink: P,
+    mtu: usize,
+}
+
+impl<F> FnSender<F>
+where
+    F: FnMut(&[u8]) -> mctp::Result<()>,
+{
+    /// Create a sender fragmenting at `mtu` bytes.
+    ///
+    /// `mtu` is clamped to [`FN_SENDER_MIN_MTU`]..=[`FN_SENDER_MAX_MTU`].
+    pub fn new(mtu: usize, transmit: F) -> Self {
+        Self::with_sink(mtu, transmit, ())
+    }
+}
+
+impl<F, P> FnSender<F, P>
+where
+    F: FnMut(&[u8]) -> mctp::Result<()>,
+    P: PacketSink,
+{
+    /// Create a sender fragmenting at `mtu` bytes that also passes every
+    /// packet to `sink`, as [`Direction::Outbound`], before transmitting it.
+    ///
+    /// `mtu` is clamped to [`FN_SENDER_MIN_MTU`]..=[`FN_SENDER_MAX_MTU`].
+    pub fn with_sink(mtu: usize, transmit: F, sink: P) -> Self {
+        Self {
+            transmit,
+            sink,
+            mtu: mtu.clamp(FN_SENDER_MIN_MTU, FN_SENDER_MAX_MTU),
+        }
+    }
+}
+
+impl<F, P> Sender for FnSender<F, P>
+where
+    F: FnMut(&[u8]) -> mctp::Result<()>,
+    P: PacketSink,
+{
+    fn send_vectored(
+        &mut self,
+        mut fragmenter: Fragmenter,
+        payload: &[&[u8]],
+    ) -> mctp::Result<Tag> {
+        loop {
+            let mut buf = [0u8; FN_SENDER_MAX_MTU + MCTP_HEADER_SIZE];
+            match fragmenter.fragment_vectored(payload, &mut buf) {
+                SendOutput::Packet(p) => {
+                    self.sink.capture(Direction::Outbound, p);
+                    (self.transmit)(p)?;
+                }
+                SendOutput::Complete { tag, .. } => return Ok(tag),
+                SendOutput::Error { err, .. } => return Err(err),
+            }
+        }
+    }
+
+    fn get_mtu(&self) -> usize {
+        self.mtu
+    }
+}
@@ -9,16 +9,39 @@
 //! `Leased`, `RecvMessage`) have been replaced with platform-independent
 //! equivalents.
 
+use core::task::{Context, Poll, Waker};
+
 use heapless::LinearMap;
 use mctp::{Eid, MsgIC, MsgType, Tag, TagValue};
 use mctp_lib::{AppCookie, Router, Sender};
 use openprot_mctp_api::{Handle, MctpError, RecvMetadata, ResponseCode};
 
+use crate::bootstrap::EidMode;
+use crate::drop_log::DropLog;
+use crate::instance::{self, InstanceCheck};
+use crate::retry::SentRequest;
+use crate::route::Route;
+use crate::{DropReason, EidPool, PortId};
+
 /// Maximum payload size in bytes.
 // TODO: Use configuration from mctp-lib (mctp-estack)
 //       see https://github.com/OpenPRoT/mctp-lib/issues/4
 const MAX_PAYLOAD: usize = 1023;
 
+/// Size of the MCTP transport header (DSP0236).
+pub(crate) const MCTP_HEADER_LEN: usize = 4;
+/// Header version field in the first transport header byte.
+const MCTP_HDR_VERSION_MASK: u8 = 0x0F;
+/// The only header version defined by DSP0236.
+const MCTP_HDR_VERSION: u8 = 0x01;
+/// Start-of-message flag in the fourth transport header byte.
+pub(crate) const MCTP_FLAG_SOM: u8 = 0x80;
+/// Null EID (DSP0236), the destination used for endpoints without an
+/// assigned EID.
+pub(crate) const EID_NULL: u8 = 0x00;
+/// Broadcast EID (DSP0236); never assignable.
+pub(crate) const EID_BROADCAST: u8 = 0xFF;
+
 /// Configuration constants for the MCTP server.
 pub struct ServerConfig;
 
@@ -31,6 +54,73 @@ impl ServerConfig {
     pub const MAX_OUTSTANDING: usize = 16;
     /// Maximum payload size in bytes.
     pub const MAX_PAYLOAD: usize = MAX_PAYLOAD;
+    /// Default number of times [`Server::retry`] re-sends one request.
+    pub const MAX_RETRIES: u8 = 3;
+    /// Maximum number of bridge routes (see [`Server::add_route`]).
+    pub const MAX_ROUTES: usize = 4;
+}
+
+/// A [`Server`] using the default [`ServerConfig`] table sizes.
+pub type DefaultServer<S> = Server<S, { ServerConfig::MAX_OUTSTANDING }>;
+
+/// What a bound handle was allocated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleKind {
+    /// A listener for the given MCTP message type.
+    Listener(u8),
+    /// A request channel to the given remote EID.
+    Request(u8),
+}
+
+/// Counters describing server activity, for production monitoring.
+///
+/// All counters saturate at `u32::MAX`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ServerStats {
+    /// Packets passed to [`Server::inbound`].
+    pub packets_received: u32,
+    /// Packets forwarded on a bridge route by [`Server::inbound_bridged`].
+    pub packets_forwarded: u32,
+    /// Inbound packets the router rejected with an error.
+    pub inbound_errors: u32,
+    /// Messages handed to the caller by `try_recv`, `try_recv_any` or `update`.
+    pub messages_delivered: u32,
+    /// Messages sent successfully.
+    pub messages_sent: u32,
+    /// Sends rejected because the payload exceeds the maximum message size.
+    pub fragmentation_errors: u32,
+    /// Sends that failed inside the router or transport.
+    pub send_errors: u32,
+    /// Pending receive calls that timed out.
+    pub recv_timeouts: u32,
+    /// Requests re-sent by [`Server::retry`].
+    pub request_retries: u32,
+    /// Responses dropped because their instance ID did not match the
+    /// request sent with [`Server::send_with_instance_id`].
+    pub stale_responses: u32,
+}
+
+/// One message for [`Server::send_batch`], with the arguments of
+/// [`Server::send`].
+#[derive(Debug, Clone, Copy)]
+pub struct SendItem<'a> {
+    /// Request handle, or `None` for a response.
+    pub handle: Option<Handle>,
+    /// MCTP message type.
+    pub typ: u8,
+    /// Destination EID; required for responses.
+    pub eid: Option<u8>,
+    /// Tag; required for responses.
+    pub tag: Option<u8>,
+    /// Integrity check bit.
+    pub ic: bool,
+    /// Message payload.
+    pub payload: &'a [u8],
+}
+
+/// Increment a statistics counter.
+fn bump(counter: &mut u32) {
+    *counter = counter.saturating_add(1);
 }
 
 /// A pending receive call waiting for a message or timeout.
@@ -50,75 +140,373 @@ struct PendingRecv {
 ///
 /// * `S` - The [`Sender`] implementation for outbound transport.
 /// * `OUTSTANDING` - Maximum number of concurrent pending receive calls.
-pub struct Server<S: Sender, const OUTSTANDING: usize> {
+/// * `LISTENERS` - Size of the router's listener table
+///   (default [`ServerConfig::MAX_LISTENERS`]).
+/// * `REQUESTS` - Size of the router's request table
+///   (default [`ServerConfig::MAX_REQUESTS`]).
+pub struct Server<
+    S: Sender,
+    const OUTSTANDING: usize,
+    const LISTENERS: usize = { ServerConfig::MAX_LISTENERS },
+    const REQUESTS: usize = { ServerConfig::MAX_REQUESTS },
+> {
     /// The underlying MCTP router (from mctp-lib).
-    pub stack: Router<S, { ServerConfig::MAX_LISTENERS }, { ServerConfig::MAX_REQUESTS }>,
+    pub stack: Router<S, LISTENERS, REQUESTS>,
     /// Currently outstanding recv calls, keyed by handle value.
     ///
     /// Maps the handle to a deadline. The platform layer is responsible
     /// for storing any additional per-recv state (e.g., reply channels).
     outstanding: LinearMap<u32, PendingRecv, OUTSTANDING>,
+    /// Wakers of tasks waiting in `poll_recv`, keyed by handle value.
+    wakers: LinearMap<u32, Waker, OUTSTANDING>,
+    /// Listener handles currently bound, mapped to their message type.
+    listeners: LinearMap<u32, u8, LISTENERS>,
+    /// Request handles currently bound, mapped to their remote EID.
+    requests: LinearMap<u32, u8, REQUESTS>,
+    /// Instance IDs of outstanding requests sent with
+    /// [`send_with_instance_id`](Self::send_with_instance_id).
+    pub(crate) instance_ids: LinearMap<u32, u8, REQUESTS>,
+    /// Request handles with a sent request awaiting its response.
+    in_flight: LinearMap<u32, (), REQUESTS>,
+    /// Whether `send` rejects a request on a handle that is still in flight.
+    reject_busy_requests: bool,
+    /// The last request sent on each request handle, for
+    /// [`retry`](Self::retry).
+    pub(crate) sent_requests: LinearMap<u32, SentRequest, REQUESTS>,
+    /// How many times `retry` re-sends one request.
+    pub(crate) max_retries: u8,
+    /// EIDs handed out to downstream endpoints when acting as bus owner.
+    pub(crate) eid_pool: EidPool,
+    /// Bridge routes to other ports; see [`add_route`](Self::add_route).
+    pub(crate) routes: heapless::Vec<Route, { ServerConfig::MAX_ROUTES }>,
+    /// Whether `req` reuses an existing handle for the same EID.
+    coalesce_requests: bool,
+    /// How `inbound` matches destination EIDs; see
+    /// [`set_bootstrap`](Self::set_bootstrap).
+    pub(crate) eid_mode: EidMode,
+    /// Position in the bound-handle list where `try_recv_any` starts scanning.
+    recv_any_start: usize,
+    /// Activity counters.
+    stats: ServerStats,
+    /// Where dropped packets are reported; see [`DropReason`].
+    pub(crate) drop_log: DropLog,
 }
 
-impl<S: Sender, const OUTSTANDING: usize> Server<S, OUTSTANDING> {
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
     /// Create a new MCTP server instance.
     pub fn new(own_eid: Eid, now_millis: u64, outbound: S) -> Self {
         let stack = Router::new(own_eid, now_millis, outbound);
         Self {
             stack,
             outstanding: LinearMap::new(),
+            wakers: LinearMap::new(),
+            listeners: LinearMap::new(),
+            requests: LinearMap::new(),
+            instance_ids: LinearMap::new(),
+            in_flight: LinearMap::new(),
+            reject_busy_requests: false,
+            sent_requests: LinearMap::new(),
+            max_retries: ServerConfig::MAX_RETRIES,
+            eid_pool: EidPool::empty(),
+            routes: heapless::Vec::new(),
+            coalesce_requests: false,
+            eid_mode: EidMode::Router,
+            recv_any_start: 0,
+            stats: ServerStats::default(),
+            drop_log: DropLog::default(),
         }
     }
 
     /// Allocate a request handle for sending messages to the given EID.
+    ///
+    /// With [request coalescing](Self::set_request_coalescing) enabled, an
+    /// existing handle for `eid` is returned instead of allocating a new one.
     pub fn req(&mut self, eid: u8) -> Result<Handle, MctpError> {
+        let shared = self
+            .active_requests()
+            .find(|&(_, e)| e == eid)
+            .filter(|_| self.coalesce_requests);
+        if let Some((handle, _)) = shared {
+            return Ok(handle);
+        }
         match self.stack.req(Eid(eid)) {
-            Ok(cookie) => Ok(Handle(cookie.0 as u32)),
+            Ok(cookie) => Ok(self.bind(cookie, HandleKind::Request(eid))),
             Err(e) => Err(mctp_error_to_server_error(e)),
         }
     }
 
+    /// Choose whether [`req`](Self::req) shares one handle per remote EID.
+    ///
+    /// Coalescing saves request slots on endpoints that open many channels
+    /// to the same peer. A shared handle is a single binding: unbinding it
+    /// through any holder releases it for all of them, and its messages are
+    /// delivered to whichever holder receives first. Off by default.
+    pub fn set_request_coalescing(&mut self, enabled: bool) {
+        self.coalesce_requests = enabled;
+    }
+
+    /// Whether a request sent on `handle` is still awaiting its response.
+    ///
+    /// A request handle becomes busy when [`send`](Self::send) succeeds on
+    /// it, and idle again once a message is delivered on it by any receive
+    /// call, its pending receive times out in [`update`](Self::update), or
+    /// it is unbound.
+    pub fn request_busy(&self, handle: Handle) -> bool {
+        self.in_flight.contains_key(&handle.0)
+    }
+
+    /// Choose whether [`send`](Self::send) on a
+    /// [busy](Self::request_busy) request handle fails with `AddrInUse`.
+    ///
+    /// Off by default, in which case a new request replaces the one in
+    /// flight.
+    pub fn set_reject_busy_requests(&mut self, enabled: bool) {
+        self.reject_busy_requests = enabled;
+    }
+
     /// Register a listener for incoming messages of the given type.
     pub fn listener(&mut self, typ: u8) -> Result<Handle, MctpError> {
         match self.stack.listener(MsgType(typ)) {
-            Ok(cookie) => Ok(Handle(cookie.0 as u32)),
+            Ok(cookie) => Ok(self.bind(cookie, HandleKind::Listener(typ))),
             Err(e) => Err(mctp_error_to_server_error(e)),
         }
     }
 
+    /// Record a freshly allocated router cookie as a bound handle.
+    fn bind(&mut self, cookie: AppCookie, kind: HandleKind) -> Handle {
+        let handle = Handle(cookie.0 as u32);
+        // Each map holds one entry per router slot, so it cannot overflow.
+        let _ = match kind {
+            HandleKind::Listener(typ) => self.listeners.insert(handle.0, typ),
+            HandleKind::Request(eid) => self.requests.insert(handle.0, eid),
+        };
+        handle
+    }
+
+    /// Report what `handle` was allocated for.
+    ///
+    /// Returns `None` if the handle is not currently bound, including
+    /// handles that were unbound and values never issued by this server.
+    pub fn handle_kind(&self, handle: Handle) -> Option<HandleKind> {
+        if let Some(&typ) = self.listeners.get(&handle.0) {
+            return Some(HandleKind::Listener(typ));
+        }
+        self.requests
+            .get(&handle.0)
+            .map(|&eid| HandleKind::Request(eid))
+    }
+
+    /// Bound listener handles with the message type each listens for.
+    ///
+    /// Together with [`active_requests`](Self::active_requests) this is the
+    /// server's connection table, for diagnostics dumps.
+    pub fn active_listeners(&self) -> impl Iterator<Item = (Handle, u8)> + '_ {
+        self.listeners.iter().map(|(&h, &typ)| (Handle(h), typ))
+    }
+
+    /// Bound request handles with the remote EID each targets.
+    pub fn active_requests(&self) -> impl Iterator<Item = (Handle, u8)> + '_ {
+        self.requests.iter().map(|(&h, &eid)| (Handle(h), eid))
+    }
+
+    /// Message types with a bound listener.
+    pub(crate) fn listener_types(&self) -> impl Iterator<Item = u8> + '_ {
+        self.listeners.values().copied()
+    }
+
+    /// Activity counters accumulated since construction.
+    pub fn stats(&self) -> &ServerStats {
+        &self.stats
+    }
+
     /// Get the currently configured EID.
     pub fn get_eid(&self) -> u8 {
         self.stack.get_eid().0
     }
 
     /// Set the EID for this endpoint.
+    ///
+    /// Assigning a non-null EID ends [bootstrap mode](Self::set_bootstrap).
     pub fn set_eid(&mut self, eid: u8) -> Result<(), MctpError> {
         self.stack
             .set_eid(Eid(eid))
-            .map_err(mctp_error_to_server_error)
+            .map_err(mctp_error_to_server_error)?;
+        self.eid_assigned(eid);
+        Ok(())
+    }
+
+    /// Configure the range of EIDs assigned to downstream endpoints.
+    ///
+    /// Replaces any previous pool, forgetting its assignments.
+    pub fn set_eid_pool(&mut self, pool: EidPool) {
+        self.eid_pool = pool;
+    }
+
+    /// Assign a unique EID to a downstream endpoint (bus-owner role).
+    ///
+    /// Never returns this endpoint's own EID. Returns `NoSpace` when the
+    /// pool is exhausted or no pool has been configured.
+    pub fn assign_eid(&mut self) -> Result<u8, MctpError> {
+        let own = self.get_eid();
+        self.eid_pool.assign(own)
+    }
+
+    /// Release an EID previously returned by [`assign_eid`](Self::assign_eid).
+    pub fn release_eid(&mut self, eid: u8) -> Result<(), MctpError> {
+        self.eid_pool.release(eid)
     }
 
     /// Check for an available message on the given handle.
     ///
     /// If a message is available, returns the metadata and copies the
-    /// payload into `buf`. Otherwise returns `None` and the caller
+    /// payload into `buf`. The metadata holds everything needed to reply
+    /// (source EID, tag, message type and IC bit), so callers never touch
+    /// the router's message type. Otherwise returns `None` and the caller
     /// should register a pending recv via [`register_recv`](Self::register_recv).
+    /// Unbound handles always return `None`.
+    ///
+    /// A response whose instance ID does not match the request sent with
+    /// [`send_with_instance_id`](Self::send_with_instance_id) is dropped and
+    /// `None` returned; the request stays outstanding.
     pub fn try_recv(&mut self, handle: Handle, buf: &mut [u8]) -> Option<RecvMetadata> {
-        let cookie = AppCookie(handle.0 as usize);
-        let msg = self.stack.recv(cookie)?;
+        let (metadata, payload) = self.try_recv_borrowed(handle)?;
+        copy_payload(payload, buf);
+        Some(metadata)
+    }
 
-        let payload_len = msg.payload.len();
-        if payload_len <= buf.len() {
-            buf[..payload_len].copy_from_slice(msg.payload);
-        }
+    /// Check for an available message on the given handle, borrowing its
+    /// payload from the router instead of copying it out.
+    ///
+    /// Behaves as [`try_recv`](Self::try_recv), but the payload is returned
+    /// in place, valid until the server is next used. A bridge can hand it
+    /// straight to [`send`](Self::send) on the server of another port:
+    ///
+    /// ```ignore
+    /// if let Some((meta, payload)) = upstream.try_recv_borrowed(listener) {
+    ///     downstream.send(Some(req), meta.msg_type, None, None, meta.msg_ic, payload)?;
+    /// }
+    /// ```
+    ///
+    /// Replying on the same server still needs a copy: the payload lives in
+    /// the router that would fragment the reply.
+    pub fn try_recv_borrowed(&mut self, handle: Handle) -> Option<(RecvMetadata, &[u8])> {
+        // A stale handle may name a router slot that has since been reused.
+        self.handle_kind(handle)?;
+        self.receive(handle.0)
+    }
 
-        Some(RecvMetadata {
+    /// Take the next message queued for `handle_val` from the router.
+    ///
+    /// The one receive path shared by every receive call: responses with a
+    /// mismatched instance ID are dropped here, and delivered messages are
+    /// counted and end the handle's in-flight request.
+    fn receive(&mut self, handle_val: u32) -> Option<(RecvMetadata, &[u8])> {
+        let msg = self
+            .stack
+            .recv(AppCookie(handle_val as usize))
+            .filter(|msg| {
+                !stale_response(
+                    &mut self.instance_ids,
+                    &mut self.stats,
+                    &mut self.drop_log,
+                    handle_val,
+                    msg.payload,
+                )
+            })?;
+        bump(&mut self.stats.messages_delivered);
+        self.in_flight.remove(&handle_val);
+
+        let metadata = RecvMetadata {
             msg_type: msg.typ.0,
             msg_ic: msg.ic.0,
             msg_tag: msg.tag.tag().0,
             remote_eid: msg.source.0,
-            payload_size: payload_len,
-        })
+            payload_size: msg.payload.len(),
+        };
+        Some((metadata, msg.payload))
+    }
+
+    /// Check every bound handle for an available message.
+    ///
+    /// Returns the first handle that has a message waiting, together with
+    /// its metadata, and copies the payload into `buf`. Lets an event loop
+    /// servicing many listeners and requests pull whatever is ready without
+    /// polling each handle with [`try_recv`](Self::try_recv).
+    ///
+    /// Scanning resumes after the handle returned by the previous call, so
+    /// a busy handle cannot starve the others.
+    pub fn try_recv_any(&mut self, buf: &mut [u8]) -> Option<(Handle, RecvMetadata)> {
+        let count = self.listeners.len() + self.requests.len();
+        for offset in 0..count {
+            // Reduce the start first: a seeded value can be anything.
+            let pos = (self.recv_any_start % count + offset) % count;
+            let &handle_val = self.listeners.keys().chain(self.requests.keys()).nth(pos)?;
+            let Some((metadata, payload)) = self.receive(handle_val) else {
+                continue;
+            };
+            copy_payload(payload, buf);
+            self.recv_any_start = (pos + 1) % count;
+            return Some((Handle(handle_val), metadata));
+        }
+        None
+    }
+
+    /// Set the position at which the next [`try_recv_any`](Self::try_recv_any)
+    /// starts scanning, for deterministic ordering in tests.
+    ///
+    /// Positions count bound listeners first, then bound requests, and wrap
+    /// around the number of bound handles.
+    pub fn seed_recv_any(&mut self, start: usize) {
+        self.recv_any_start = start;
+    }
+
+    /// Poll for a message on `handle`, registering the task's waker when
+    /// none is available yet.
+    ///
+    /// This is the building block for async receive: wrap it with
+    /// [`core::future::poll_fn`] to get a future that resolves once a
+    /// message for `handle` has been fed through [`inbound`](Self::inbound).
+    /// Nothing is allocated; wakers are stored in a table of `OUTSTANDING`
+    /// entries, and `NoSpace` is returned when it is full.
+    ///
+    /// Only one task may wait on a handle at a time. Polling with a waker
+    /// that would not wake the registered one returns `AddrInUse`; a task
+    /// that abandons its wait must call [`cancel_poll_recv`](Self::cancel_poll_recv).
+    /// Unbound handles return `BadArgument`.
+    pub fn poll_recv(
+        &mut self,
+        handle: Handle,
+        buf: &mut [u8],
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<RecvMetadata, MctpError>> {
+        if self.handle_kind(handle).is_none() {
+            return Poll::Ready(Err(MctpError::from_code(ResponseCode::BadArgument)));
+        }
+        let waker = cx.waker();
+        if self
+            .wakers
+            .get(&handle.0)
+            .is_some_and(|registered| !registered.will_wake(waker))
+        {
+            return Poll::Ready(Err(MctpError::from_code(ResponseCode::AddrInUse)));
+        }
+
+        if let Some(metadata) = self.try_recv(handle, buf) {
+            self.wakers.remove(&handle.0);
+            return Poll::Ready(Ok(metadata));
+        }
+
+        match self.wakers.insert(handle.0, waker.clone()) {
+            Ok(_) => Poll::Pending,
+            Err(_) => Poll::Ready(Err(MctpError::from_code(ResponseCode::NoSpace))),
+        }
+    }
+
+    /// Drop the waker registered for `handle` by [`poll_recv`](Self::poll_recv).
+    pub fn cancel_poll_recv(&mut self, handle: Handle) {
+        self.wakers.remove(&handle.0);
     }
 
     /// Register a pending receive call for the given handle.
@@ -154,6 +542,16 @@ impl<S: Sender, const OUTSTANDING: usize> Server<S, OUTSTANDING> {
     /// For requests, `handle` is `Some`. For responses, `handle` is `None`.
     /// When responding to a request received by a listener, `eid` and `tag`
     /// must be set. Returns the tag value used.
+    ///
+    /// The tag owner (TO) bit follows the same split. A request is sent
+    /// with TO set, under `tag` if given or else a tag the router allocates
+    /// for the handle. A response is sent with TO clear, reusing the tag of
+    /// the request it answers, so a response without `eid` and `tag` fails
+    /// with `BadArgument` rather than going out as a new request.
+    ///
+    /// With [`set_reject_busy_requests`](Self::set_reject_busy_requests)
+    /// enabled, a request on a handle that is still awaiting its response
+    /// fails with `AddrInUse`.
     pub fn send(
         &mut self,
         handle: Option<Handle>,
@@ -164,11 +562,19 @@ impl<S: Sender, const OUTSTANDING: usize> Server<S, OUTSTANDING> {
         buf: &[u8],
     ) -> Result<u8, MctpError> {
         if buf.len() > MAX_PAYLOAD {
+            bump(&mut self.stats.fragmentation_errors);
             return Err(MctpError::from_code(ResponseCode::NoSpace));
         }
+        let request = handle.filter(|h| self.requests.contains_key(&h.0));
+        if self.reject_busy_requests && request.is_some_and(|h| self.request_busy(h)) {
+            return Err(MctpError::from_code(ResponseCode::AddrInUse));
+        }
 
         let tag = if handle.is_none() {
-            // Responses use unowned tags
+            // Responses echo the requester's tag with the owner bit clear
+            if eid.is_none() || tag.is_none() {
+                return Err(MctpError::from_code(ResponseCode::BadArgument));
+            }
             tag.map(|x| Tag::Unowned(TagValue(x)))
         } else {
             // Requests use owned tags (or allocate a new one)
@@ -183,16 +589,66 @@ impl<S: Sender, const OUTSTANDING: usize> Server<S, OUTSTANDING> {
             .send(eid.map(Eid), MsgType(typ), tag, MsgIC(ic), cookie, buf);
 
         match result {
-            Ok(tag) => Ok(tag.tag().0),
-            Err(e) => Err(mctp_error_to_server_error(e)),
+            Ok(tag) => {
+                bump(&mut self.stats.messages_sent);
+                if let Some(h) = request {
+                    // One entry per bound request handle, so this cannot overflow.
+                    let _ = self.in_flight.insert(h.0, ());
+                    self.request_sent(h, typ, ic);
+                }
+                Ok(tag.tag().0)
+            }
+            Err(e) => {
+                bump(&mut self.stats.send_errors);
+                Err(mctp_error_to_server_error(e))
+            }
         }
     }
 
+    /// Send several messages back to back, in order.
+    ///
+    /// Each item is sent as by [`send`](Self::send). Sending stops at the
+    /// first failure, which is reported with the index of the failed item;
+    /// items before it have been sent and items after it have not.
+    pub fn send_batch(&mut self, items: &[SendItem<'_>]) -> Result<(), (usize, MctpError)> {
+        for (index, item) in items.iter().enumerate() {
+            self.send(
+                item.handle,
+                item.typ,
+                item.eid,
+                item.tag,
+                item.ic,
+                item.payload,
+            )
+            .map_err(|e| (index, e))?;
+        }
+        Ok(())
+    }
+
+    /// Reply to a message received on a listener.
+    ///
+    /// Sends `buf` back to the request's source EID with its message type,
+    /// IC bit and tag, so the requester's owned tag is answered with the
+    /// matching unowned tag. Equivalent to calling [`send`](Self::send)
+    /// with no handle and those fields copied from `request`.
+    pub fn respond(&mut self, request: &RecvMetadata, buf: &[u8]) -> Result<u8, MctpError> {
+        self.send(
+            None,
+            request.msg_type,
+            Some(request.remote_eid),
+            Some(request.msg_tag),
+            request.msg_ic,
+            buf,
+        )
+    }
+
     /// Update the stack and check for fulfilled receive calls.
     ///
     /// Should be called on timer events. Returns the interval (ms) until
     /// the next required update, and a list of handles that now have
     /// messages available (the platform layer should deliver them).
+    /// Responses with a mismatched instance ID are dropped, as by
+    /// [`try_recv`](Self::try_recv).
     pub fn update(
         &mut self,
         now_millis: u64,
@@ -203,59 +659,161 @@ impl<S: Sender, const OUTSTANDING: usize> Server<S, OUTSTANDING> {
 
         let mut ready: heapless::Vec<(Handle, RecvResult), OUTSTANDING> = heapless::Vec::new();
 
-        for (handle_val, pending) in self.outstanding.iter() {
-            let handle = Handle(*handle_val);
-            let cookie = AppCookie(*handle_val as usize);
+        // Snapshot the pending receives: receiving needs the whole server.
+        // Both tables hold OUTSTANDING entries, so every push succeeds.
+        let mut waiting: heapless::Vec<(u32, PendingRecv), OUTSTANDING> = heapless::Vec::new();
+        for (&handle_val, &entry) in self.outstanding.iter() {
+            let _ = waiting.push((handle_val, entry));
+        }
+
+        for (handle_val, pending) in waiting {
+            let handle = Handle(handle_val);
 
             // Check if a message arrived for this handle
-            if let Some(mctp_msg) = self.stack.recv(cookie) {
-                let payload_len = mctp_msg.payload.len();
-                if payload_len <= recv_buf.len() {
-                    recv_buf[..payload_len].copy_from_slice(mctp_msg.payload);
-                }
-                let metadata = RecvMetadata {
-                    msg_type: mctp_msg.typ.0,
-                    msg_ic: mctp_msg.ic.0,
-                    msg_tag: mctp_msg.tag.tag().0,
-                    remote_eid: mctp_msg.source.0,
-                    payload_size: payload_len,
-                };
+            if let Some((metadata, payload)) = self.receive(handle_val) {
+                copy_payload(payload, recv_buf);
                 let _ = ready.push((handle, RecvResult::Message(metadata)));
                 continue;
             }
 
             // Check for timeout
             if pending.deadline != 0 && now_millis >= pending.deadline {
+                bump(&mut self.stats.recv_timeouts);
+                self.in_flight.remove(&handle_val);
                 let _ = ready.push((handle, RecvResult::TimedOut));
             }
         }
 
         // Remove fulfilled/timed-out entries
-        for (handle, _) in &ready {
+        for (handle, result) in &ready {
             self.outstanding.remove(&handle.0);
+            if let RecvResult::TimedOut = result {
+                self.request_timed_out(*handle);
+            }
         }
 
         (stack_timeout, ready)
     }
 
     /// Unbind a handle previously allocated by `req` or `listener`.
+    ///
+    /// Messages still queued for the handle are discarded. A request handle shared through
+    /// [request coalescing](Self::set_request_coalescing) is released for
+    /// every holder.
     pub fn unbind(&mut self, handle: Handle) -> Result<(), MctpError> {
         let cookie = AppCookie(handle.0 as usize);
+        self.drain(cookie);
         let _ = self.stack.unbind(cookie);
         self.outstanding.remove(&handle.0);
+        self.wakers.remove(&handle.0);
+        self.listeners.remove(&handle.0);
+        self.requests.remove(&handle.0);
+        self.instance_ids.remove(&handle.0);
+        self.in_flight.remove(&handle.0);
+        self.sent_requests.remove(&handle.0);
         Ok(())
     }
 
+    /// Discard every message the router holds for `cookie`, so a handle
+    /// later bound to the same slot never sees them.
+    fn drain(&mut self, cookie: AppCookie) {
+        while self.stack.recv(cookie).is_some() {}
+    }
+
+    /// Return the server to a clean state without reconstructing it.
+    ///
+    /// Unbinds every listener and request handle (cancelling their flows in
+    /// the router and discarding their queued messages) and drops all
+    /// pending receive calls. The configured EID, EID pool, bridge routes
+    /// and outbound transport are preserved. Intended for use after a
+    /// link-layer reset or a detected protocol error storm.
+    pub fn reset(&mut self) {
+        for &handle_val in self.listeners.keys().chain(self.requests.keys()) {
+            let cookie = AppCookie(handle_val as usize);
+            // As `drain`, inlined while the handle maps are borrowed.
+            while self.stack.recv(cookie).is_some() {}
+            let _ = self.stack.unbind(cookie);
+        }
+        self.listeners.clear();
+        self.requests.clear();
+        self.instance_ids.clear();
+        self.in_flight.clear();
+        self.sent_requests.clear();
+        self.outstanding.clear();
+        self.wakers.clear();
+    }
+
     /// Feed an inbound MCTP packet to the router.
     ///
     /// The platform layer calls this when data arrives from a transport
     /// binding. The packet should be a raw MCTP packet without transport
-    /// headers (the transport binding strips those).
-    pub fn inbound(&mut self, pkt: &[u8]) -> Result<(), MctpError> {
-        self.stack.inbound(pkt).map_err(mctp_error_to_server_error)
+    /// headers (the transport binding strips those). Tasks waiting in
+    /// [`poll_recv`](Self::poll_recv) are woken.
+    ///
+    /// Packets shorter than the MCTP header, start-of-message packets
+    /// without a message type byte, and unknown header versions are
+    /// rejected with `BadArgument` before they reach the router, as are
+    /// packets the router itself rejects. Packets for other destinations,
+    /// and in [bootstrap mode](Self::set_bootstrap) non-control packets,
+    /// are dropped without an error; the returned [`InboundDisposition`]
+    /// tells them apart from packets handed to the router.
+    pub fn inbound(&mut self, pkt: &[u8]) -> Result<InboundDisposition, MctpError> {
+        if !is_well_formed(pkt) {
+            self.log_drop(DropReason::Malformed, pkt);
+            self.count_inbound_rejection();
+            return Err(MctpError::from_code(ResponseCode::BadArgument));
+        }
+        bump(&mut self.stats.packets_received);
+        if let Some(reason) = self.drop_reason(pkt) {
+            self.log_drop(reason, pkt);
+            return Ok(InboundDisposition::Dropped(reason));
+        }
+        self.stack.inbound(pkt).map_err(|e| {
+            self.log_drop(DropReason::Rejected, pkt);
+            bump(&mut self.stats.inbound_errors);
+            mctp_error_to_server_error(e)
+        })?;
+
+        // The router does not report which handle a packet completed, so
+        // wake every waiting task and let each re-poll its own handle.
+        for waker in self.wakers.values() {
+            waker.wake_by_ref();
+        }
+        Ok(InboundDisposition::Delivered)
+    }
+
+    /// Count an inbound packet rejected before reaching the router.
+    pub(crate) fn count_inbound_rejection(&mut self) {
+        bump(&mut self.stats.packets_received);
+        bump(&mut self.stats.inbound_errors);
+    }
+
+    /// Count a packet forwarded on a bridge route.
+    pub(crate) fn count_forwarded(&mut self) {
+        bump(&mut self.stats.packets_received);
+        bump(&mut self.stats.packets_forwarded);
+    }
+
+    /// Count a request re-sent by [`retry`](Self::retry).
+    pub(crate) fn count_retry(&mut self) {
+        bump(&mut self.stats.request_retries);
     }
 }
 
+/// What [`Server::inbound`] did with a well-formed packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InboundDisposition {
+    /// Handed to the router for reassembly and delivery to a listener or
+    /// request. Drops decided inside the router (no listener, no matching
+    /// request) are not visible to the server and are reported here too.
+    Delivered,
+    /// Transmitted on a bridge port by [`Server::inbound_bridged`].
+    Forwarded(PortId),
+    /// Dropped before reaching the router, as [`Server::drop_reason`]
+    /// reports.
+    Dropped(DropReason),
+}
+
 /// Result of a pending receive call.
 #[derive(Debug, Clone, Copy)]
 pub enum RecvResult {
@@ -265,8 +823,48 @@ pub enum RecvResult {
     TimedOut,
 }
 
+/// Copy a received payload into `buf`, if it fits.
+fn copy_payload(payload: &[u8], buf: &mut [u8]) {
+    if let Some(dst) = buf.get_mut(..payload.len()) {
+        dst.copy_from_slice(payload);
+    }
+}
+
+/// Returns `true`, counting the drop in `stats` and reporting it to
+/// `drop_log`, if `payload` is a response whose instance ID does not match
+/// the request tracked for `handle_val`.
+fn stale_response<const REQUESTS: usize>(
+    ids: &mut LinearMap<u32, u8, REQUESTS>,
+    stats: &mut ServerStats,
+    drop_log: &mut DropLog,
+    handle_val: u32,
+    payload: &[u8],
+) -> bool {
+    let stale = matches!(
+        instance::check(ids, handle_val, payload),
+        InstanceCheck::Mismatched { .. }
+    );
+    if stale {
+        bump(&mut stats.stale_responses);
+        drop_log.report_stale(handle_val as usize);
+    }
+    stale
+}
+
+/// Check the transport header of a raw packet before it reaches the router.
+pub(crate) fn is_well_formed(pkt: &[u8]) -> bool {
+    let Some(header) = pkt.get(..MCTP_HEADER_LEN) else {
+        return false;
+    };
+    if header[0] & MCTP_HDR_VERSION_MASK != MCTP_HDR_VERSION {
+        return false;
+    }
+    // A first packet must also carry the message type byte.
+    header[3] & MCTP_FLAG_SOM == 0 || pkt.len() > MCTP_HEADER_LEN
+}
+
 /// Map mctp::Error to our MctpError.
-fn mctp_error_to_server_error(e: mctp::Error) -> MctpError {
+pub(crate) fn mctp_error_to_server_error(e: mctp::Error) -> MctpError {
     use mctp::Error::*;
     let code = match e {
         InternalError => ResponseCode::InternalError,
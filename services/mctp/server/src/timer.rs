@@ -0,0 +1,44 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Timer-driven entry points.
+//!
+//! [`Server::new`], [`Server::register_recv`] and [`Server::update`] take
+//! the current time as an argument. Platforms with a [`MonotonicTimer`] can
+//! use the `*_at` variants here instead of reading the clock at every call
+//! site; they read the timer once and forward to the plain methods.
+
+use mctp::Eid;
+use openprot_hal_blocking::timer::MonotonicTimer;
+use openprot_mctp_api::{Handle, MctpError};
+
+use crate::{RecvResult, Sender, Server};
+
+impl<S: Sender, const OUTSTANDING: usize, const LISTENERS: usize, const REQUESTS: usize>
+    Server<S, OUTSTANDING, LISTENERS, REQUESTS>
+{
+    /// Create a new server, starting its clock at `timer`'s current time.
+    pub fn new_at(own_eid: Eid, timer: &impl MonotonicTimer, outbound: S) -> Self {
+        Self::new(own_eid, timer.now_millis(), outbound)
+    }
+
+    /// [`register_recv`](Self::register_recv) with the deadline measured
+    /// from `timer`'s current time.
+    pub fn register_recv_at(
+        &mut self,
+        handle: Handle,
+        timeout_millis: u32,
+        timer: &impl MonotonicTimer,
+    ) -> Result<(), MctpError> {
+        self.register_recv(handle, timeout_millis, timer.now_millis())
+    }
+
+    /// [`update`](Self::update) at `timer`'s current time.
+    pub fn update_at(
+        &mut self,
+        timer: &impl MonotonicTimer,
+        recv_buf: &mut [u8],
+    ) -> (u32, heapless::Vec<(Handle, RecvResult), OUTSTANDING>) {
+        self.update(timer.now_millis(), recv_buf)
+    }
+}
@@ -0,0 +1,126 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bootstrap mode tests — feed raw packets to an endpoint awaiting its EID,
+//! assign one with Set Endpoint ID and check that destination matching
+//! turns strict.
+
+mod common;
+
+use mctp::Eid;
+use openprot_mctp_server::control::{command, completion, MCTP_CTRL_MSG_TYPE};
+use openprot_mctp_server::{DropReason, InboundDisposition, Server};
+
+use common::DroppingBufferSender;
+
+const VENDOR_MSG_TYPE: u8 = 0x7E;
+
+/// A single-packet message from EID 0x2A (SOM, EOM, TO, tag 0).
+fn packet(dest: u8, typ: u8, body: &[u8]) -> Vec<u8> {
+    let mut pkt = vec![0x01, dest, 0x2A, 0xC8, typ];
+    pkt.extend_from_slice(body);
+    pkt
+}
+
+/// Before assignment only control traffic gets through; Set Endpoint ID
+/// ends bootstrap mode.
+#[test]
+fn passes_control_traffic_before_assignment() {
+    let mut server: Server<_, 16> = Server::new(Eid(0), 0, DroppingBufferSender);
+    server.set_bootstrap(true);
+    assert!(server.in_bootstrap());
+    let control = server.listener(MCTP_CTRL_MSG_TYPE).unwrap();
+    let vendor = server.listener(VENDOR_MSG_TYPE).unwrap();
+
+    let vendor_pkt = packet(0x00, VENDOR_MSG_TYPE, &[0xAB]);
+    assert_eq!(server.drop_reason(&vendor_pkt), Some(DropReason::Bootstrap));
+    server.inbound(&vendor_pkt).unwrap();
+    let mut buf = [0u8; 64];
+    assert!(server.try_recv(vendor, &mut buf).is_none());
+
+    let set_eid = packet(
+        0x00,
+        MCTP_CTRL_MSG_TYPE,
+        &[0x81, command::SET_ENDPOINT_ID, 0x00, 0x1D],
+    );
+    assert_eq!(server.drop_reason(&set_eid), None);
+    server.inbound(&set_eid).unwrap();
+    let meta = server.try_recv(control, &mut buf).unwrap();
+
+    let mut resp = [0u8; 64];
+    let len = server
+        .handle_control(&buf[..meta.payload_size], &mut resp)
+        .unwrap();
+    assert_eq!(resp[2], completion::SUCCESS);
+    assert!(len > 3);
+    assert_eq!(server.get_eid(), 0x1D);
+    assert!(!server.in_bootstrap());
+}
+
+/// Once an EID is assigned, the null EID is dropped and only the assigned
+/// and broadcast EIDs get through.
+#[test]
+fn strict_matching_after_assignment() {
+    let mut server: Server<_, 16> = Server::new(Eid(0), 0, DroppingBufferSender);
+    server.set_bootstrap(true);
+    let vendor = server.listener(VENDOR_MSG_TYPE).unwrap();
+    server.set_eid(0x1D).unwrap();
+    assert!(!server.in_bootstrap());
+
+    let to_null = packet(0x00, MCTP_CTRL_MSG_TYPE, &[0x82, command::GET_ENDPOINT_ID]);
+    assert_eq!(server.drop_reason(&to_null), Some(DropReason::ForeignEid));
+    server.inbound(&to_null).unwrap();
+
+    server
+        .inbound(&packet(0x1D, VENDOR_MSG_TYPE, &[0xCD]))
+        .unwrap();
+    let mut buf = [0u8; 64];
+    let meta = server.try_recv(vendor, &mut buf).unwrap();
+    assert_eq!(buf[..meta.payload_size], [0xCD]);
+    assert_eq!(server.stats().inbound_errors, 0);
+}
+
+/// In bootstrap mode the null EID only gets through for control messages,
+/// and other EIDs are dropped even for those.
+#[test]
+fn null_eid_passes_only_control_in_bootstrap() {
+    let mut server: Server<_, 16> = Server::new(Eid(0), 0, DroppingBufferSender);
+    server.set_bootstrap(true);
+    let control = server.listener(MCTP_CTRL_MSG_TYPE).unwrap();
+    let vendor = server.listener(VENDOR_MSG_TYPE).unwrap();
+
+    let get_eid = packet(0x00, MCTP_CTRL_MSG_TYPE, &[0x82, command::GET_ENDPOINT_ID]);
+    assert_eq!(server.inbound(&get_eid), Ok(InboundDisposition::Delivered));
+    let mut buf = [0u8; 64];
+    let meta = server.try_recv(control, &mut buf).unwrap();
+    assert_eq!(buf[..meta.payload_size], [0x82, command::GET_ENDPOINT_ID]);
+
+    assert_eq!(
+        server.inbound(&packet(0x00, VENDOR_MSG_TYPE, &[0xAB])),
+        Ok(InboundDisposition::Dropped(DropReason::Bootstrap))
+    );
+    assert!(server.try_recv(vendor, &mut buf).is_none());
+
+    let foreign = packet(0x33, MCTP_CTRL_MSG_TYPE, &[0x82, command::GET_ENDPOINT_ID]);
+    assert_eq!(
+        server.inbound(&foreign),
+        Ok(InboundDisposition::Dropped(DropReason::ForeignEid))
+    );
+    assert!(server.try_recv(control, &mut buf).is_none());
+    assert!(server.in_bootstrap());
+}
+
+/// Assigning the null EID does not leave bootstrap mode, and leaving it
+/// explicitly restores the router's matching.
+#[test]
+fn null_assignment_stays_in_bootstrap() {
+    let mut server: Server<_, 16> = Server::new(Eid(0), 0, DroppingBufferSender);
+    server.set_bootstrap(true);
+    server.set_eid(0).unwrap();
+    assert!(server.in_bootstrap());
+
+    server.set_bootstrap(false);
+    assert!(!server.in_bootstrap());
+    let vendor_pkt = packet(0x00, VENDOR_MSG_TYPE, &[0xAB]);
+    assert_eq!(server.drop_reason(&vendor_pkt), None);
+}
@@ -0,0 +1,154 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! MCTP control protocol tests — encode each supported control command,
+//! pass it to `Server::handle_control` and assert the exact response bytes.
+
+mod common;
+
+use mctp::Eid;
+use openprot_mctp_server::control::{command, completion};
+use openprot_mctp_server::{EidPool, Server};
+
+use common::DroppingBufferSender;
+
+/// Rq bit set, instance ID 0x05.
+const RQ_IID: u8 = 0x85;
+
+fn make_server() -> Server<DroppingBufferSender, 16> {
+    Server::new(Eid(8), 0, DroppingBufferSender)
+}
+
+fn control(server: &mut Server<DroppingBufferSender, 16>, request: &[u8]) -> Vec<u8> {
+    let mut resp = [0u8; 64];
+    let len = server
+        .handle_control(request, &mut resp)
+        .expect("request should produce a response");
+    resp[..len].to_vec()
+}
+
+// ---------------------------------------------------------------------------
+// Endpoint ID
+// ---------------------------------------------------------------------------
+
+/// Get Endpoint ID reports the current EID as a simple dynamic endpoint.
+#[test]
+fn get_endpoint_id() {
+    let mut server = make_server();
+    let resp = control(&mut server, &[RQ_IID, command::GET_ENDPOINT_ID]);
+    assert_eq!(resp, [0x05, 0x02, completion::SUCCESS, 8, 0x00, 0x00]);
+}
+
+/// Set Endpoint ID applies the new EID and acknowledges it.
+#[test]
+fn set_endpoint_id() {
+    let mut server = make_server();
+    let resp = control(&mut server, &[RQ_IID, command::SET_ENDPOINT_ID, 0x00, 0x1D]);
+    assert_eq!(resp, [0x05, 0x01, completion::SUCCESS, 0x00, 0x1D, 0x00]);
+    assert_eq!(server.get_eid(), 0x1D);
+}
+
+/// With an EID pool configured, Set Endpoint ID reports the pool as
+/// allocated and gives its size.
+#[test]
+fn set_endpoint_id_reports_eid_pool() {
+    let mut server = make_server();
+    server.set_eid_pool(EidPool::new(0x20, 0x2F).unwrap());
+    let resp = control(&mut server, &[RQ_IID, command::SET_ENDPOINT_ID, 0x00, 0x1D]);
+    assert_eq!(resp, [0x05, 0x01, completion::SUCCESS, 0x02, 0x1D, 16]);
+}
+
+/// Set Endpoint ID rejects the null and broadcast EIDs without changing state.
+#[test]
+fn set_endpoint_id_reserved_eid_rejected() {
+    let mut server = make_server();
+    for eid in [0x00, 0xFF] {
+        let resp = control(&mut server, &[RQ_IID, command::SET_ENDPOINT_ID, 0x00, eid]);
+        assert_eq!(resp, [0x05, 0x01, completion::ERROR_INVALID_DATA]);
+    }
+    assert_eq!(server.get_eid(), 8);
+}
+
+/// Set Endpoint ID with a truncated body reports an invalid length.
+#[test]
+fn set_endpoint_id_short_request() {
+    let mut server = make_server();
+    let resp = control(&mut server, &[RQ_IID, command::SET_ENDPOINT_ID, 0x00]);
+    assert_eq!(resp, [0x05, 0x01, completion::ERROR_INVALID_LENGTH]);
+    assert_eq!(server.get_eid(), 8);
+}
+
+// ---------------------------------------------------------------------------
+// Version and message type support
+// ---------------------------------------------------------------------------
+
+/// Get MCTP Version Support for the base specification reports 1.3.1.
+#[test]
+fn get_version_support_base_spec() {
+    let mut server = make_server();
+    let resp = control(&mut server, &[RQ_IID, command::GET_VERSION_SUPPORT, 0xFF]);
+    assert_eq!(
+        resp,
+        [0x05, 0x04, completion::SUCCESS, 1, 0xF1, 0xF3, 0xF1, 0x00]
+    );
+}
+
+/// Get MCTP Version Support for an unknown message type reports 0x80.
+#[test]
+fn get_version_support_unknown_type() {
+    let mut server = make_server();
+    let resp = control(&mut server, &[RQ_IID, command::GET_VERSION_SUPPORT, 0x7E]);
+    assert_eq!(resp, [0x05, 0x04, completion::MSG_TYPE_NOT_SUPPORTED]);
+}
+
+/// Get Message Type Support lists bound listener types, excluding control.
+#[test]
+fn get_message_type_support_lists_listeners() {
+    let mut server = make_server();
+    server.listener(0).unwrap();
+    server.listener(1).unwrap();
+    let spdm = server.listener(5).unwrap();
+    server.req(42).unwrap();
+
+    let resp = control(&mut server, &[RQ_IID, command::GET_MESSAGE_TYPE_SUPPORT]);
+    assert_eq!(&resp[..3], [0x05, 0x05, completion::SUCCESS]);
+    assert_eq!(resp[3], 2);
+    let mut types = resp[4..].to_vec();
+    types.sort();
+    assert_eq!(types, [1, 5]);
+
+    server.unbind(spdm).unwrap();
+    let resp = control(&mut server, &[RQ_IID, command::GET_MESSAGE_TYPE_SUPPORT]);
+    assert_eq!(resp, [0x05, 0x05, completion::SUCCESS, 1, 1]);
+}
+
+// ---------------------------------------------------------------------------
+// Malformed input
+// ---------------------------------------------------------------------------
+
+/// An unknown command code is answered with ERROR_UNSUPPORTED_CMD.
+#[test]
+fn unsupported_command() {
+    let mut server = make_server();
+    let resp = control(&mut server, &[RQ_IID, 0x7F]);
+    assert_eq!(resp, [0x05, 0x7F, completion::ERROR_UNSUPPORTED_CMD]);
+}
+
+/// A request missing its command code is answered with an invalid length.
+#[test]
+fn missing_command_code() {
+    let mut server = make_server();
+    let resp = control(&mut server, &[RQ_IID]);
+    assert_eq!(resp, [0x05, 0x00, completion::ERROR_INVALID_LENGTH]);
+}
+
+/// Empty payloads and control responses (Rq clear) are never answered.
+#[test]
+fn responses_and_empty_payloads_ignored() {
+    let mut server = make_server();
+    let mut resp = [0u8; 64];
+    assert!(server.handle_control(&[], &mut resp).is_none());
+    assert!(server
+        .handle_control(&[0x05, command::GET_ENDPOINT_ID, 0x00], &mut resp)
+        .is_none());
+}
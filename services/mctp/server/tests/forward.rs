@@ -0,0 +1,129 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Forwarding tests — bridge a message from one port's server to another's
+//! with `Server::try_recv_borrowed`, counting heap allocations and checking
+//! the forwarded bytes.
+
+mod common;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::{Cell, RefCell};
+
+use mctp::Eid;
+use openprot_mctp_server::{FnSender, Server};
+
+use common::DroppingBufferSender;
+
+/// Counts heap allocations made on each thread.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: forwards to the system allocator, only counting calls.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|n| n.set(n.get() + 1));
+        // SAFETY: same contract as `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` was allocated by `System` with `layout`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+const UPSTREAM_EID: u8 = 8;
+const DOWNSTREAM_EID: u8 = 0x30;
+const VENDOR_MSG_TYPE: u8 = 0x7E;
+
+/// Packets sent on the downstream port, kept in a fixed buffer so that
+/// transmitting allocates nothing.
+struct Wire {
+    bytes: [u8; 512],
+    len: usize,
+}
+
+/// A message received upstream reaches the downstream port without being
+/// copied to an intermediate buffer or allocated.
+#[test]
+fn forward_without_copy() {
+    let mut upstream: Server<_, 16> = Server::new(Eid(UPSTREAM_EID), 0, DroppingBufferSender);
+    let listener = upstream.listener(VENDOR_MSG_TYPE).unwrap();
+
+    let wire = RefCell::new(Wire {
+        bytes: [0; 512],
+        len: 0,
+    });
+    let sender = FnSender::new(255, |pkt: &[u8]| {
+        let mut wire = wire.borrow_mut();
+        let start = wire.len;
+        wire.bytes[start..start + pkt.len()].copy_from_slice(pkt);
+        wire.len += pkt.len();
+        Ok(())
+    });
+    let mut downstream: Server<_, 16> = Server::new(Eid(UPSTREAM_EID), 0, sender);
+    let req = downstream.req(DOWNSTREAM_EID).unwrap();
+
+    // A single-packet message from EID 0x2A (SOM, EOM, TO, tag 0).
+    let body: Vec<u8> = (0u8..100).collect();
+    let mut pkt = vec![0x01, UPSTREAM_EID, 0x2A, 0xC8, VENDOR_MSG_TYPE];
+    pkt.extend_from_slice(&body);
+    upstream.inbound(&pkt).unwrap();
+
+    let before = allocations();
+    let (meta, payload) = upstream.try_recv_borrowed(listener).unwrap();
+    let tag = downstream
+        .send(Some(req), meta.msg_type, None, None, meta.msg_ic, payload)
+        .unwrap();
+    assert_eq!(allocations(), before, "forwarding must not allocate");
+
+    assert_eq!(meta.remote_eid, 0x2A);
+    assert_eq!(meta.payload_size, body.len());
+    let wire = wire.borrow();
+    let sent = &wire.bytes[..wire.len];
+    assert_eq!(
+        sent[..5],
+        [
+            0x01,
+            DOWNSTREAM_EID,
+            UPSTREAM_EID,
+            0xC8 | tag,
+            VENDOR_MSG_TYPE
+        ]
+    );
+    assert_eq!(sent[5..], body[..]);
+    assert_eq!(upstream.stats().messages_delivered, 1);
+}
+
+/// The borrowed view carries the same metadata and payload as `try_recv`.
+#[test]
+fn borrowed_matches_copied() {
+    let mut server: Server<_, 16> = Server::new(Eid(UPSTREAM_EID), 0, DroppingBufferSender);
+    let listener = server.listener(VENDOR_MSG_TYPE).unwrap();
+    let pkt = [0x01, UPSTREAM_EID, 0x2A, 0xC8, VENDOR_MSG_TYPE, 0xAB, 0xCD];
+
+    server.inbound(&pkt).unwrap();
+    let mut buf = [0u8; 16];
+    let copied = server.try_recv(listener, &mut buf).unwrap();
+
+    server.inbound(&pkt).unwrap();
+    let (borrowed, payload) = server.try_recv_borrowed(listener).unwrap();
+    assert_eq!(
+        (borrowed.msg_type, borrowed.msg_tag, borrowed.remote_eid),
+        (copied.msg_type, copied.msg_tag, copied.remote_eid)
+    );
+    assert_eq!(borrowed.payload_size, copied.payload_size);
+    assert_eq!(payload, &buf[..copied.payload_size]);
+    assert!(server.try_recv_borrowed(listener).is_none());
+}
@@ -0,0 +1,139 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Instance-ID tracking tests — send a request with
+//! `Server::send_with_instance_id` and check that responses carrying a
+//! mismatched instance ID are dropped on receive.
+
+mod common;
+
+use std::cell::RefCell;
+
+use mctp::Eid;
+use openprot_mctp_api::ResponseCode;
+use openprot_mctp_server::{InstanceCheck, Server};
+
+use common::{transfer, BufferSender, DroppingBufferSender};
+
+const MSG_TYPE: u8 = 0x7E;
+
+/// A response with a different instance ID is dropped on receive and
+/// leaves the request outstanding; a matching one is then delivered and
+/// ends tracking.
+#[test]
+fn mismatched_instance_dropped_matching_delivered() {
+    let req_buf = RefCell::new(Vec::new());
+    let mut requester: Server<_, 16> = Server::new(Eid(42), 0, BufferSender { packets: &req_buf });
+    let resp_buf = RefCell::new(Vec::new());
+    let mut responder: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &resp_buf });
+    let req = requester.req(8).unwrap();
+    let listener = responder.listener(MSG_TYPE).unwrap();
+
+    let mut msg = [0x80, 0xAA];
+    requester
+        .send_with_instance_id(req, MSG_TYPE, 3, false, &mut msg)
+        .unwrap();
+    assert_eq!(msg[0], 0x83, "instance ID stamped into the header");
+    transfer(&req_buf, &mut responder);
+
+    let mut recv_buf = [0u8; 16];
+    let meta = responder.try_recv(listener, &mut recv_buf).unwrap();
+    assert_eq!(recv_buf[..meta.payload_size], [0x83, 0xAA]);
+
+    // A late response to an earlier request, instance ID 4.
+    responder.respond(&meta, &[0x04, 0x00]).unwrap();
+    transfer(&resp_buf, &mut requester);
+    resp_buf.borrow_mut().clear();
+    assert!(requester.try_recv(req, &mut recv_buf).is_none());
+    assert_eq!(requester.stats().stale_responses, 1);
+    assert_eq!(requester.stats().messages_delivered, 0);
+
+    // The request is still outstanding, so the right ID is delivered.
+    responder.respond(&meta, &[0x03, 0x00]).unwrap();
+    transfer(&resp_buf, &mut requester);
+    let resp = requester.try_recv(req, &mut recv_buf).unwrap();
+    assert_eq!(recv_buf[..resp.payload_size], [0x03, 0x00]);
+    assert_eq!(
+        requester.check_instance_id(req, &[0x03]),
+        InstanceCheck::Untracked
+    );
+}
+
+/// `update` drops a mismatched response to a pending receive and keeps
+/// waiting for the right one.
+#[test]
+fn update_drops_mismatched_instance() {
+    let req_buf = RefCell::new(Vec::new());
+    let mut requester: Server<_, 16> = Server::new(Eid(42), 0, BufferSender { packets: &req_buf });
+    let resp_buf = RefCell::new(Vec::new());
+    let mut responder: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &resp_buf });
+    let req = requester.req(8).unwrap();
+    let listener = responder.listener(MSG_TYPE).unwrap();
+
+    requester
+        .send_with_instance_id(req, MSG_TYPE, 7, false, &mut [0x80])
+        .unwrap();
+    transfer(&req_buf, &mut responder);
+    let mut recv_buf = [0u8; 16];
+    let meta = responder.try_recv(listener, &mut recv_buf).unwrap();
+
+    requester.register_recv(req, 0, 0).unwrap();
+    responder.respond(&meta, &[0x06]).unwrap();
+    transfer(&resp_buf, &mut requester);
+    resp_buf.borrow_mut().clear();
+    let (_, ready) = requester.update(0, &mut recv_buf);
+    assert!(ready.is_empty());
+    assert_eq!(requester.stats().stale_responses, 1);
+
+    responder.respond(&meta, &[0x07]).unwrap();
+    transfer(&resp_buf, &mut requester);
+    let (_, ready) = requester.update(0, &mut recv_buf);
+    assert_eq!(ready.len(), 1);
+    assert_eq!(ready[0].0, req);
+}
+
+/// Handles without a tracked send, and empty responses, are reported as
+/// such.
+#[test]
+fn untracked_and_empty_responses() {
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, DroppingBufferSender);
+    let req = server.req(42).unwrap();
+    assert_eq!(
+        server.check_instance_id(req, &[0x00]),
+        InstanceCheck::Untracked
+    );
+
+    server
+        .send_with_instance_id(req, MSG_TYPE, 0x1F, false, &mut [0x80])
+        .unwrap();
+    assert_eq!(
+        server.check_instance_id(req, &[]),
+        InstanceCheck::Mismatched { expected: 0x1F }
+    );
+
+    // Unbinding the handle drops the expectation.
+    server.unbind(req).unwrap();
+    assert_eq!(
+        server.check_instance_id(req, &[0x1F]),
+        InstanceCheck::Untracked
+    );
+}
+
+/// Listener handles, empty buffers and oversized IDs are rejected.
+#[test]
+fn send_with_instance_id_rejects_bad_arguments() {
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, DroppingBufferSender);
+    let req = server.req(42).unwrap();
+    let listener = server.listener(MSG_TYPE).unwrap();
+
+    for (handle, iid, buf) in [
+        (listener, 0, &mut [0x80][..]),
+        (req, 0x20, &mut [0x80][..]),
+        (req, 0, &mut [][..]),
+    ] {
+        let err = server
+            .send_with_instance_id(handle, MSG_TYPE, iid, false, buf)
+            .expect_err("send should be rejected");
+        assert_eq!(err.code, ResponseCode::BadArgument);
+    }
+}
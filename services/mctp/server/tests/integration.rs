@@ -5,6 +5,7 @@
 //!
 //! Tests in this file exercise:
 //! - Multi-fragment reassembly (small MTU sender)
+//! - Multi-fragment transmit (packet count, SOM/EOM flags)
 //! - Multiple concurrent listeners with no cross-talk
 //! - `MctpListener` + `MctpRespChannel` trait path (mirrors real echo application)
 //! - `MctpReqChannel` trait path
@@ -76,6 +77,41 @@ fn multi_fragment_roundtrip() {
     assert_eq!(meta.remote_eid, 42);
 }
 
+/// A multi-fragment send emits one packet per MTU-sized chunk, marks the
+/// first and last packets with SOM/EOM, and returns the assigned tag.
+#[test]
+fn multi_fragment_send_packet_count() {
+    const MTU: usize = 64;
+    const MCTP_HEADER_SIZE: usize = 4;
+
+    let buf = RefCell::new(Vec::new());
+    let sender = SmallMtuBufferSender {
+        packets: &buf,
+        mtu: MTU,
+    };
+    let mut server: Server<_, 16> = Server::new(Eid(42), 0, sender);
+    let req = server.req(8).unwrap();
+
+    // 150 payload bytes + 1 message type byte = 151 bytes -> 3 packets.
+    let payload: Vec<u8> = (0u8..150).collect();
+    let tag = server
+        .send(Some(req), 1, None, None, false, &payload)
+        .expect("multi-fragment send should complete");
+
+    let packets = buf.borrow();
+    assert_eq!(packets.len(), 3);
+    for pkt in packets.iter() {
+        assert!(pkt.len() <= MTU + MCTP_HEADER_SIZE);
+        // All fragments carry the tag returned by `send`.
+        assert_eq!(pkt[3] & 0x07, tag);
+    }
+    let som = |pkt: &Vec<u8>| pkt[3] & 0x80 != 0;
+    let eom = |pkt: &Vec<u8>| pkt[3] & 0x40 != 0;
+    assert!(som(&packets[0]) && !eom(&packets[0]));
+    assert!(!som(&packets[1]) && !eom(&packets[1]));
+    assert!(!som(&packets[2]) && eom(&packets[2]));
+}
+
 // ---------------------------------------------------------------------------
 // Multiple concurrent listeners — no cross-talk
 // ---------------------------------------------------------------------------
@@ -0,0 +1,123 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! PLDM framing tests — round-trip request and response headers through
+//! `PldmHeader::encode`/`decode`, then carry them over a live server pair.
+
+mod common;
+
+use std::cell::RefCell;
+
+use mctp::Eid;
+use openprot_mctp_server::pldm::{
+    completion, PldmHeader, PLDM_MSG_TYPE, PLDM_REQUEST_HEADER_SIZE, PLDM_RESPONSE_HEADER_SIZE,
+};
+use openprot_mctp_server::Server;
+
+use common::{transfer, BufferSender};
+
+/// PLDM type 0 (messaging control and discovery), GetTID command.
+const PLDM_BASE: u8 = 0x00;
+const GET_TID: u8 = 0x02;
+
+/// A request header encodes Rq and the instance ID, then decodes back.
+#[test]
+fn request_header_roundtrip() {
+    let header = PldmHeader::request(0x0A, PLDM_BASE, GET_TID);
+    let mut buf = [0u8; 8];
+    let len = header.encode(&mut buf).unwrap();
+    assert_eq!(len, PLDM_REQUEST_HEADER_SIZE);
+    assert_eq!(buf[..len], [0x8A, PLDM_BASE, GET_TID]);
+
+    buf[len] = 0xAB;
+    let (decoded, body) = PldmHeader::decode(&buf[..len + 1]).unwrap();
+    assert_eq!(decoded, header);
+    assert!(decoded.is_request());
+    assert_eq!(body, [0xAB]);
+}
+
+/// A response header keeps the request's fields and adds the completion code.
+#[test]
+fn response_header_roundtrip() {
+    let header = PldmHeader::request(0x1F, 0x05, 0x10).response(completion::ERROR_NOT_READY);
+    let mut buf = [0u8; 8];
+    let len = header.encode(&mut buf).unwrap();
+    assert_eq!(len, PLDM_RESPONSE_HEADER_SIZE);
+    assert_eq!(buf[..len], [0x1F, 0x05, 0x10, completion::ERROR_NOT_READY]);
+
+    let (decoded, body) = PldmHeader::decode(&buf[..len]).unwrap();
+    assert_eq!(decoded, header);
+    assert_eq!(decoded.completion_code, Some(completion::ERROR_NOT_READY));
+    assert!(body.is_empty());
+}
+
+/// Out-of-range fields and short buffers are not encoded.
+#[test]
+fn encode_rejects_invalid_header() {
+    let mut buf = [0u8; 8];
+    assert!(PldmHeader::request(0x20, PLDM_BASE, GET_TID)
+        .encode(&mut buf)
+        .is_none());
+    assert!(PldmHeader::request(0, 0x40, GET_TID)
+        .encode(&mut buf)
+        .is_none());
+    assert!(PldmHeader::request(0, PLDM_BASE, GET_TID)
+        .response(completion::SUCCESS)
+        .encode(&mut buf[..3])
+        .is_none());
+}
+
+/// Truncated payloads, datagrams and unknown header versions are rejected.
+#[test]
+fn decode_rejects_malformed_header() {
+    assert!(PldmHeader::decode(&[]).is_none());
+    assert!(PldmHeader::decode(&[0x80, 0x00]).is_none());
+    // Response without a completion code.
+    assert!(PldmHeader::decode(&[0x00, 0x00, GET_TID]).is_none());
+    // Unacknowledged request.
+    assert!(PldmHeader::decode(&[0xC0, 0x00, GET_TID]).is_none());
+    // Header version 1.
+    assert!(PldmHeader::decode(&[0x80, 0x40, GET_TID]).is_none());
+}
+
+/// A responder decodes a request received on a PLDM listener and answers
+/// with a matching response header via `Server::respond`.
+#[test]
+fn pldm_request_response_over_server() {
+    let resp_buf = RefCell::new(Vec::new());
+    let mut responder: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &resp_buf });
+    let listener = responder.listener(PLDM_MSG_TYPE).unwrap();
+
+    let req_buf = RefCell::new(Vec::new());
+    let mut requester: Server<_, 16> = Server::new(Eid(42), 0, BufferSender { packets: &req_buf });
+    let req = requester.req(8).unwrap();
+
+    let mut msg = [0u8; 64];
+    let len = PldmHeader::request(3, PLDM_BASE, GET_TID)
+        .encode(&mut msg)
+        .unwrap();
+    requester
+        .send(Some(req), PLDM_MSG_TYPE, None, None, false, &msg[..len])
+        .unwrap();
+    transfer(&req_buf, &mut responder);
+
+    let meta = responder.try_recv(listener, &mut msg).unwrap();
+    let (header, body) = PldmHeader::decode(&msg[..meta.payload_size]).unwrap();
+    assert_eq!(header, PldmHeader::request(3, PLDM_BASE, GET_TID));
+    assert!(body.is_empty());
+
+    let mut reply = [0u8; 64];
+    let len = header
+        .response(completion::SUCCESS)
+        .encode(&mut reply)
+        .unwrap();
+    reply[len] = 0x01; // TID
+    responder.respond(&meta, &reply[..len + 1]).unwrap();
+    transfer(&resp_buf, &mut requester);
+
+    let meta = requester.try_recv(req, &mut msg).unwrap();
+    let (header, body) = PldmHeader::decode(&msg[..meta.payload_size]).unwrap();
+    assert_eq!(header.instance_id, 3);
+    assert_eq!(header.completion_code, Some(completion::SUCCESS));
+    assert_eq!(body, [0x01]);
+}
@@ -0,0 +1,123 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Retry tests — let a request's pending receive time out in `update`,
+//! re-send it with `Server::retry` and check the retry budget.
+
+mod common;
+
+use std::cell::RefCell;
+
+use mctp::Eid;
+use openprot_mctp_api::{Handle, ResponseCode};
+use openprot_mctp_server::{RecvResult, Server, ServerConfig};
+
+use common::{transfer, BufferSender, DroppingBufferSender};
+
+const REQUESTER_EID: u8 = 42;
+const RESPONDER_EID: u8 = 8;
+const MSG_TYPE: u8 = 0x01;
+const TIMEOUT_MS: u32 = 100;
+
+/// Wait for a response on `req` and let the wait time out.
+fn time_out<S: mctp_lib::Sender>(server: &mut Server<S, 16>, req: Handle, now: &mut u64) {
+    server.register_recv(req, TIMEOUT_MS, *now).unwrap();
+    *now += u64::from(TIMEOUT_MS);
+    let mut buf = [0u8; 64];
+    let (_, ready) = server.update(*now, &mut buf);
+    assert!(matches!(ready.as_slice(), [(h, RecvResult::TimedOut)] if *h == req));
+}
+
+/// A timed-out request is re-sent with its type and payload, and the
+/// responder receives it.
+#[test]
+fn retry_resends_timed_out_request() {
+    let packets = RefCell::new(Vec::new());
+    let mut requester: Server<_, 16> =
+        Server::new(Eid(REQUESTER_EID), 0, BufferSender { packets: &packets });
+    let req = requester.req(RESPONDER_EID).unwrap();
+    let mut now = 0;
+
+    requester
+        .send(Some(req), MSG_TYPE, None, None, false, b"ping")
+        .unwrap();
+    assert_eq!(
+        requester.retry(req, b"ping").unwrap_err().code,
+        ResponseCode::BadArgument,
+        "a request still awaiting its response is not retryable"
+    );
+    time_out(&mut requester, req, &mut now);
+    packets.borrow_mut().clear();
+
+    requester.retry(req, b"ping").unwrap();
+    assert_eq!(requester.retry_count(req), 1);
+    assert!(requester.request_busy(req));
+    assert_eq!(requester.stats().request_retries, 1);
+
+    let mut responder: Server<_, 16> = Server::new(Eid(RESPONDER_EID), 0, DroppingBufferSender);
+    let listener = responder.listener(MSG_TYPE).unwrap();
+    transfer(&packets, &mut responder);
+    let mut buf = [0u8; 64];
+    let meta = responder.try_recv(listener, &mut buf).unwrap();
+    assert_eq!(meta.remote_eid, REQUESTER_EID);
+    assert_eq!(&buf[..meta.payload_size], b"ping");
+}
+
+/// Retries stop with `TimedOut` once the budget is spent, and a new send
+/// starts the count over.
+#[test]
+fn retry_budget_exhausted() {
+    let mut server: Server<_, 16> = Server::new(Eid(REQUESTER_EID), 0, DroppingBufferSender);
+    server.set_max_retries(2);
+    let req = server.req(RESPONDER_EID).unwrap();
+    let mut now = 0;
+
+    server
+        .send(Some(req), MSG_TYPE, None, None, false, b"ping")
+        .unwrap();
+    for attempt in 1..=2 {
+        time_out(&mut server, req, &mut now);
+        server.retry(req, b"ping").unwrap();
+        assert_eq!(server.retry_count(req), attempt);
+    }
+    time_out(&mut server, req, &mut now);
+    assert_eq!(
+        server.retry(req, b"ping").unwrap_err().code,
+        ResponseCode::TimedOut
+    );
+    assert_eq!(server.stats().request_retries, 2);
+
+    server
+        .send(Some(req), MSG_TYPE, None, None, false, b"ping")
+        .unwrap();
+    assert_eq!(server.retry_count(req), 0);
+    time_out(&mut server, req, &mut now);
+    server.retry(req, b"ping").unwrap();
+}
+
+/// Listeners, unbound handles and requests never sent cannot be retried.
+#[test]
+fn retry_needs_a_sent_request() {
+    let mut server: Server<_, 16> = Server::new(Eid(REQUESTER_EID), 0, DroppingBufferSender);
+    assert_eq!(ServerConfig::MAX_RETRIES, 3);
+    let listener = server.listener(MSG_TYPE).unwrap();
+    let req = server.req(RESPONDER_EID).unwrap();
+    let mut now = 0;
+
+    for handle in [listener, req] {
+        assert_eq!(
+            server.retry(handle, b"ping").unwrap_err().code,
+            ResponseCode::BadArgument
+        );
+    }
+
+    server
+        .send(Some(req), MSG_TYPE, None, None, false, b"ping")
+        .unwrap();
+    time_out(&mut server, req, &mut now);
+    server.unbind(req).unwrap();
+    assert_eq!(
+        server.retry(req, b"ping").unwrap_err().code,
+        ResponseCode::BadArgument
+    );
+}
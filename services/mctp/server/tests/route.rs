@@ -0,0 +1,129 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bridge routing tests — configure routes with `Server::add_route`, feed
+//! raw packets through `Server::inbound_bridged` and check which port, if
+//! any, they are transmitted on.
+
+mod common;
+
+use std::cell::RefCell;
+
+use mctp::Eid;
+use openprot_mctp_api::ResponseCode;
+use openprot_mctp_server::{DropReason, InboundDisposition, PortId, Server, ServerConfig};
+
+use common::DroppingBufferSender;
+
+const BRIDGE_EID: u8 = 8;
+const VENDOR_MSG_TYPE: u8 = 0x7E;
+
+/// A single-packet message from EID 0x2A (SOM, EOM, TO, tag 0).
+fn packet(dest: u8, body: &[u8]) -> Vec<u8> {
+    let mut pkt = vec![0x01, dest, 0x2A, 0xC8, VENDOR_MSG_TYPE];
+    pkt.extend_from_slice(body);
+    pkt
+}
+
+fn bridge() -> Server<DroppingBufferSender, 16> {
+    let mut server = Server::new(Eid(BRIDGE_EID), 0, DroppingBufferSender);
+    server.add_route(0x30..=0x3F, PortId(1)).unwrap();
+    server.add_route(0x40..=0x4F, PortId(2)).unwrap();
+    server
+}
+
+/// A packet for an EID behind another port is transmitted unchanged on
+/// that port and not delivered locally.
+#[test]
+fn routed_packet_is_forwarded_on_its_port() {
+    let mut server = bridge();
+    let listener = server.listener(VENDOR_MSG_TYPE).unwrap();
+    let sent = RefCell::new(Vec::new());
+    let mut ports = |port: PortId, pkt: &[u8]| {
+        sent.borrow_mut().push((port, pkt.to_vec()));
+        Ok(())
+    };
+
+    let pkt = packet(0x42, &[0xAB, 0xCD]);
+    assert_eq!(server.route(0x42), Some(PortId(2)));
+    assert_eq!(
+        server.inbound_bridged(&pkt, &mut ports),
+        Ok(InboundDisposition::Forwarded(PortId(2)))
+    );
+
+    assert_eq!(*sent.borrow(), [(PortId(2), pkt)]);
+    let mut buf = [0u8; 64];
+    assert!(server.try_recv(listener, &mut buf).is_none());
+    assert_eq!(server.stats().packets_forwarded, 1);
+    assert_eq!(server.stats().packets_received, 1);
+}
+
+/// A packet for an EID no route covers is still dropped, and one for the
+/// bridge itself is delivered rather than forwarded.
+#[test]
+fn unrouted_and_local_packets_are_not_forwarded() {
+    let mut server = bridge();
+    let listener = server.listener(VENDOR_MSG_TYPE).unwrap();
+    let sent = RefCell::new(Vec::new());
+    let mut ports = |port: PortId, pkt: &[u8]| {
+        sent.borrow_mut().push((port, pkt.to_vec()));
+        Ok(())
+    };
+
+    let foreign = packet(0x50, &[0x01]);
+    assert_eq!(server.route(0x50), None);
+    assert_eq!(server.drop_reason(&foreign), Some(DropReason::ForeignEid));
+    assert_eq!(
+        server.inbound_bridged(&foreign, &mut ports),
+        Ok(InboundDisposition::Dropped(DropReason::ForeignEid))
+    );
+    assert_eq!(
+        server.inbound_bridged(&packet(BRIDGE_EID, &[0x02]), &mut ports),
+        Ok(InboundDisposition::Delivered)
+    );
+
+    assert!(sent.borrow().is_empty());
+    let mut buf = [0u8; 64];
+    let meta = server.try_recv(listener, &mut buf).unwrap();
+    assert_eq!(buf[..meta.payload_size], [0x02]);
+    assert!(server.try_recv(listener, &mut buf).is_none());
+    assert_eq!(server.stats().packets_forwarded, 0);
+}
+
+/// A transmit failure on the outbound port is returned to the caller.
+#[test]
+fn forward_error_propagates() {
+    let mut server = bridge();
+    let mut ports = |_: PortId, _: &[u8]| Err(mctp::Error::TxFailure);
+
+    let err = server
+        .inbound_bridged(&packet(0x31, &[0x00]), &mut ports)
+        .expect_err("transmit failure should surface");
+    assert_eq!(err.code, ResponseCode::InternalError);
+}
+
+/// Routes must be non-empty, avoid the null and broadcast EIDs and not
+/// overlap; the table holds `MAX_ROUTES` entries.
+#[test]
+fn add_route_validates_ranges() {
+    let mut server = bridge();
+    #[allow(clippy::reversed_empty_ranges)]
+    let invalid = [
+        0x20..=0x10,
+        0x00..=0x10,
+        0xF0..=0xFF,
+        0x3F..=0x40,
+        0x38..=0x39,
+    ];
+    for eids in invalid {
+        let err = server.add_route(eids, PortId(3)).unwrap_err();
+        assert_eq!(err.code, ResponseCode::BadArgument);
+    }
+
+    for i in 2..ServerConfig::MAX_ROUTES as u8 {
+        let first = 0x50 + i * 0x10;
+        server.add_route(first..=first + 0x0F, PortId(i)).unwrap();
+    }
+    let err = server.add_route(0xE0..=0xEF, PortId(9)).unwrap_err();
+    assert_eq!(err.code, ResponseCode::NoSpace);
+}
@@ -10,10 +10,18 @@
 mod common;
 
 use std::cell::RefCell;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
 
 use mctp::Eid;
-use openprot_mctp_api::ResponseCode;
-use openprot_mctp_server::{RecvResult, Server, ServerConfig};
+use openprot_mctp_api::{Handle, ResponseCode};
+use openprot_mctp_server::{
+    DropReason, EidPool, FnSender, HandleKind, InboundDisposition, RecvResult, SendItem, Sender,
+    Server, ServerConfig, ServerStats, FN_SENDER_MAX_MTU, FN_SENDER_MIN_MTU,
+};
 
 use common::{transfer, BufferSender, DroppingBufferSender};
 
@@ -67,6 +75,60 @@ fn eid_set_get_roundtrip() {
     assert_eq!(server.get_eid(), 42);
 }
 
+/// `EidPool::new` rejects empty ranges and reserved or broadcast EIDs.
+#[test]
+fn eid_pool_rejects_invalid_range() {
+    for (first, last) in [(0x20, 0x10), (0x00, 0x10), (0x07, 0x10), (0x20, 0xFF)] {
+        let err = EidPool::new(first, last).expect_err("range should be rejected");
+        assert_eq!(err.code, ResponseCode::BadArgument);
+    }
+}
+
+/// `assign_eid` hands out unique EIDs, skipping the server's own, and
+/// returns `NoSpace` once the pool is exhausted.
+#[test]
+fn assign_eid_until_exhausted() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(0x11), 0, sender);
+    server.set_eid_pool(EidPool::new(0x10, 0x13).unwrap());
+
+    let assigned: Vec<u8> = (0..3).map(|_| server.assign_eid().unwrap()).collect();
+    assert_eq!(assigned, [0x10, 0x12, 0x13]);
+
+    let err = server.assign_eid().expect_err("pool should be exhausted");
+    assert_eq!(err.code, ResponseCode::NoSpace);
+}
+
+/// A released EID is handed out again; releasing it twice is rejected.
+#[test]
+fn release_eid_allows_reuse() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    server.set_eid_pool(EidPool::new(0x20, 0x21).unwrap());
+
+    let a = server.assign_eid().unwrap();
+    let b = server.assign_eid().unwrap();
+    assert!(server.assign_eid().is_err());
+
+    server.release_eid(a).expect("release should succeed");
+    let err = server
+        .release_eid(a)
+        .expect_err("double release should fail");
+    assert_eq!(err.code, ResponseCode::BadArgument);
+
+    assert_eq!(server.assign_eid().unwrap(), a);
+    assert_ne!(a, b);
+}
+
+/// Without a configured pool, `assign_eid` returns `NoSpace`.
+#[test]
+fn assign_eid_without_pool_returns_no_space() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let err = server.assign_eid().expect_err("no pool configured");
+    assert_eq!(err.code, ResponseCode::NoSpace);
+}
+
 // ---------------------------------------------------------------------------
 // Handle allocation / deallocation
 // ---------------------------------------------------------------------------
@@ -80,6 +142,28 @@ fn req_handle_alloc_and_unbind() {
     server.unbind(handle).expect("unbind should succeed");
 }
 
+/// With coalescing enabled, `req` to the same EID returns the same handle
+/// until it is unbound.
+#[test]
+fn req_coalescing_shares_handle() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let first = server.req(42).unwrap();
+    assert_ne!(
+        server.req(42).unwrap(),
+        first,
+        "coalescing is off by default"
+    );
+
+    server.set_request_coalescing(true);
+    assert_eq!(server.req(42).unwrap(), first);
+    assert_ne!(server.req(43).unwrap(), first);
+
+    server.unbind(first).unwrap();
+    let next = server.req(42).unwrap();
+    assert_eq!(server.handle_kind(next), Some(HandleKind::Request(42)));
+}
+
 /// `listener()` succeeds and `unbind()` releases the handle cleanly.
 #[test]
 fn listener_handle_alloc_and_unbind() {
@@ -111,6 +195,151 @@ fn listener_different_types_both_succeed() {
     assert_ne!(h1, h2);
 }
 
+/// `handle_kind` reports bound listeners and requests with their targets.
+#[test]
+fn handle_kind_for_bound_handles() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let listener = server.listener(5).unwrap();
+    let req = server.req(42).unwrap();
+
+    assert_eq!(server.handle_kind(listener), Some(HandleKind::Listener(5)));
+    assert_eq!(server.handle_kind(req), Some(HandleKind::Request(42)));
+}
+
+/// `handle_kind` returns `None` for unbound and never-issued handles.
+#[test]
+fn handle_kind_for_unbound_handles() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let listener = server.listener(5).unwrap();
+    server.unbind(listener).unwrap();
+
+    assert_eq!(server.handle_kind(listener), None);
+    assert_eq!(server.handle_kind(Handle(u32::MAX)), None);
+}
+
+/// Unbinding a handle discards its queued message, and `try_recv` on the
+/// stale handle returns `None`.
+#[test]
+fn unbind_discards_queued_message() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let listener = server.listener(1).unwrap();
+    deliver_to(42, 8, 1, b"stale", &mut server);
+
+    server.unbind(listener).unwrap();
+    let mut buf = [0u8; 255];
+    assert!(server.try_recv(listener, &mut buf).is_none());
+}
+
+/// A listener bound into a freed slot does not receive the previous
+/// holder's queued message.
+#[test]
+fn rebound_slot_does_not_deliver_old_message() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let old = server.listener(1).unwrap();
+    deliver_to(42, 8, 1, b"stale", &mut server);
+    server.unbind(old).unwrap();
+
+    let new = server.listener(1).unwrap();
+    let mut buf = [0u8; 255];
+    assert!(server.try_recv(new, &mut buf).is_none());
+
+    deliver_to(42, 8, 1, b"fresh", &mut server);
+    let meta = server.try_recv(new, &mut buf).unwrap();
+    assert_eq!(&buf[..meta.payload_size], b"fresh");
+}
+
+/// `active_listeners` and `active_requests` list exactly the bound handles.
+#[test]
+fn active_handles_enumerate_bindings() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let l1 = server.listener(1).unwrap();
+    let l2 = server.listener(2).unwrap();
+    let req = server.req(42).unwrap();
+
+    let mut listeners: Vec<_> = server.active_listeners().collect();
+    listeners.sort_by_key(|&(_, typ)| typ);
+    assert_eq!(listeners, [(l1, 1), (l2, 2)]);
+    assert_eq!(server.active_requests().collect::<Vec<_>>(), [(req, 42)]);
+
+    server.unbind(l1).unwrap();
+    assert_eq!(server.active_listeners().collect::<Vec<_>>(), [(l2, 2)]);
+}
+
+/// `reset` invalidates every issued handle, keeps the EID, and lets new
+/// allocations reuse the freed slots from the start.
+#[test]
+fn reset_frees_all_handles() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let first_listener = server.listener(1).unwrap();
+    server.listener(2).unwrap();
+    let first_req = server.req(42).unwrap();
+    server.register_recv(first_listener, 100, 0).unwrap();
+
+    server.reset();
+    assert_eq!(server.get_eid(), 8);
+
+    // Messages no longer reach the old listener and the pending recv is gone.
+    deliver_to(42, 8, 1, b"stale", &mut server);
+    let mut buf = [0u8; 255];
+    assert!(server.try_recv(first_listener, &mut buf).is_none());
+    let (_, ready) = server.update(200, &mut buf);
+    assert!(ready.is_empty());
+
+    // The duplicate-type check no longer sees the old listener, and
+    // allocation restarts from the first slot.
+    assert_eq!(server.listener(2).unwrap(), first_listener);
+    assert_eq!(server.req(42).unwrap(), first_req);
+}
+
+// ---------------------------------------------------------------------------
+// Table sizes
+// ---------------------------------------------------------------------------
+
+/// A server with two listener slots rejects a third listener with `NoSpace`.
+#[test]
+fn small_listener_table_exhausts() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 4, 2, 2> = Server::new(Eid(8), 0, sender);
+    server.listener(1).unwrap();
+    let second = server.listener(2).unwrap();
+
+    let err = server.listener(3).expect_err("third listener should fail");
+    assert_eq!(err.code, ResponseCode::NoSpace);
+
+    server.unbind(second).unwrap();
+    server.listener(3).expect("freed slot should be reusable");
+}
+
+/// A server with two request slots rejects a third request with `NoSpace`,
+/// and its request and listener handles stay distinguishable.
+#[test]
+fn small_request_table_exhausts() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 4, 2, 2> = Server::new(Eid(8), 0, sender);
+    let l1 = server.listener(1).unwrap();
+    let l2 = server.listener(2).unwrap();
+    let r1 = server.req(42).unwrap();
+    let r2 = server.req(43).unwrap();
+
+    let err = server.req(44).expect_err("third request should fail");
+    assert_eq!(err.code, ResponseCode::NoSpace);
+
+    for h in [l1, l2] {
+        assert!(matches!(
+            server.handle_kind(h),
+            Some(HandleKind::Listener(_))
+        ));
+    }
+    assert_eq!(server.handle_kind(r1), Some(HandleKind::Request(42)));
+    assert_eq!(server.handle_kind(r2), Some(HandleKind::Request(43)));
+}
+
 // ---------------------------------------------------------------------------
 // try_recv before inbound
 // ---------------------------------------------------------------------------
@@ -152,6 +381,170 @@ fn inbound_then_try_recv_delivers_message() {
     assert_eq!(&recv_buf[..meta.payload_size], payload);
 }
 
+/// The metadata returned by `try_recv` carries the source EID, tag, type
+/// and IC bit of the message fed via `inbound`.
+#[test]
+fn try_recv_metadata_matches_delivered_message() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let listener = server.listener(5).unwrap();
+
+    let buf = RefCell::new(Vec::new());
+    let mut peer: Server<BufferSender<'_>, 16> =
+        Server::new(Eid(42), 0, BufferSender { packets: &buf });
+    let req = peer.req(8).unwrap();
+    let sent_tag = peer.send(Some(req), 5, None, None, true, b"ic").unwrap();
+    transfer(&buf, &mut server);
+
+    let mut recv_buf = [0u8; 255];
+    let meta = server
+        .try_recv(listener, &mut recv_buf)
+        .expect("message should be available after inbound");
+    assert_eq!(meta.remote_eid, 42);
+    assert_eq!(meta.msg_tag, sent_tag);
+    assert_eq!(meta.msg_type, 5);
+    assert!(meta.msg_ic);
+    assert_eq!(&recv_buf[..meta.payload_size], b"ic");
+}
+
+/// `respond` answers a request with the requester's tag, so the response
+/// is delivered on the request handle that sent it.
+#[test]
+fn respond_correlates_tag() {
+    let resp_buf = RefCell::new(Vec::new());
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &resp_buf });
+    let listener = server.listener(5).unwrap();
+
+    let req_buf = RefCell::new(Vec::new());
+    let mut peer: Server<BufferSender<'_>, 16> =
+        Server::new(Eid(42), 0, BufferSender { packets: &req_buf });
+    let req = peer.req(8).unwrap();
+    let sent_tag = peer.send(Some(req), 5, None, None, false, b"ping").unwrap();
+    transfer(&req_buf, &mut server);
+
+    let mut recv_buf = [0u8; 255];
+    let meta = server.try_recv(listener, &mut recv_buf).unwrap();
+    let resp_tag = server.respond(&meta, b"pong").unwrap();
+    assert_eq!(resp_tag, sent_tag);
+    transfer(&resp_buf, &mut peer);
+
+    let resp = peer
+        .try_recv(req, &mut recv_buf)
+        .expect("response should arrive on the request handle");
+    assert_eq!(resp.remote_eid, 8);
+    assert_eq!(resp.msg_tag, sent_tag);
+    assert_eq!(&recv_buf[..resp.payload_size], b"pong");
+}
+
+/// A request goes out with the tag owner bit set and its response with the
+/// bit clear, both carrying the same tag value.
+#[test]
+fn response_clears_tag_owner_bit() {
+    let resp_buf = RefCell::new(Vec::new());
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &resp_buf });
+    let listener = server.listener(5).unwrap();
+
+    let req_buf = RefCell::new(Vec::new());
+    let mut peer: Server<BufferSender<'_>, 16> =
+        Server::new(Eid(42), 0, BufferSender { packets: &req_buf });
+    let req = peer.req(8).unwrap();
+    let sent_tag = peer.send(Some(req), 5, None, None, false, b"ping").unwrap();
+    let flags = req_buf.borrow()[0][3];
+    assert_eq!(flags & 0x08, 0x08, "request must own its tag");
+    assert_eq!(flags & 0x07, sent_tag);
+    transfer(&req_buf, &mut server);
+
+    let mut recv_buf = [0u8; 255];
+    let meta = server.try_recv(listener, &mut recv_buf).unwrap();
+    server.respond(&meta, b"pong").unwrap();
+    let flags = resp_buf.borrow()[0][3];
+    assert_eq!(flags & 0x08, 0, "response must not own its tag");
+    assert_eq!(flags & 0x07, sent_tag);
+}
+
+/// A request sent under an explicit tag still owns it.
+#[test]
+fn request_with_explicit_tag_owns_it() {
+    let packets = RefCell::new(Vec::new());
+    let mut server: Server<_, 16> = Server::new(Eid(42), 0, BufferSender { packets: &packets });
+    let req = server.req(8).unwrap();
+
+    let tag = server
+        .send(Some(req), 5, None, Some(3), false, b"ping")
+        .unwrap();
+    assert_eq!(tag, 3);
+    assert_eq!(packets.borrow()[0][3] & 0x0F, 0x08 | 3);
+}
+
+/// A response without the requester's EID and tag is rejected and nothing
+/// is sent.
+#[test]
+fn response_without_tag_or_eid_rejected() {
+    let packets = RefCell::new(Vec::new());
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &packets });
+
+    for (eid, tag) in [(Some(42), None), (None, Some(1)), (None, None)] {
+        let err = server.send(None, 5, eid, tag, false, b"pong").unwrap_err();
+        assert_eq!(err.code, ResponseCode::BadArgument);
+    }
+    assert!(packets.borrow().is_empty());
+    assert_eq!(server.stats().messages_sent, 0);
+}
+
+/// A request handle is busy from a successful send until its response is
+/// received.
+#[test]
+fn request_busy_until_response() {
+    let resp_buf = RefCell::new(Vec::new());
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &resp_buf });
+    let listener = server.listener(5).unwrap();
+
+    let req_buf = RefCell::new(Vec::new());
+    let mut peer: Server<BufferSender<'_>, 16> =
+        Server::new(Eid(42), 0, BufferSender { packets: &req_buf });
+    let req = peer.req(8).unwrap();
+    assert!(!peer.request_busy(req));
+
+    peer.send(Some(req), 5, None, None, false, b"ping").unwrap();
+    assert!(peer.request_busy(req));
+    transfer(&req_buf, &mut server);
+
+    let mut recv_buf = [0u8; 255];
+    let meta = server.try_recv(listener, &mut recv_buf).unwrap();
+    server.respond(&meta, b"pong").unwrap();
+    transfer(&resp_buf, &mut peer);
+
+    peer.try_recv(req, &mut recv_buf).unwrap();
+    assert!(!peer.request_busy(req));
+}
+
+/// With rejection enabled, a second send on a busy request handle fails
+/// with `AddrInUse`; other handles and unbinding are unaffected.
+#[test]
+fn busy_request_send_rejected() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let req = server.req(42).unwrap();
+    let other = server.req(43).unwrap();
+
+    server.send(Some(req), 1, None, None, false, b"a").unwrap();
+    server
+        .send(Some(req), 1, None, None, false, b"b")
+        .expect("busy requests are replaced by default");
+
+    server.set_reject_busy_requests(true);
+    let err = server
+        .send(Some(req), 1, None, None, false, b"c")
+        .expect_err("busy request should be rejected");
+    assert_eq!(err.code, ResponseCode::AddrInUse);
+    server
+        .send(Some(other), 1, None, None, false, b"d")
+        .unwrap();
+
+    server.unbind(req).unwrap();
+    assert!(!server.request_busy(req));
+}
+
 /// A packet for msg_type 2 is not delivered to a listener for msg_type 1.
 #[test]
 fn inbound_wrong_type_not_delivered() {
@@ -165,6 +558,98 @@ fn inbound_wrong_type_not_delivered() {
     assert!(server.try_recv(listener, &mut buf).is_none());
 }
 
+/// `try_recv_any` returns messages waiting on different listeners, each
+/// paired with the handle it arrived on, then `None` once drained.
+#[test]
+fn try_recv_any_returns_each_ready_handle() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let l1 = server.listener(1).unwrap();
+    let l2 = server.listener(2).unwrap();
+
+    deliver_to(42, 8, 1, b"one", &mut server);
+    deliver_to(43, 8, 2, b"two", &mut server);
+
+    let mut buf = [0u8; 255];
+    let mut seen = Vec::new();
+    while let Some((handle, meta)) = server.try_recv_any(&mut buf) {
+        seen.push((handle, buf[..meta.payload_size].to_vec()));
+    }
+    seen.sort_by_key(|(h, _)| h.0);
+
+    let mut expected = vec![(l1, b"one".to_vec()), (l2, b"two".to_vec())];
+    expected.sort_by_key(|(h, _)| h.0);
+    assert_eq!(seen, expected);
+}
+
+/// With messages queued on two listeners, `try_recv_any` alternates between
+/// them instead of draining the first listener before the second.
+#[test]
+fn try_recv_any_round_robin() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let l1 = server.listener(1).unwrap();
+    let l2 = server.listener(2).unwrap();
+
+    for i in 0u8..3 {
+        deliver_to(42, 8, 1, &[i], &mut server);
+        deliver_to(42, 8, 2, &[i], &mut server);
+    }
+
+    let mut buf = [0u8; 255];
+    let order: Vec<_> = core::iter::from_fn(|| server.try_recv_any(&mut buf))
+        .map(|(h, _)| h)
+        .collect();
+    assert_eq!(order, [l1, l2, l1, l2, l1, l2]);
+}
+
+/// `seed_recv_any` selects which bound handle is scanned first.
+#[test]
+fn try_recv_any_seeded_start() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    server.listener(1).unwrap();
+    let l2 = server.listener(2).unwrap();
+
+    deliver_to(42, 8, 1, b"one", &mut server);
+    deliver_to(42, 8, 2, b"two", &mut server);
+
+    server.seed_recv_any(1);
+    let (handle, _) = server.try_recv_any(&mut [0u8; 255]).unwrap();
+    assert_eq!(handle, l2);
+}
+
+/// A seed past the number of bound handles wraps instead of overflowing.
+#[test]
+fn try_recv_any_seed_wraps() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let l1 = server.listener(1).unwrap();
+    let l2 = server.listener(2).unwrap();
+
+    deliver_to(42, 8, 1, b"one", &mut server);
+    deliver_to(42, 8, 2, b"two", &mut server);
+
+    // usize::MAX is odd, so it selects the second handle.
+    server.seed_recv_any(usize::MAX);
+    let mut buf = [0u8; 255];
+    assert_eq!(server.try_recv_any(&mut buf).unwrap().0, l2);
+    assert_eq!(server.try_recv_any(&mut buf).unwrap().0, l1);
+}
+
+/// `try_recv_any` ignores handles that have been unbound.
+#[test]
+fn try_recv_any_skips_unbound_handles() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let listener = server.listener(1).unwrap();
+    assert!(server.try_recv_any(&mut [0u8; 255]).is_none());
+
+    server.unbind(listener).unwrap();
+    deliver_to(42, 8, 1, b"late", &mut server);
+    assert!(server.try_recv_any(&mut [0u8; 255]).is_none());
+}
+
 // ---------------------------------------------------------------------------
 // send with oversized payload
 // ---------------------------------------------------------------------------
@@ -181,6 +666,298 @@ fn send_oversized_payload_returns_no_space() {
         .send(Some(req_handle), 1, None, None, false, &big_payload)
         .expect_err("oversized send should fail");
     assert_eq!(err.code, ResponseCode::NoSpace);
+    assert_eq!(server.stats().fragmentation_errors, 1);
+    assert_eq!(server.stats().messages_sent, 0);
+}
+
+/// `send_batch` sends every item in order.
+#[test]
+fn send_batch_sends_in_order() {
+    let buf = RefCell::new(Vec::new());
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &buf });
+    let req = server.req(42).unwrap();
+    let items = [b"one".as_slice(), b"two", b"three"].map(|payload| SendItem {
+        handle: Some(req),
+        typ: 1,
+        eid: None,
+        tag: None,
+        ic: false,
+        payload,
+    });
+
+    server.send_batch(&items).unwrap();
+
+    let mut receiver: Server<_, 16> = Server::new(Eid(42), 0, DroppingBufferSender);
+    let listener = receiver.listener(1).unwrap();
+    let mut recv_buf = [0u8; 255];
+    let packets = buf.borrow();
+    assert_eq!(packets.len(), items.len());
+    for (pkt, item) in packets.iter().zip(&items) {
+        receiver.inbound(pkt).unwrap();
+        let meta = receiver.try_recv(listener, &mut recv_buf).unwrap();
+        assert_eq!(&recv_buf[..meta.payload_size], item.payload);
+    }
+}
+
+/// `send_batch` stops at the first failing item and reports its index.
+#[test]
+fn send_batch_reports_failed_index() {
+    let buf = RefCell::new(Vec::new());
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, BufferSender { packets: &buf });
+    let req = server.req(42).unwrap();
+    let big_payload = vec![0u8; ServerConfig::MAX_PAYLOAD + 1];
+    let items = [b"one".as_slice(), &big_payload, b"three"].map(|payload| SendItem {
+        handle: Some(req),
+        typ: 1,
+        eid: None,
+        tag: None,
+        ic: false,
+        payload,
+    });
+
+    let (index, err) = server
+        .send_batch(&items)
+        .expect_err("oversized item should fail");
+    assert_eq!(index, 1);
+    assert_eq!(err.code, ResponseCode::NoSpace);
+    assert_eq!(buf.borrow().len(), 1, "only the first item is sent");
+    assert_eq!(server.stats().messages_sent, 1);
+}
+
+// ---------------------------------------------------------------------------
+// Statistics
+// ---------------------------------------------------------------------------
+
+/// Sending, receiving and timing out each update the matching counters.
+#[test]
+fn stats_track_traffic() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    assert_eq!(*server.stats(), ServerStats::default());
+
+    let listener = server.listener(1).unwrap();
+    let req = server.req(42).unwrap();
+    server
+        .send(Some(req), 1, None, None, false, b"out")
+        .unwrap();
+
+    deliver_to(42, 8, 1, b"in", &mut server);
+    let mut buf = [0u8; 255];
+    server.try_recv(listener, &mut buf).unwrap();
+
+    server.register_recv(listener, 100, 0).unwrap();
+    server.update(100, &mut buf);
+
+    let stats = server.stats();
+    assert_eq!(stats.messages_sent, 1);
+    assert_eq!(stats.packets_received, 1);
+    assert_eq!(stats.messages_delivered, 1);
+    assert_eq!(stats.recv_timeouts, 1);
+    assert_eq!(stats.inbound_errors, 0);
+}
+
+/// Zero-length, one-byte, truncated-header and bad-version packets are
+/// rejected with `BadArgument` and counted as inbound errors.
+#[test]
+fn inbound_rejects_malformed_packets() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let listener = server.listener(1).unwrap();
+
+    let malformed: [&[u8]; 5] = [
+        &[],
+        &[0x01],
+        &[0x01, 0x08, 0x2A],
+        // Start of message with no message type byte.
+        &[0x01, 0x08, 0x2A, 0xC8],
+        // Header version 2.
+        &[0x02, 0x08, 0x2A, 0xC8, 0x01],
+    ];
+    for pkt in malformed {
+        let err = server.inbound(pkt).expect_err("packet should be rejected");
+        assert_eq!(err.code, ResponseCode::BadArgument);
+    }
+    assert_eq!(server.stats().packets_received, 5);
+    assert_eq!(server.stats().inbound_errors, 5);
+
+    let mut buf = [0u8; 255];
+    assert!(server.try_recv(listener, &mut buf).is_none());
+
+    // A well-formed single-packet message still gets through.
+    server
+        .inbound(&[0x01, 0x08, 0x2A, 0xC8, 0x01, 0xAB])
+        .unwrap();
+    let meta = server.try_recv(listener, &mut buf).unwrap();
+    assert_eq!(&buf[..meta.payload_size], [0xAB]);
+}
+
+/// `inbound` reports whether a packet reached the router or was dropped,
+/// and why.
+#[test]
+fn inbound_reports_disposition() {
+    let mut server: Server<_, 16> = Server::new(Eid(0), 0, DroppingBufferSender);
+    let listener = server.listener(1).unwrap();
+    server.set_bootstrap(true);
+
+    // Before assignment, only control traffic passes.
+    assert_eq!(
+        server.inbound(&[0x01, 0x00, 0x2A, 0xC8, 0x01, 0xAB]),
+        Ok(InboundDisposition::Dropped(DropReason::Bootstrap))
+    );
+
+    server.set_eid(8).unwrap();
+    assert_eq!(
+        server.inbound(&[0x01, 0x08, 0x2A, 0xC8, 0x01, 0xAB]),
+        Ok(InboundDisposition::Delivered)
+    );
+    assert_eq!(
+        server.inbound(&[0x01, 0x09, 0x2A, 0xC8, 0x01, 0xCD]),
+        Ok(InboundDisposition::Dropped(DropReason::ForeignEid))
+    );
+
+    let mut buf = [0u8; 255];
+    let meta = server.try_recv(listener, &mut buf).unwrap();
+    assert_eq!(&buf[..meta.payload_size], [0xAB]);
+    assert!(server.try_recv(listener, &mut buf).is_none());
+}
+
+// ---------------------------------------------------------------------------
+// FnSender outbound port
+// ---------------------------------------------------------------------------
+
+/// A closure-backed `FnSender` receives every outbound packet, and those
+/// packets reassemble into the original message on the peer.
+#[test]
+fn fn_sender_transmits_every_packet() {
+    let captured = RefCell::new(Vec::new());
+    let sender = FnSender::new(64, |pkt: &[u8]| {
+        captured.borrow_mut().push(pkt.to_vec());
+        Ok(())
+    });
+    let mut server: Server<_, 16> = Server::new(Eid(42), 0, sender);
+    let req = server.req(8).unwrap();
+
+    let payload: Vec<u8> = (0u8..150).collect();
+    server
+        .send(Some(req), 1, None, None, false, &payload)
+        .expect("send through FnSender should succeed");
+    assert!(captured.borrow().len() > 1, "payload should be fragmented");
+
+    let mut peer: Server<_, 16> = Server::new(Eid(8), 0, DroppingBufferSender);
+    let listener = peer.listener(1).unwrap();
+    transfer(&captured, &mut peer);
+
+    let mut recv_buf = [0u8; 255];
+    let meta = peer
+        .try_recv(listener, &mut recv_buf)
+        .expect("peer should reassemble the message");
+    assert_eq!(&recv_buf[..meta.payload_size], payload.as_slice());
+}
+
+/// `FnSender` never fragments below the MCTP baseline MTU or above its
+/// buffer size.
+#[test]
+fn fn_sender_mtu_is_clamped() {
+    let transmit = |_: &[u8]| Ok(());
+    assert_eq!(FnSender::new(8, transmit).get_mtu(), FN_SENDER_MIN_MTU);
+    assert_eq!(FnSender::new(0, transmit).get_mtu(), FN_SENDER_MIN_MTU);
+    assert_eq!(FnSender::new(100, transmit).get_mtu(), 100);
+    assert_eq!(FnSender::new(1024, transmit).get_mtu(), FN_SENDER_MAX_MTU);
+}
+
+/// An error returned by the `FnSender` closure is propagated by `send`.
+#[test]
+fn fn_sender_error_propagates() {
+    let sender = FnSender::new(64, |_: &[u8]| Err(mctp::Error::TxFailure));
+    let mut server: Server<_, 16> = Server::new(Eid(42), 0, sender);
+    let req = server.req(8).unwrap();
+
+    let err = server
+        .send(Some(req), 1, None, None, false, b"hello")
+        .expect_err("transmit failure should surface");
+    assert_eq!(err.code, ResponseCode::InternalError);
+}
+
+// ---------------------------------------------------------------------------
+// poll_recv (async receive)
+// ---------------------------------------------------------------------------
+
+/// A waker that counts how many times it was woken.
+struct CountingWaker(AtomicUsize);
+
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn counting_waker() -> (Arc<CountingWaker>, Waker) {
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    (counter.clone(), Waker::from(counter))
+}
+
+/// A `poll_fn` future over `poll_recv` stays pending until `inbound`
+/// delivers a message, wakes the task, and then resolves with it.
+#[test]
+fn poll_recv_wakes_on_inbound() {
+    let sender = DroppingBufferSender;
+    let server = RefCell::new(Server::<_, 16>::new(Eid(8), 0, sender));
+    let listener = server.borrow_mut().listener(1).unwrap();
+
+    let (counter, waker) = counting_waker();
+    let mut cx = Context::from_waker(&waker);
+    let mut buf = [0u8; 255];
+    let mut fut = pin!(core::future::poll_fn(|cx| {
+        server.borrow_mut().poll_recv(listener, &mut buf, cx)
+    }));
+
+    assert!(fut.as_mut().poll(&mut cx).is_pending());
+    assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+
+    deliver_to(42, 8, 1, b"async", &mut server.borrow_mut());
+    assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+
+    let Poll::Ready(Ok(meta)) = fut.as_mut().poll(&mut cx) else {
+        panic!("future should resolve after wake");
+    };
+    assert_eq!(meta.remote_eid, 42);
+    assert_eq!(meta.payload_size, 5);
+}
+
+/// A second task waiting on the same handle is rejected with `AddrInUse`
+/// until the first one cancels its wait.
+#[test]
+fn poll_recv_single_waiter() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let listener = server.listener(1).unwrap();
+    let mut buf = [0u8; 255];
+
+    let (_, first) = counting_waker();
+    let (_, second) = counting_waker();
+    let poll = server.poll_recv(listener, &mut buf, &mut Context::from_waker(&first));
+    assert!(poll.is_pending());
+
+    let poll = server.poll_recv(listener, &mut buf, &mut Context::from_waker(&second));
+    assert!(matches!(poll, Poll::Ready(Err(e)) if e.code == ResponseCode::AddrInUse));
+
+    server.cancel_poll_recv(listener);
+    let poll = server.poll_recv(listener, &mut buf, &mut Context::from_waker(&second));
+    assert!(poll.is_pending());
+}
+
+/// `poll_recv` on an unbound handle resolves immediately with `BadArgument`.
+#[test]
+fn poll_recv_unbound_handle() {
+    let sender = DroppingBufferSender;
+    let mut server: Server<_, 16> = Server::new(Eid(8), 0, sender);
+    let (_, waker) = counting_waker();
+    let poll = server.poll_recv(Handle(3), &mut [0u8; 255], &mut Context::from_waker(&waker));
+    assert!(matches!(poll, Poll::Ready(Err(e)) if e.code == ResponseCode::BadArgument));
 }
 
 // ---------------------------------------------------------------------------
@@ -0,0 +1,65 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Timer-driven server tests — drive receive timeouts from a
+//! `MonotonicTimer` instead of passing `now_millis` explicitly.
+
+mod common;
+
+use std::cell::Cell;
+
+use mctp::Eid;
+use openprot_hal_blocking::timer::MonotonicTimer;
+use openprot_mctp_server::{RecvResult, Server};
+
+use common::DroppingBufferSender;
+
+/// Virtual time advanced by the test.
+#[derive(Default)]
+struct VirtualTimer {
+    micros: Cell<u64>,
+}
+
+impl VirtualTimer {
+    fn advance_millis(&self, millis: u64) {
+        self.micros.set(self.micros.get() + millis * 1000);
+    }
+}
+
+impl MonotonicTimer for VirtualTimer {
+    fn now_millis(&self) -> u64 {
+        self.micros.get() / 1000
+    }
+
+    fn now_micros(&self) -> u64 {
+        self.micros.get()
+    }
+}
+
+/// A receive registered against the timer times out once the timer passes
+/// its deadline.
+#[test]
+fn pending_recv_times_out_on_timer() {
+    let timer = VirtualTimer::default();
+    timer.advance_millis(1_000);
+
+    let mut server: Server<_, 16> = Server::new_at(Eid(8), &timer, DroppingBufferSender);
+    let listener = server.listener(1).unwrap();
+    server
+        .register_recv_at(listener, 100, &timer)
+        .expect("register_recv_at should succeed");
+
+    let mut recv_buf = [0u8; 255];
+
+    timer.advance_millis(99);
+    let (_, ready) = server.update_at(&timer, &mut recv_buf);
+    assert!(ready.is_empty(), "should not fire before deadline");
+
+    timer.advance_millis(1);
+    let (_, ready) = server.update_at(&timer, &mut recv_buf);
+    assert_eq!(ready.len(), 1);
+    assert!(
+        matches!(ready[0], (h, RecvResult::TimedOut) if h == listener),
+        "expected TimedOut for listener handle"
+    );
+}
@@ -0,0 +1,54 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Monotonic counters for rollback protection.
+
+use crate::StorageError;
+
+/// Number of independent monotonic counters a backend provides.
+pub const MAX_COUNTERS: usize = 4;
+
+/// Identifies one monotonic counter, `0..MAX_COUNTERS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CounterId(pub u8);
+
+impl CounterId {
+    /// The counter's index, or [`StorageError::NotFound`] if it is out of
+    /// range.
+    pub fn index(self) -> Result<usize, StorageError> {
+        let index = usize::from(self.0);
+        if index < MAX_COUNTERS {
+            Ok(index)
+        } else {
+            Err(StorageError::NotFound)
+        }
+    }
+}
+
+/// Persistent counters that never decrease, even across resets.
+///
+/// Anti-rollback checks compare a firmware image's security version against
+/// one of these counters; once the counter has moved forward, an older image
+/// can no longer be accepted. Every counter starts at zero.
+pub trait MonotonicCounters {
+    /// Returns the current value of counter `id`.
+    fn read_counter(&self, id: CounterId) -> Result<u64, StorageError>;
+
+    /// Adds `by` to counter `id` and returns the new value.
+    ///
+    /// Returns [`StorageError::CounterOverflow`], leaving the counter
+    /// unchanged, if the addition would wrap.
+    fn increment_counter(&mut self, id: CounterId, by: u64) -> Result<u64, StorageError>;
+
+    /// Raises counter `id` to `value`, e.g. to the security version of a
+    /// newly committed image.
+    ///
+    /// Setting the current value again is a no-op. Returns
+    /// [`StorageError::Rollback`], leaving the counter unchanged, if `value`
+    /// is below the current value.
+    fn advance_counter_to(&mut self, id: CounterId, value: u64) -> Result<u64, StorageError> {
+        let current = self.read_counter(id)?;
+        let by = value.checked_sub(current).ok_or(StorageError::Rollback)?;
+        self.increment_counter(id, by)
+    }
+}
@@ -0,0 +1,44 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Record integrity check.
+
+/// CRC-32 (IEEE 802.3, reflected polynomial `0xEDB88320`) of `bytes`.
+///
+/// Backends store this alongside each record and recompute it on read;
+/// a mismatch is reported as [`StorageError::Corrupt`](crate::StorageError::Corrupt).
+/// Pass a previous result as `crc` to continue over another segment, or
+/// `0` to start.
+pub const fn crc32(crc: u32, bytes: &[u8]) -> u32 {
+    let mut crc = !crc;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i] as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_value() {
+        assert_eq!(crc32(0, b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn continues_across_segments() {
+        assert_eq!(crc32(crc32(0, b"1234"), b"56789"), crc32(0, b"123456789"));
+    }
+}
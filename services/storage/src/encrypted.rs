@@ -0,0 +1,151 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encryption at rest: [`EncryptedStore`] over any [`KvStore`].
+
+use crate::kv::check_lengths;
+use crate::{KvStore, StorageError, MAX_VALUE_LEN};
+
+/// Nonce length, in bytes, of the [`Aead`] an [`EncryptedStore`] uses.
+pub const NONCE_LEN: usize = 12;
+
+/// Authentication tag length, in bytes, of the [`Aead`] an
+/// [`EncryptedStore`] uses.
+pub const TAG_LEN: usize = 16;
+
+/// Longest value, in bytes, an [`EncryptedStore`] accepts: the nonce and
+/// tag stored with each value come out of the backend's [`MAX_VALUE_LEN`].
+pub const MAX_ENCRYPTED_VALUE_LEN: usize = MAX_VALUE_LEN - NONCE_LEN - TAG_LEN;
+
+/// Authenticated encryption with associated data, keyed at construction.
+///
+/// The 96-bit nonce and 128-bit tag match AES-GCM and ChaCha20-Poly1305.
+/// Both operations take `&self` so that reads through a shared
+/// [`KvStore::get`] can decrypt.
+pub trait Aead {
+    /// Encrypts `data` in place and returns the tag authenticating it and
+    /// `aad`.
+    fn encrypt_in_place(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        data: &mut [u8],
+    ) -> [u8; TAG_LEN];
+
+    /// Checks `tag` against `data` and `aad`, then decrypts `data` in place.
+    ///
+    /// Fails with [`StorageError::Corrupt`], leaving `data` unspecified, if
+    /// the tag does not match.
+    fn decrypt_in_place(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        data: &mut [u8],
+        tag: &[u8; TAG_LEN],
+    ) -> Result<(), StorageError>;
+}
+
+/// A [`KvStore`] decorator that encrypts every value before it reaches the
+/// backend.
+///
+/// Each value is stored as `nonce || tag || ciphertext`, with the key as
+/// associated data, so a record cannot be decrypted under another key or
+/// moved to one undetected. A tag mismatch on `get` is reported as
+/// [`StorageError::Corrupt`]. Keys are stored in the clear; values may be
+/// at most [`MAX_ENCRYPTED_VALUE_LEN`] bytes.
+///
+/// Nonces are a 4-byte prefix given at construction followed by a 64-bit
+/// write counter starting at zero. The prefix must differ every time a
+/// store is created with the same key (e.g. taken from a boot counter or an
+/// RNG), or nonces repeat.
+pub struct EncryptedStore<S: KvStore, A: Aead> {
+    store: S,
+    aead: A,
+    nonce_prefix: [u8; 4],
+    writes: u64,
+}
+
+impl<S: KvStore, A: Aead> EncryptedStore<S, A> {
+    /// Wraps `store`, encrypting with `aead` and nonces under
+    /// `nonce_prefix`.
+    pub fn new(store: S, aead: A, nonce_prefix: [u8; 4]) -> Self {
+        Self {
+            store,
+            aead,
+            nonce_prefix,
+            writes: 0,
+        }
+    }
+
+    /// The backend, holding ciphertext.
+    pub fn inner(&self) -> &S {
+        &self.store
+    }
+
+    /// The backend, mutably, e.g. to inject faults in tests.
+    pub fn inner_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    /// Unwraps the backend.
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+
+    fn next_nonce(&mut self) -> Result<[u8; NONCE_LEN], StorageError> {
+        let counter = self.writes;
+        self.writes = counter.checked_add(1).ok_or(StorageError::Full)?;
+        let mut nonce = [0; NONCE_LEN];
+        nonce[..4].copy_from_slice(&self.nonce_prefix);
+        nonce[4..].copy_from_slice(&counter.to_be_bytes());
+        Ok(nonce)
+    }
+}
+
+impl<S: KvStore, A: Aead> KvStore for EncryptedStore<S, A> {
+    fn get(&self, key: &[u8], buf: &mut [u8]) -> Result<usize, StorageError> {
+        let mut record = [0; MAX_VALUE_LEN];
+        let len = self.store.get(key, &mut record)?;
+        if len < NONCE_LEN + TAG_LEN {
+            return Err(StorageError::Corrupt);
+        }
+        let (header, ciphertext) = record[..len].split_at_mut(NONCE_LEN + TAG_LEN);
+        let (nonce, tag) = header.split_at(NONCE_LEN);
+        let dst = buf
+            .get_mut(..ciphertext.len())
+            .ok_or(StorageError::BufferTooSmall)?;
+        let nonce = nonce.try_into().map_err(|_| StorageError::Corrupt)?;
+        let tag = tag.try_into().map_err(|_| StorageError::Corrupt)?;
+        self.aead.decrypt_in_place(nonce, key, ciphertext, tag)?;
+        dst.copy_from_slice(ciphertext);
+        Ok(ciphertext.len())
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), StorageError> {
+        if value.len() > MAX_ENCRYPTED_VALUE_LEN {
+            return Err(StorageError::TooLarge);
+        }
+        check_lengths(key, value)?;
+        let nonce = self.next_nonce()?;
+        let len = NONCE_LEN + TAG_LEN + value.len();
+        let mut record = [0; MAX_VALUE_LEN];
+        let (header, ciphertext) = record[..len].split_at_mut(NONCE_LEN + TAG_LEN);
+        ciphertext.copy_from_slice(value);
+        let tag = self.aead.encrypt_in_place(&nonce, key, ciphertext);
+        header[..NONCE_LEN].copy_from_slice(&nonce);
+        header[NONCE_LEN..].copy_from_slice(&tag);
+        self.store.put(key, &record[..len])
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<(), StorageError> {
+        self.store.delete(key)
+    }
+
+    fn contains(&self, key: &[u8]) -> bool {
+        self.store.contains(key)
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &[u8]> {
+        self.store.keys()
+    }
+}
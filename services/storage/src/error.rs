@@ -0,0 +1,53 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Storage error vocabulary shared by every backend.
+
+/// Why a storage operation failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageError {
+    /// No record is stored under the key, or no counter has the id.
+    NotFound,
+    /// The caller's buffer is shorter than the stored value.
+    BufferTooSmall,
+    /// The key is empty.
+    InvalidLength,
+    /// The key is longer than [`MAX_KEY_LEN`](crate::MAX_KEY_LEN) or the
+    /// value longer than [`MAX_VALUE_LEN`](crate::MAX_VALUE_LEN), or than
+    /// the tighter limit of a wrapping store.
+    TooLarge,
+    /// The backend has no room for another record, or the write would
+    /// exceed its [`StorageQuota`](crate::StorageQuota) capacity.
+    Full,
+    /// The transaction already holds [`MAX_TXN_OPS`](crate::MAX_TXN_OPS)
+    /// staged operations.
+    TransactionFull,
+    /// Power was lost before the commit completed; nothing was applied.
+    Interrupted,
+    /// The stored record failed its integrity check.
+    Corrupt,
+    /// The counter update would wrap around.
+    CounterOverflow,
+    /// The counter update would move it backwards.
+    Rollback,
+}
+
+impl core::fmt::Display for StorageError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotFound => f.write_str("key not found"),
+            Self::BufferTooSmall => f.write_str("buffer too small"),
+            Self::InvalidLength => f.write_str("empty key"),
+            Self::TooLarge => f.write_str("key or value too large"),
+            Self::Full => f.write_str("storage full"),
+            Self::TransactionFull => f.write_str("too many staged operations"),
+            Self::Interrupted => f.write_str("commit interrupted"),
+            Self::Corrupt => f.write_str("record corrupt"),
+            Self::CounterOverflow => f.write_str("counter overflow"),
+            Self::Rollback => f.write_str("counter rollback rejected"),
+        }
+    }
+}
+
+impl core::error::Error for StorageError {}
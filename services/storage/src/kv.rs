@@ -0,0 +1,77 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Home of the [`KvStore`] persistence contract.
+
+use crate::{NamespaceHandle, NamespaceId, StorageError};
+
+/// Longest key, in bytes, any backend must accept.
+///
+/// Longer keys are rejected with [`StorageError::TooLarge`].
+pub const MAX_KEY_LEN: usize = 32;
+
+/// Longest value, in bytes, any backend must accept: the size of a record
+/// slot's value field.
+///
+/// Longer values are rejected with [`StorageError::TooLarge`] before the
+/// backend is touched, so an oversize `put` never leaves a partial write.
+pub const MAX_VALUE_LEN: usize = 256;
+
+/// Persistent key-value store.
+///
+/// Keys are non-empty byte strings of at most [`MAX_KEY_LEN`] bytes; values
+/// are byte strings (possibly empty) of at most [`MAX_VALUE_LEN`] bytes.
+/// Empty keys are rejected with [`StorageError::InvalidLength`] and
+/// oversize keys or values with [`StorageError::TooLarge`], in both cases
+/// before the backend is touched.
+///
+/// A successful `put` or `delete` is durable: it survives a reset of the
+/// device once the call returns.
+pub trait KvStore {
+    /// Copies the value stored under `key` into `buf` and returns its length.
+    ///
+    /// Fails with [`StorageError::NotFound`] if no value is stored,
+    /// [`StorageError::BufferTooSmall`] if `buf` cannot hold it, or
+    /// [`StorageError::Corrupt`] if the stored record no longer matches its
+    /// CRC.
+    fn get(&self, key: &[u8], buf: &mut [u8]) -> Result<usize, StorageError>;
+
+    /// Stores `value` under `key`, replacing any previous value.
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), StorageError>;
+
+    /// Removes the value stored under `key`.
+    ///
+    /// Fails with [`StorageError::NotFound`] if no value is stored.
+    fn delete(&mut self, key: &[u8]) -> Result<(), StorageError>;
+
+    /// Returns `true` if a value is stored under `key`.
+    fn contains(&self, key: &[u8]) -> bool;
+
+    /// Iterates over every stored key, in no particular order.
+    ///
+    /// The iterator borrows the store, so it reflects exactly the `put`s and
+    /// `delete`s that completed before it was created. To modify the store
+    /// based on what it yields, copy the keys out first.
+    fn keys(&self) -> impl Iterator<Item = &[u8]>;
+
+    /// Returns a view of this store confined to namespace `ns`.
+    ///
+    /// See [`NamespaceHandle`] for how keyspaces are kept apart.
+    fn namespace(&mut self, ns: NamespaceId) -> NamespaceHandle<'_, Self>
+    where
+        Self: Sized,
+    {
+        NamespaceHandle::new(self, ns)
+    }
+}
+
+/// Checks `key` and `value` against the [`KvStore`] length bounds.
+pub(crate) fn check_lengths(key: &[u8], value: &[u8]) -> Result<(), StorageError> {
+    if key.is_empty() {
+        return Err(StorageError::InvalidLength);
+    }
+    if key.len() > MAX_KEY_LEN || value.len() > MAX_VALUE_LEN {
+        return Err(StorageError::TooLarge);
+    }
+    Ok(())
+}
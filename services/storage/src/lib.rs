@@ -4,5 +4,49 @@
 //! Persistent storage service and abstractions for OpenPRoT
 //!
 //! This crate provides storage abstractions and persistence capabilities.
+//!
+//! - [`KvStore`] — the key-value persistence contract that config and state
+//!   storage is built on. Keys and values are byte strings bounded by
+//!   [`MAX_KEY_LEN`] and [`MAX_VALUE_LEN`], so every backend can use
+//!   fixed-size records without allocation. Each record carries a
+//!   [`crc32`] checked on read, so flash corruption surfaces as
+//!   [`StorageError::Corrupt`] instead of bad data.
+//! - [`NamespaceHandle`] — an isolated keyspace per service (attestation,
+//!   config, telemetry) over one shared store, from [`KvStore::namespace`].
+//! - [`Transaction`] — stages several `put`/`delete` operations and commits
+//!   them all-or-nothing through an [`AtomicKvStore`], so a power loss never
+//!   leaves a half-applied update.
+//! - [`MonotonicCounters`] — persistent counters that only move forward,
+//!   used for firmware anti-rollback.
+//! - [`EncryptedStore`] — encryption at rest over any [`KvStore`], with
+//!   values sealed by an [`Aead`] so tampering surfaces as
+//!   [`StorageError::Corrupt`].
+//! - [`StorageQuota`] — byte capacity accounting, so a service can check
+//!   what remains before a large write instead of failing part-way.
+//! - [`LogKvStore`] — an append-only store whose stale entries are reclaimed
+//!   by a crash-safe `compact()`, matching how flash is best written.
+//! - [`mock::MockKvStore`] and [`mock::MockLogStore`] — in-RAM backends for
+//!   host tests.
+
+#![cfg_attr(not(test), no_std)]
+
+mod counter;
+mod crc;
+mod encrypted;
+mod error;
+mod kv;
+mod log;
+pub mod mock;
+mod namespace;
+mod quota;
+mod txn;
 
-#![no_std]
+pub use counter::{CounterId, MonotonicCounters, MAX_COUNTERS};
+pub use crc::crc32;
+pub use encrypted::{Aead, EncryptedStore, MAX_ENCRYPTED_VALUE_LEN, NONCE_LEN, TAG_LEN};
+pub use error::StorageError;
+pub use kv::{KvStore, MAX_KEY_LEN, MAX_VALUE_LEN};
+pub use log::LogKvStore;
+pub use namespace::{NamespaceHandle, NamespaceId, MAX_NAMESPACED_KEY_LEN};
+pub use quota::StorageQuota;
+pub use txn::{AtomicKvStore, Transaction, TxnOp, MAX_TXN_OPS};
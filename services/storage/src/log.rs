@@ -0,0 +1,35 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Append-only, flash-friendly stores: [`LogKvStore`].
+
+use crate::{KvStore, StorageError};
+
+/// A [`KvStore`] that appends every update to a log instead of rewriting
+/// records in place.
+///
+/// Each `put` appends the new value and each `delete` appends a tombstone;
+/// older entries for the same key become stale but keep occupying log space
+/// until [`compact`](Self::compact) reclaims them. Once the log is full,
+/// `put` and `delete` fail with [`StorageError::Full`] until space is
+/// reclaimed.
+pub trait LogKvStore: KvStore {
+    /// Total number of entries the log can hold.
+    fn log_capacity(&self) -> usize;
+
+    /// Number of entries currently appended, live or stale.
+    fn log_used(&self) -> usize;
+
+    /// Number of entries that can be appended before the log is full.
+    fn log_free(&self) -> usize {
+        self.log_capacity() - self.log_used()
+    }
+
+    /// Rewrites the log keeping only live records and returns how many
+    /// entries were reclaimed.
+    ///
+    /// Compaction is crash-safe: if it is interrupted, the store still holds
+    /// every committed record and the call fails with
+    /// [`StorageError::Interrupted`]; compacting again completes the job.
+    fn compact(&mut self) -> Result<usize, StorageError>;
+}
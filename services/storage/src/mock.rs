@@ -0,0 +1,1160 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-RAM [`KvStore`] backends for host tests.
+//!
+//! Records live in a fixed array of slots sized for the [`KvStore`] length
+//! bounds, so the mock has the same `no_std`, allocation-free shape as a
+//! flash-backed store.
+//!
+//! Atomic commits are modelled as a shadow copy of the slot array that is
+//! swapped in only once every operation has been applied, the way an A/B
+//! flash bank is switched by a single final write. Tests can inject a power
+//! loss part-way through a commit with
+//! [`interrupt_commit_after`](MockKvStore::interrupt_commit_after).
+//!
+//! Each record keeps the [`crc32`] of its key and value, verified on every
+//! `get`; [`corrupt_record`](MockKvStore::corrupt_record) flips a stored
+//! byte to exercise the [`StorageError::Corrupt`] path.
+//!
+//! [`MockLogStore`] is the append-only counterpart: a [`LogKvStore`] over two
+//! log regions, with compaction that can be interrupted the same way.
+//!
+//! Like a flash translation layer, [`MockKvStore`] spreads rewrites over its
+//! slots: every `put` lands in the least-written slot available to it and
+//! frees the key's old slot, and
+//! [`slot_write_counts`](MockKvStore::slot_write_counts) exposes the
+//! per-slot wear.
+//!
+//! [`MockKvStore`] also enforces a [`StorageQuota`] byte budget, set with
+//! [`with_capacity`](MockKvStore::with_capacity), independently of its slot
+//! count.
+//!
+//! [`EncryptedStore`](crate::EncryptedStore) is tested against the platform
+//! mock's `MockAead`, through an [`Aead`](crate::Aead) impl local to the
+//! tests.
+//!
+//! Records and [`MonotonicCounters`] are the mock's "flash": they survive
+//! [`reset`](MockKvStore::reset), which only clears volatile test state.
+
+use crate::kv::check_lengths;
+use crate::{
+    crc32, AtomicKvStore, CounterId, KvStore, LogKvStore, MonotonicCounters, StorageError,
+    StorageQuota, TxnOp, MAX_COUNTERS, MAX_KEY_LEN, MAX_VALUE_LEN,
+};
+
+/// Default number of record slots in a [`MockKvStore`].
+pub const DEFAULT_SLOTS: usize = 16;
+
+/// One stored key-value pair.
+#[derive(Clone, Copy)]
+struct Record {
+    key: [u8; MAX_KEY_LEN],
+    key_len: usize,
+    value: [u8; MAX_VALUE_LEN],
+    value_len: usize,
+    /// [`crc32`] over the key then the value, as written.
+    crc: u32,
+}
+
+impl Record {
+    fn new(key: &[u8], value: &[u8]) -> Self {
+        let mut record = Self {
+            key: [0; MAX_KEY_LEN],
+            key_len: key.len(),
+            value: [0; MAX_VALUE_LEN],
+            value_len: value.len(),
+            crc: crc32(crc32(0, key), value),
+        };
+        record.key[..key.len()].copy_from_slice(key);
+        record.value[..value.len()].copy_from_slice(value);
+        record
+    }
+
+    fn is_intact(&self) -> bool {
+        crc32(crc32(0, self.key()), self.value()) == self.crc
+    }
+
+    fn key(&self) -> &[u8] {
+        &self.key[..self.key_len]
+    }
+
+    fn value(&self) -> &[u8] {
+        &self.value[..self.value_len]
+    }
+
+    /// Bytes the record counts against a [`StorageQuota`].
+    fn size(&self) -> usize {
+        self.key_len + self.value_len
+    }
+}
+
+/// In-RAM key-value store holding up to `SLOTS` records.
+///
+/// Records are bounded both by the slot count and by a byte budget, which
+/// defaults to enough for every slot to hold its longest record.
+pub struct MockKvStore<const SLOTS: usize = DEFAULT_SLOTS> {
+    slots: [Option<Record>; SLOTS],
+    /// [`StorageQuota`] budget, in bytes.
+    capacity: usize,
+    /// Times each slot has been written, i.e. erased and programmed.
+    slot_writes: [u32; SLOTS],
+    counters: [u64; MAX_COUNTERS],
+    /// Lose power after this many operations of the next atomic commit.
+    interrupt_after: Option<usize>,
+}
+
+impl<const SLOTS: usize> MockKvStore<SLOTS> {
+    /// Creates an empty store.
+    pub const fn new() -> Self {
+        Self::with_capacity(SLOTS * (MAX_KEY_LEN + MAX_VALUE_LEN))
+    }
+
+    /// Creates an empty store whose records may total at most `capacity`
+    /// bytes of keys and values.
+    pub const fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: [None; SLOTS],
+            capacity,
+            slot_writes: [0; SLOTS],
+            counters: [0; MAX_COUNTERS],
+            interrupt_after: None,
+        }
+    }
+
+    /// Simulates a device reset: stored records and counters persist, any
+    /// pending injected fault is dropped.
+    pub fn reset(&mut self) {
+        self.interrupt_after = None;
+    }
+
+    /// Simulates a power loss during the next atomic commit, after `ops`
+    /// of its operations have been written to the shadow copy.
+    ///
+    /// The commit returns [`StorageError::Interrupted`] and leaves the
+    /// store unchanged.
+    pub fn interrupt_commit_after(&mut self, ops: usize) {
+        self.interrupt_after = Some(ops);
+    }
+
+    /// Flips every bit of byte `index` of the value stored under `key`,
+    /// without updating its CRC, to simulate flash corruption.
+    ///
+    /// Returns [`StorageError::NotFound`] if there is no such record or
+    /// `index` is past the end of its value.
+    pub fn corrupt_record(&mut self, key: &[u8], index: usize) -> Result<(), StorageError> {
+        let record = self
+            .find(key)
+            .and_then(|i| self.slots[i].as_mut())
+            .filter(|r| index < r.value_len)
+            .ok_or(StorageError::NotFound)?;
+        record.value[index] ^= 0xFF;
+        Ok(())
+    }
+
+    /// Number of records currently stored.
+    pub fn len(&self) -> usize {
+        self.slots.iter().flatten().count()
+    }
+
+    /// How many times each physical slot has been written, indexed by slot.
+    ///
+    /// Counts persist across [`reset`](Self::reset), like flash wear.
+    pub fn slot_write_counts(&self) -> &[u32] {
+        &self.slot_writes
+    }
+
+    /// Returns `true` if no records are stored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn find(&self, key: &[u8]) -> Option<usize> {
+        find(&self.slots, key)
+    }
+}
+
+fn find(slots: &[Option<Record>], key: &[u8]) -> Option<usize> {
+    slots
+        .iter()
+        .position(|slot| slot.as_ref().is_some_and(|r| r.key() == key))
+}
+
+fn used(slots: &[Option<Record>]) -> usize {
+    slots.iter().flatten().map(Record::size).sum()
+}
+
+/// Stores `key` and `value` in the least-written of the free slots and the
+/// key's current slot, counting the write in `slot_writes`, then frees the
+/// key's old slot if the record moved.
+fn put(
+    slots: &mut [Option<Record>],
+    slot_writes: &mut [u32],
+    capacity: usize,
+    key: &[u8],
+    value: &[u8],
+) -> Result<(), StorageError> {
+    check_lengths(key, value)?;
+    let existing = find(slots, key);
+    let replaced = existing
+        .and_then(|i| slots[i].as_ref())
+        .map_or(0, Record::size);
+    if used(slots) - replaced + key.len() + value.len() > capacity {
+        return Err(StorageError::Full);
+    }
+    let index = (0..slots.len())
+        .filter(|&i| slots[i].is_none() || Some(i) == existing)
+        .min_by_key(|&i| slot_writes[i])
+        .ok_or(StorageError::Full)?;
+    slots[index] = Some(Record::new(key, value));
+    slot_writes[index] = slot_writes[index].saturating_add(1);
+    if let Some(old) = existing.filter(|&old| old != index) {
+        slots[old] = None;
+    }
+    Ok(())
+}
+
+fn delete(slots: &mut [Option<Record>], key: &[u8]) -> Result<(), StorageError> {
+    let index = find(slots, key).ok_or(StorageError::NotFound)?;
+    slots[index] = None;
+    Ok(())
+}
+
+/// Verifies `record` and copies its value into `buf`.
+fn copy_value(record: &Record, buf: &mut [u8]) -> Result<usize, StorageError> {
+    if !record.is_intact() {
+        return Err(StorageError::Corrupt);
+    }
+    let value = record.value();
+    let dst = buf
+        .get_mut(..value.len())
+        .ok_or(StorageError::BufferTooSmall)?;
+    dst.copy_from_slice(value);
+    Ok(value.len())
+}
+
+impl<const SLOTS: usize> Default for MockKvStore<SLOTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const SLOTS: usize> KvStore for MockKvStore<SLOTS> {
+    fn get(&self, key: &[u8], buf: &mut [u8]) -> Result<usize, StorageError> {
+        let record = self
+            .find(key)
+            .and_then(|i| self.slots[i].as_ref())
+            .ok_or(StorageError::NotFound)?;
+        copy_value(record, buf)
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), StorageError> {
+        put(
+            &mut self.slots,
+            &mut self.slot_writes,
+            self.capacity,
+            key,
+            value,
+        )
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<(), StorageError> {
+        delete(&mut self.slots, key)
+    }
+
+    fn contains(&self, key: &[u8]) -> bool {
+        self.find(key).is_some()
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &[u8]> {
+        self.slots.iter().flatten().map(Record::key)
+    }
+}
+
+impl<const SLOTS: usize> StorageQuota for MockKvStore<SLOTS> {
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn used(&self) -> usize {
+        used(&self.slots)
+    }
+}
+
+impl<const SLOTS: usize> MonotonicCounters for MockKvStore<SLOTS> {
+    fn read_counter(&self, id: CounterId) -> Result<u64, StorageError> {
+        Ok(self.counters[id.index()?])
+    }
+
+    fn increment_counter(&mut self, id: CounterId, by: u64) -> Result<u64, StorageError> {
+        let counter = &mut self.counters[id.index()?];
+        *counter = counter
+            .checked_add(by)
+            .ok_or(StorageError::CounterOverflow)?;
+        Ok(*counter)
+    }
+}
+
+impl<const SLOTS: usize> AtomicKvStore for MockKvStore<SLOTS> {
+    fn apply_atomic(&mut self, ops: &[TxnOp<'_>]) -> Result<(), StorageError> {
+        let interrupt_after = self.interrupt_after.take();
+        let mut shadow = self.slots;
+        for (applied, op) in ops.iter().enumerate() {
+            if interrupt_after == Some(applied) {
+                return Err(StorageError::Interrupted);
+            }
+            match *op {
+                // Wear is real even if the commit is later interrupted.
+                TxnOp::Put { key, value } => put(
+                    &mut shadow,
+                    &mut self.slot_writes,
+                    self.capacity,
+                    key,
+                    value,
+                )?,
+                TxnOp::Delete { key } => delete(&mut shadow, key)?,
+            }
+        }
+        // Power lost with every operation written but before the switch-over.
+        if interrupt_after == Some(ops.len()) {
+            return Err(StorageError::Interrupted);
+        }
+        // The single switch-over write: the whole batch becomes visible.
+        self.slots = shadow;
+        Ok(())
+    }
+}
+
+/// Default number of log entries per region of a [`MockLogStore`].
+pub const DEFAULT_LOG_ENTRIES: usize = 32;
+
+/// One appended log entry: a new value, or a tombstone for `record`'s key.
+#[derive(Clone, Copy)]
+struct LogEntry {
+    record: Record,
+    deleted: bool,
+}
+
+/// In-RAM [`LogKvStore`] with `ENTRIES` log entries per region.
+///
+/// The log lives in one of two regions, like two flash sectors. Compaction
+/// erases the inactive region, copies the live records into it and only
+/// then makes it active, so losing power part-way leaves the old log in
+/// charge. [`interrupt_compaction_after`](Self::interrupt_compaction_after)
+/// injects that power loss.
+pub struct MockLogStore<const ENTRIES: usize = DEFAULT_LOG_ENTRIES> {
+    regions: [[Option<LogEntry>; ENTRIES]; 2],
+    active: usize,
+    /// Lose power after copying this many records during the next compaction.
+    interrupt_after: Option<usize>,
+}
+
+impl<const ENTRIES: usize> MockLogStore<ENTRIES> {
+    /// Creates an empty store.
+    pub const fn new() -> Self {
+        Self {
+            regions: [[None; ENTRIES]; 2],
+            active: 0,
+            interrupt_after: None,
+        }
+    }
+
+    /// Simulates a power loss during the next [`compact`](LogKvStore::compact),
+    /// after `records` live records have been copied to the new region.
+    ///
+    /// The compaction returns [`StorageError::Interrupted`] and the store
+    /// keeps serving the old log.
+    pub fn interrupt_compaction_after(&mut self, records: usize) {
+        self.interrupt_after = Some(records);
+    }
+
+    /// Simulates a device reset: the log persists, any pending injected
+    /// fault is dropped.
+    pub fn reset(&mut self) {
+        self.interrupt_after = None;
+    }
+
+    /// Number of live records.
+    pub fn len(&self) -> usize {
+        self.keys().count()
+    }
+
+    /// Returns `true` if no live records are stored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn log(&self) -> impl DoubleEndedIterator<Item = &LogEntry> {
+        self.regions[self.active].iter().flatten()
+    }
+
+    /// The newest entry for `key`, if it is not a tombstone.
+    fn latest(&self, key: &[u8]) -> Option<&Record> {
+        self.log()
+            .rev()
+            .find(|entry| entry.record.key() == key)
+            .filter(|entry| !entry.deleted)
+            .map(|entry| &entry.record)
+    }
+
+    /// Returns the live records in log order: each key's newest entry, if
+    /// that entry is not a tombstone.
+    fn live(&self) -> impl Iterator<Item = &Record> {
+        let log = &self.regions[self.active];
+        log.iter()
+            .enumerate()
+            .map_while(|(i, entry)| Some((i, entry.as_ref()?)))
+            .filter(move |&(i, entry)| {
+                !entry.deleted
+                    && !log[i + 1..]
+                        .iter()
+                        .flatten()
+                        .any(|later| later.record.key() == entry.record.key())
+            })
+            .map(|(_, entry)| &entry.record)
+    }
+
+    fn append(&mut self, record: Record, deleted: bool) -> Result<(), StorageError> {
+        let slot = self.regions[self.active]
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(StorageError::Full)?;
+        *slot = Some(LogEntry { record, deleted });
+        Ok(())
+    }
+}
+
+impl<const ENTRIES: usize> Default for MockLogStore<ENTRIES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const ENTRIES: usize> KvStore for MockLogStore<ENTRIES> {
+    fn get(&self, key: &[u8], buf: &mut [u8]) -> Result<usize, StorageError> {
+        let record = self.latest(key).ok_or(StorageError::NotFound)?;
+        copy_value(record, buf)
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), StorageError> {
+        check_lengths(key, value)?;
+        self.append(Record::new(key, value), false)
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<(), StorageError> {
+        if self.latest(key).is_none() {
+            return Err(StorageError::NotFound);
+        }
+        self.append(Record::new(key, &[]), true)
+    }
+
+    fn contains(&self, key: &[u8]) -> bool {
+        self.latest(key).is_some()
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &[u8]> {
+        self.live().map(Record::key)
+    }
+}
+
+impl<const ENTRIES: usize> LogKvStore for MockLogStore<ENTRIES> {
+    fn log_capacity(&self) -> usize {
+        ENTRIES
+    }
+
+    fn log_used(&self) -> usize {
+        self.log().count()
+    }
+
+    fn compact(&mut self) -> Result<usize, StorageError> {
+        let interrupt_after = self.interrupt_after.take();
+        let target = 1 - self.active;
+        let mut fresh = [None; ENTRIES];
+        let mut copied = 0;
+        let mut interrupted = false;
+        for record in self.live() {
+            if interrupt_after == Some(copied) {
+                interrupted = true;
+                break;
+            }
+            fresh[copied] = Some(LogEntry {
+                record: *record,
+                deleted: false,
+            });
+            copied += 1;
+        }
+        self.regions[target] = fresh;
+        // A fault after the last record still lands before the commit point.
+        if interrupted || interrupt_after == Some(copied) {
+            // The half-written region is never made active.
+            return Err(StorageError::Interrupted);
+        }
+        let reclaimed = self.log_used() - copied;
+        // Switching regions is the commit point.
+        self.active = target;
+        self.regions[1 - target] = [None; ENTRIES];
+        Ok(reclaimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Aead, EncryptedStore, LogKvStore, NamespaceId, Transaction, MAX_ENCRYPTED_VALUE_LEN,
+        MAX_NAMESPACED_KEY_LEN, MAX_TXN_OPS, NONCE_LEN, TAG_LEN,
+    };
+    use openprot_platform_mock::cipher::{MockAead, KEY_LEN};
+
+    #[test]
+    fn put_get_round_trip() {
+        let mut store = MockKvStore::<4>::new();
+        store.put(b"boot.slot", b"A").expect("put failed");
+
+        let mut buf = [0u8; 8];
+        let len = store.get(b"boot.slot", &mut buf).expect("get failed");
+        assert_eq!(&buf[..len], b"A");
+        assert!(store.contains(b"boot.slot"));
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn put_overwrites_existing_value() {
+        let mut store = MockKvStore::<4>::new();
+        store.put(b"fw.version", b"1.0.0").expect("put failed");
+        store.put(b"fw.version", b"1.2").expect("overwrite failed");
+
+        let mut buf = [0u8; 8];
+        let len = store.get(b"fw.version", &mut buf).expect("get failed");
+        assert_eq!(&buf[..len], b"1.2");
+        assert_eq!(store.len(), 1);
+    }
+
+    #[test]
+    fn missing_key() {
+        let mut store = MockKvStore::<4>::new();
+        let mut buf = [0u8; 8];
+        assert_eq!(store.get(b"absent", &mut buf), Err(StorageError::NotFound));
+        assert_eq!(store.delete(b"absent"), Err(StorageError::NotFound));
+        assert!(!store.contains(b"absent"));
+
+        store.put(b"present", b"").expect("put failed");
+        store.delete(b"present").expect("delete failed");
+        assert!(!store.contains(b"present"));
+        assert_eq!(store.get(b"present", &mut buf), Err(StorageError::NotFound));
+    }
+
+    #[test]
+    fn get_into_short_buffer() {
+        let mut store = MockKvStore::<4>::new();
+        store.put(b"k", b"four").expect("put failed");
+
+        let mut buf = [0u8; 3];
+        assert_eq!(store.get(b"k", &mut buf), Err(StorageError::BufferTooSmall));
+    }
+
+    #[test]
+    fn length_bounds() {
+        let mut store = MockKvStore::<4>::new();
+        let long_key = [b'k'; MAX_KEY_LEN + 1];
+        let long_value = [0u8; MAX_VALUE_LEN + 1];
+
+        assert_eq!(store.put(b"", b"v"), Err(StorageError::InvalidLength));
+        assert_eq!(store.put(&long_key, b"v"), Err(StorageError::TooLarge));
+        assert_eq!(store.put(b"k", &long_value), Err(StorageError::TooLarge));
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn record_size_limits_at_boundary() {
+        let mut store = MockKvStore::<4>::new();
+        let max_key = [b'k'; MAX_KEY_LEN];
+        let max_value = [0xA5u8; MAX_VALUE_LEN];
+
+        store.put(&max_key, b"v").expect("max key rejected");
+        store.put(b"v", &max_value).expect("max value rejected");
+        store
+            .put(&max_key, &max_value)
+            .expect("max record rejected");
+        let mut buf = [0u8; MAX_VALUE_LEN];
+        assert_eq!(store.get(&max_key, &mut buf), Ok(MAX_VALUE_LEN));
+        assert_eq!(buf, max_value);
+
+        // One byte over fails without touching what is stored.
+        assert_eq!(
+            store.put(&[b'k'; MAX_KEY_LEN + 1], b"v"),
+            Err(StorageError::TooLarge)
+        );
+        assert_eq!(
+            store.put(&max_key, &[0; MAX_VALUE_LEN + 1]),
+            Err(StorageError::TooLarge)
+        );
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get(&max_key, &mut buf), Ok(MAX_VALUE_LEN));
+        assert_eq!(buf, max_value);
+    }
+
+    fn value_of(store: &MockKvStore<4>, key: &[u8]) -> Option<Vec<u8>> {
+        let mut buf = [0u8; MAX_VALUE_LEN];
+        let len = store.get(key, &mut buf).ok()?;
+        Some(buf[..len].to_vec())
+    }
+
+    fn seeded_store() -> MockKvStore<4> {
+        let mut store = MockKvStore::<4>::new();
+        store.put(b"cfg.a", b"old-a").expect("put failed");
+        store.put(b"cfg.b", b"old-b").expect("put failed");
+        store
+    }
+
+    #[test]
+    fn transaction_commit_applies_all() {
+        let mut store = seeded_store();
+
+        let mut txn = Transaction::new(&mut store);
+        txn.put(b"cfg.a", b"new-a").expect("stage failed");
+        txn.put(b"cfg.c", b"new-c").expect("stage failed");
+        txn.delete(b"cfg.b").expect("stage failed");
+        assert_eq!(txn.len(), 3);
+        txn.commit().expect("commit failed");
+
+        assert_eq!(value_of(&store, b"cfg.a").as_deref(), Some(&b"new-a"[..]));
+        assert_eq!(value_of(&store, b"cfg.c").as_deref(), Some(&b"new-c"[..]));
+        assert!(!store.contains(b"cfg.b"));
+    }
+
+    #[test]
+    fn transaction_abort_discards_staged() {
+        let mut store = seeded_store();
+
+        let mut txn = Transaction::new(&mut store);
+        txn.put(b"cfg.a", b"new-a").expect("stage failed");
+        txn.delete(b"cfg.b").expect("stage failed");
+        txn.abort();
+
+        assert_eq!(value_of(&store, b"cfg.a").as_deref(), Some(&b"old-a"[..]));
+        assert_eq!(value_of(&store, b"cfg.b").as_deref(), Some(&b"old-b"[..]));
+    }
+
+    #[test]
+    fn transaction_interrupted_commit_applies_nothing() {
+        let mut store = seeded_store();
+        store.interrupt_commit_after(2);
+
+        let mut txn = Transaction::new(&mut store);
+        txn.put(b"cfg.a", b"new-a").expect("stage failed");
+        txn.delete(b"cfg.b").expect("stage failed");
+        txn.put(b"cfg.c", b"new-c").expect("stage failed");
+        assert_eq!(txn.commit(), Err(StorageError::Interrupted));
+
+        assert_eq!(value_of(&store, b"cfg.a").as_deref(), Some(&b"old-a"[..]));
+        assert_eq!(value_of(&store, b"cfg.b").as_deref(), Some(&b"old-b"[..]));
+        assert!(!store.contains(b"cfg.c"));
+
+        // The injected power loss is one-shot; a retry goes through.
+        let mut txn = Transaction::new(&mut store);
+        txn.put(b"cfg.c", b"new-c").expect("stage failed");
+        txn.commit().expect("retry failed");
+        assert!(store.contains(b"cfg.c"));
+    }
+
+    #[test]
+    fn transaction_interrupted_before_switch_over_applies_nothing() {
+        let mut store = seeded_store();
+        store.interrupt_commit_after(2);
+
+        let mut txn = Transaction::new(&mut store);
+        txn.put(b"cfg.a", b"new-a").expect("stage failed");
+        txn.delete(b"cfg.b").expect("stage failed");
+        assert_eq!(txn.commit(), Err(StorageError::Interrupted));
+
+        assert_eq!(value_of(&store, b"cfg.a").as_deref(), Some(&b"old-a"[..]));
+        assert_eq!(value_of(&store, b"cfg.b").as_deref(), Some(&b"old-b"[..]));
+    }
+
+    #[test]
+    fn transaction_failing_op_applies_nothing() {
+        let mut store = seeded_store();
+
+        let mut txn = Transaction::new(&mut store);
+        txn.put(b"cfg.a", b"new-a").expect("stage failed");
+        txn.delete(b"cfg.missing").expect("stage failed");
+        assert_eq!(txn.commit(), Err(StorageError::NotFound));
+
+        assert_eq!(value_of(&store, b"cfg.a").as_deref(), Some(&b"old-a"[..]));
+    }
+
+    #[test]
+    fn transaction_staging_limits() {
+        let mut store = seeded_store();
+        let long_value = [0u8; MAX_VALUE_LEN + 1];
+
+        let mut txn = Transaction::new(&mut store);
+        assert_eq!(txn.put(b"cfg.a", &long_value), Err(StorageError::TooLarge));
+        for _ in 0..MAX_TXN_OPS {
+            txn.put(b"cfg.a", b"v").expect("stage failed");
+        }
+        assert_eq!(txn.delete(b"cfg.b"), Err(StorageError::TransactionFull));
+    }
+
+    #[test]
+    fn counter_increment() {
+        let mut store = MockKvStore::<4>::new();
+        let id = CounterId(1);
+
+        assert_eq!(store.read_counter(id), Ok(0));
+        assert_eq!(store.increment_counter(id, 1), Ok(1));
+        assert_eq!(store.increment_counter(id, 5), Ok(6));
+        assert_eq!(store.read_counter(id), Ok(6));
+        // Counters are independent.
+        assert_eq!(store.read_counter(CounterId(0)), Ok(0));
+    }
+
+    #[test]
+    fn counter_survives_reset() {
+        let mut store = MockKvStore::<4>::new();
+        let id = CounterId(0);
+        store.put(b"fw.version", b"7").expect("put failed");
+        store.advance_counter_to(id, 7).expect("advance failed");
+
+        store.reset();
+
+        assert_eq!(store.read_counter(id), Ok(7));
+        assert!(store.contains(b"fw.version"));
+    }
+
+    #[test]
+    fn counter_rejects_decrement() {
+        let mut store = MockKvStore::<4>::new();
+        let id = CounterId(2);
+        store.advance_counter_to(id, 10).expect("advance failed");
+
+        assert_eq!(store.advance_counter_to(id, 9), Err(StorageError::Rollback));
+        assert_eq!(store.advance_counter_to(id, 10), Ok(10));
+        assert_eq!(store.read_counter(id), Ok(10));
+    }
+
+    #[test]
+    fn counter_rejects_wraparound() {
+        let mut store = MockKvStore::<4>::new();
+        let id = CounterId(3);
+        store
+            .advance_counter_to(id, u64::MAX - 1)
+            .expect("advance failed");
+
+        assert_eq!(
+            store.increment_counter(id, 2),
+            Err(StorageError::CounterOverflow)
+        );
+        assert_eq!(store.increment_counter(id, 1), Ok(u64::MAX));
+        assert_eq!(
+            store.increment_counter(id, 1),
+            Err(StorageError::CounterOverflow)
+        );
+        assert_eq!(store.read_counter(id), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn counter_unknown_id() {
+        let mut store = MockKvStore::<4>::new();
+        let id = CounterId(MAX_COUNTERS as u8);
+
+        assert_eq!(store.read_counter(id), Err(StorageError::NotFound));
+        assert_eq!(store.increment_counter(id, 1), Err(StorageError::NotFound));
+    }
+
+    #[test]
+    fn clean_read_passes_crc() {
+        let mut store = MockKvStore::<4>::new();
+        store.put(b"cfg.a", b"value").expect("put failed");
+
+        assert_eq!(value_of(&store, b"cfg.a").as_deref(), Some(&b"value"[..]));
+    }
+
+    #[test]
+    fn corrupted_read_detected() {
+        let mut store = MockKvStore::<4>::new();
+        store.put(b"cfg.a", b"value").expect("put failed");
+        store.put(b"cfg.b", b"other").expect("put failed");
+        store.corrupt_record(b"cfg.a", 2).expect("corrupt failed");
+
+        let mut buf = [0u8; 8];
+        assert_eq!(store.get(b"cfg.a", &mut buf), Err(StorageError::Corrupt));
+        // Only the damaged record is affected.
+        assert_eq!(value_of(&store, b"cfg.b").as_deref(), Some(&b"other"[..]));
+
+        // Rewriting the record restores it.
+        store.put(b"cfg.a", b"value").expect("put failed");
+        assert_eq!(value_of(&store, b"cfg.a").as_deref(), Some(&b"value"[..]));
+
+        assert_eq!(
+            store.corrupt_record(b"cfg.a", 5),
+            Err(StorageError::NotFound)
+        );
+    }
+
+    #[test]
+    fn keys_yields_stored_set() {
+        let mut store = MockKvStore::<8>::new();
+        assert_eq!(store.keys().count(), 0);
+
+        for key in [&b"cfg.a"[..], b"cfg.b", b"cfg.c", b"state.boot"] {
+            store.put(key, b"v").expect("put failed");
+        }
+        store.delete(b"cfg.b").expect("delete failed");
+        store.put(b"cfg.a", b"updated").expect("put failed");
+
+        let mut keys: Vec<Vec<u8>> = store.keys().map(<[u8]>::to_vec).collect();
+        keys.sort();
+        assert_eq!(keys, [&b"cfg.a"[..], b"cfg.c", b"state.boot"]);
+    }
+
+    #[test]
+    fn namespaces_are_isolated() {
+        let mut store = MockKvStore::<8>::new();
+        store
+            .namespace(NamespaceId::CONFIG)
+            .put(b"version", b"config-v")
+            .expect("put failed");
+        store
+            .namespace(NamespaceId::ATTESTATION)
+            .put(b"version", b"attest-v")
+            .expect("put failed");
+
+        let mut buf = [0u8; 16];
+        let config = store.namespace(NamespaceId::CONFIG);
+        let len = config.get(b"version", &mut buf).expect("get failed");
+        assert_eq!(&buf[..len], b"config-v");
+
+        let attestation = store.namespace(NamespaceId::ATTESTATION);
+        let len = attestation.get(b"version", &mut buf).expect("get failed");
+        assert_eq!(&buf[..len], b"attest-v");
+
+        let mut telemetry = store.namespace(NamespaceId::TELEMETRY);
+        assert!(!telemetry.contains(b"version"));
+        assert_eq!(telemetry.delete(b"version"), Err(StorageError::NotFound));
+        assert_eq!(telemetry.keys().count(), 0);
+
+        let mut config = store.namespace(NamespaceId::CONFIG);
+        config.delete(b"version").expect("delete failed");
+        assert!(store
+            .namespace(NamespaceId::ATTESTATION)
+            .contains(b"version"));
+    }
+
+    #[test]
+    fn namespace_keys_and_limits() {
+        let mut store = MockKvStore::<8>::new();
+        let mut config = store.namespace(NamespaceId::CONFIG);
+        config.put(b"a", b"1").expect("put failed");
+        config.put(b"b", b"2").expect("put failed");
+        store
+            .namespace(NamespaceId::TELEMETRY)
+            .put(b"c", b"3")
+            .expect("put failed");
+
+        let config = store.namespace(NamespaceId::CONFIG);
+        let mut keys: Vec<Vec<u8>> = config.keys().map(<[u8]>::to_vec).collect();
+        keys.sort();
+        assert_eq!(keys, [b"a", b"b"]);
+
+        let mut config = store.namespace(NamespaceId::CONFIG);
+        let longest = [b'k'; MAX_NAMESPACED_KEY_LEN];
+        config.put(&longest, b"v").expect("put failed");
+        assert_eq!(
+            config.put(&[b'k'; MAX_NAMESPACED_KEY_LEN + 1], b"v"),
+            Err(StorageError::TooLarge)
+        );
+        assert_eq!(config.put(b"", b"v"), Err(StorageError::InvalidLength));
+    }
+
+    fn log_value(store: &MockLogStore<8>, key: &[u8]) -> Option<Vec<u8>> {
+        let mut buf = [0u8; MAX_VALUE_LEN];
+        let len = store.get(key, &mut buf).ok()?;
+        Some(buf[..len].to_vec())
+    }
+
+    /// Fills an 8-entry log: `a` written three times, `b` written then
+    /// deleted, `c` once, leaving two live records behind six stale entries.
+    fn filled_log() -> MockLogStore<8> {
+        let mut store = MockLogStore::<8>::new();
+        store.put(b"a", b"a1").expect("put failed");
+        store.put(b"b", b"b1").expect("put failed");
+        store.put(b"a", b"a2").expect("put failed");
+        store.put(b"c", b"c1").expect("put failed");
+        store.delete(b"b").expect("delete failed");
+        store.put(b"a", b"a3").expect("put failed");
+        store.put(b"c", b"c2").expect("put failed");
+        store.put(b"a", b"a4").expect("put failed");
+        store
+    }
+
+    #[test]
+    fn log_fill_compact_verify() {
+        let mut store = filled_log();
+        assert_eq!(store.log_free(), 0);
+        assert_eq!(store.put(b"d", b"d1"), Err(StorageError::Full));
+        assert_eq!(store.len(), 2);
+
+        assert_eq!(store.compact(), Ok(6));
+        assert_eq!(store.log_used(), 2);
+        assert_eq!(store.log_free(), 6);
+
+        assert_eq!(log_value(&store, b"a").as_deref(), Some(&b"a4"[..]));
+        assert_eq!(log_value(&store, b"c").as_deref(), Some(&b"c2"[..]));
+        assert!(!store.contains(b"b"));
+
+        // The reclaimed space is usable again.
+        store.put(b"d", b"d1").expect("put failed");
+        assert_eq!(log_value(&store, b"d").as_deref(), Some(&b"d1"[..]));
+
+        let mut keys: Vec<Vec<u8>> = store.keys().map(<[u8]>::to_vec).collect();
+        keys.sort();
+        assert_eq!(keys, [b"a", b"c", b"d"]);
+    }
+
+    #[test]
+    fn log_compact_on_clean_log_reclaims_nothing() {
+        let mut store = MockLogStore::<8>::new();
+        assert_eq!(store.compact(), Ok(0));
+        store.put(b"a", b"a1").expect("put failed");
+        assert_eq!(store.compact(), Ok(0));
+        assert_eq!(log_value(&store, b"a").as_deref(), Some(&b"a1"[..]));
+    }
+
+    #[test]
+    fn log_interrupted_compaction_keeps_data() {
+        let mut store = filled_log();
+        store.interrupt_compaction_after(1);
+
+        assert_eq!(store.compact(), Err(StorageError::Interrupted));
+        store.reset();
+
+        assert_eq!(store.log_used(), 8);
+        assert_eq!(log_value(&store, b"a").as_deref(), Some(&b"a4"[..]));
+        assert_eq!(log_value(&store, b"c").as_deref(), Some(&b"c2"[..]));
+        assert!(!store.contains(b"b"));
+
+        assert_eq!(store.compact(), Ok(6));
+        assert_eq!(log_value(&store, b"a").as_deref(), Some(&b"a4"[..]));
+        assert_eq!(log_value(&store, b"c").as_deref(), Some(&b"c2"[..]));
+    }
+
+    #[test]
+    fn log_compaction_interrupted_after_last_record_keeps_data() {
+        let mut store = filled_log();
+        store.interrupt_compaction_after(2);
+
+        assert_eq!(store.compact(), Err(StorageError::Interrupted));
+        assert_eq!(store.log_used(), 8);
+        assert_eq!(log_value(&store, b"a").as_deref(), Some(&b"a4"[..]));
+        assert_eq!(log_value(&store, b"c").as_deref(), Some(&b"c2"[..]));
+    }
+
+    #[test]
+    fn log_delete_missing_key() {
+        let mut store = MockLogStore::<8>::new();
+        assert_eq!(store.delete(b"missing"), Err(StorageError::NotFound));
+        assert_eq!(store.log_used(), 0);
+    }
+
+    #[test]
+    fn quota_fill_to_capacity() {
+        let mut store = MockKvStore::<8>::with_capacity(24);
+        assert_eq!(
+            (store.capacity(), store.used(), store.available()),
+            (24, 0, 24)
+        );
+
+        store.put(b"cfg.a", &[0; 7]).expect("put failed");
+        store.put(b"cfg.b", &[0; 7]).expect("put failed");
+        assert_eq!((store.used(), store.available()), (24, 0));
+
+        // Free slots remain, but the byte budget is spent.
+        assert_eq!(store.put(b"c", b""), Err(StorageError::Full));
+        assert!(!store.contains(b"c"));
+        assert_eq!(store.used(), 24);
+
+        // Overwriting only costs the difference, and shrinking frees space.
+        assert_eq!(store.put(b"cfg.a", &[0; 8]), Err(StorageError::Full));
+        store.put(b"cfg.a", &[0; 3]).expect("shrink failed");
+        assert_eq!(store.available(), 4);
+        store.delete(b"cfg.b").expect("delete failed");
+        assert_eq!(store.available(), 16);
+    }
+
+    #[test]
+    fn quota_applies_to_transactions_and_namespaces() {
+        let mut store = MockKvStore::<8>::with_capacity(16);
+        store.put(b"a", &[0; 7]).expect("put failed");
+
+        let mut txn = Transaction::new(&mut store);
+        txn.delete(b"a").expect("stage failed");
+        txn.put(b"b", &[0; 15]).expect("stage failed");
+        txn.put(b"c", b"").expect("stage failed");
+        assert_eq!(txn.commit(), Err(StorageError::Full));
+        assert!(store.contains(b"a"));
+        assert_eq!(store.used(), 8);
+
+        // A namespaced key costs its tag byte against the shared budget.
+        let mut config = store.namespace(NamespaceId::CONFIG);
+        assert_eq!(config.available(), 8);
+        config.put(b"k", &[0; 6]).expect("put failed");
+        assert_eq!((config.used(), config.available()), (16, 0));
+        assert_eq!(config.put(b"l", b""), Err(StorageError::Full));
+    }
+
+    impl Aead for MockAead {
+        fn encrypt_in_place(
+            &self,
+            nonce: &[u8; NONCE_LEN],
+            aad: &[u8],
+            data: &mut [u8],
+        ) -> [u8; TAG_LEN] {
+            MockAead::encrypt_in_place(self, nonce, aad, data)
+        }
+
+        fn decrypt_in_place(
+            &self,
+            nonce: &[u8; NONCE_LEN],
+            aad: &[u8],
+            data: &mut [u8],
+            tag: &[u8; TAG_LEN],
+        ) -> Result<(), StorageError> {
+            MockAead::decrypt_in_place(self, nonce, aad, data, tag)
+                .map_err(|_| StorageError::Corrupt)
+        }
+    }
+
+    const SECRET_KEY: [u8; KEY_LEN] = *b"provisioning-key-for-dice-seeds!";
+
+    fn encrypted_store() -> EncryptedStore<MockKvStore<4>, MockAead> {
+        EncryptedStore::new(MockKvStore::new(), MockAead::new(SECRET_KEY), [0, 0, 0, 1])
+    }
+
+    #[test]
+    fn encrypted_round_trip() {
+        let mut store = encrypted_store();
+        store.put(b"dice.seed", b"top secret").expect("put failed");
+        store.put(b"empty", b"").expect("put failed");
+
+        let mut buf = [0u8; 16];
+        let len = store.get(b"dice.seed", &mut buf).expect("get failed");
+        assert_eq!(&buf[..len], b"top secret");
+        assert_eq!(store.get(b"empty", &mut buf), Ok(0));
+
+        // The backend only ever sees ciphertext, prefixed by nonce and tag.
+        let mut raw = [0u8; MAX_VALUE_LEN];
+        let len = store
+            .inner()
+            .get(b"dice.seed", &mut raw)
+            .expect("get failed");
+        assert_eq!(len, NONCE_LEN + TAG_LEN + 10);
+        assert_ne!(&raw[NONCE_LEN + TAG_LEN..len], b"top secret");
+
+        // Rewriting the same value uses a fresh nonce.
+        let mut before = [0u8; MAX_VALUE_LEN];
+        before[..len].copy_from_slice(&raw[..len]);
+        store.put(b"dice.seed", b"top secret").expect("put failed");
+        store
+            .inner()
+            .get(b"dice.seed", &mut raw)
+            .expect("get failed");
+        assert_ne!(raw[..len], before[..len]);
+
+        let mut short = [0u8; 4];
+        assert_eq!(
+            store.get(b"dice.seed", &mut short),
+            Err(StorageError::BufferTooSmall)
+        );
+        assert_eq!(
+            store.put(b"big", &[0; MAX_ENCRYPTED_VALUE_LEN + 1]),
+            Err(StorageError::TooLarge)
+        );
+        store
+            .put(b"big", &[0; MAX_ENCRYPTED_VALUE_LEN])
+            .expect("put failed");
+    }
+
+    #[test]
+    fn encrypted_tamper_detected() {
+        let mut store = encrypted_store();
+        store.put(b"dice.seed", b"top secret").expect("put failed");
+        store.put(b"other", b"intact").expect("put failed");
+
+        // Flip a ciphertext byte, rewriting the record so its CRC still
+        // matches: only the tag can catch this.
+        let mut raw = [0u8; MAX_VALUE_LEN];
+        let len = store
+            .inner()
+            .get(b"dice.seed", &mut raw)
+            .expect("get failed");
+        raw[NONCE_LEN + TAG_LEN + 3] ^= 0x01;
+        store
+            .inner_mut()
+            .put(b"dice.seed", &raw[..len])
+            .expect("put failed");
+
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            store.get(b"dice.seed", &mut buf),
+            Err(StorageError::Corrupt)
+        );
+        let len = store.get(b"other", &mut buf).expect("get failed");
+        assert_eq!(&buf[..len], b"intact");
+
+        // A sealed value moved to another key fails too, as does one
+        // opened with the wrong key.
+        store.inner().get(b"other", &mut raw).expect("get failed");
+        store
+            .inner_mut()
+            .put(b"moved", &raw[..NONCE_LEN + TAG_LEN + 6])
+            .expect("put failed");
+        assert_eq!(store.get(b"moved", &mut buf), Err(StorageError::Corrupt));
+
+        let wrong = EncryptedStore::new(store.into_inner(), MockAead::new([0; KEY_LEN]), [0; 4]);
+        assert_eq!(wrong.get(b"other", &mut buf), Err(StorageError::Corrupt));
+
+        // Truncated records cannot even hold a nonce and tag.
+        let mut backend = wrong.into_inner();
+        backend.put(b"short", &[0; 8]).expect("put failed");
+        let store = EncryptedStore::new(backend, MockAead::new(SECRET_KEY), [0; 4]);
+        assert_eq!(store.get(b"short", &mut buf), Err(StorageError::Corrupt));
+    }
+
+    #[test]
+    fn rewrites_spread_across_slots() {
+        let mut store = MockKvStore::<4>::new();
+        store.put(b"static", b"cold").expect("put failed");
+        for i in 0..99u8 {
+            store.put(b"hot", &[i]).expect("put failed");
+        }
+
+        let counts = store.slot_write_counts();
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts.iter().sum::<u32>(), 100);
+        // The cold record holds its slot; the three others share the
+        // hot key's rewrites evenly instead of one slot taking all 99.
+        assert_eq!(counts.iter().filter(|&&n| n == 1).count(), 1);
+        assert_eq!(counts.iter().filter(|&&n| n == 33).count(), 3);
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(value_of(&store, b"hot").as_deref(), Some(&[98u8][..]));
+        assert_eq!(value_of(&store, b"static").as_deref(), Some(&b"cold"[..]));
+
+        // Wear survives a reset.
+        store.reset();
+        assert_eq!(store.slot_write_counts().iter().sum::<u32>(), 100);
+    }
+
+    #[test]
+    fn full_store() {
+        let mut store = MockKvStore::<2>::new();
+        store.put(b"a", b"1").expect("put failed");
+        store.put(b"b", b"2").expect("put failed");
+        assert_eq!(store.put(b"c", b"3"), Err(StorageError::Full));
+
+        // Overwriting an existing key needs no new slot.
+        store.put(b"a", b"9").expect("overwrite failed");
+    }
+}
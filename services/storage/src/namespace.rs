@@ -0,0 +1,103 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-service keyspaces over one shared [`KvStore`].
+
+use crate::kv::check_lengths;
+use crate::{KvStore, StorageError, MAX_KEY_LEN};
+
+/// Longest key, in bytes, accepted through a [`NamespaceHandle`].
+///
+/// One byte of every stored key is taken by the namespace tag.
+pub const MAX_NAMESPACED_KEY_LEN: usize = MAX_KEY_LEN - 1;
+
+/// Identifies one service's keyspace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NamespaceId(pub u8);
+
+impl NamespaceId {
+    /// Attestation service state.
+    pub const ATTESTATION: Self = Self(1);
+    /// Platform configuration.
+    pub const CONFIG: Self = Self(2);
+    /// Telemetry counters and logs.
+    pub const TELEMETRY: Self = Self(3);
+}
+
+/// A [`KvStore`] view confined to one namespace.
+///
+/// Every key is stored with the namespace tag prepended, so byte-identical
+/// keys in different namespaces never collide and [`keys`](KvStore::keys)
+/// only yields the handle's own keys, with the tag stripped. A store shared
+/// this way should only be accessed through handles; a raw key whose first
+/// byte happens to equal a tag would appear inside that namespace.
+///
+/// Create one with [`KvStore::namespace`].
+pub struct NamespaceHandle<'s, S: KvStore> {
+    store: &'s mut S,
+    ns: NamespaceId,
+}
+
+impl<'s, S: KvStore> NamespaceHandle<'s, S> {
+    pub(crate) fn new(store: &'s mut S, ns: NamespaceId) -> Self {
+        Self { store, ns }
+    }
+
+    /// The namespace this handle is confined to.
+    pub fn id(&self) -> NamespaceId {
+        self.ns
+    }
+
+    /// The shared store behind this handle.
+    pub(crate) fn store(&self) -> &S {
+        self.store
+    }
+
+    /// Builds the stored form of `key` in `buf`.
+    fn full_key<'b>(
+        &self,
+        key: &[u8],
+        buf: &'b mut [u8; MAX_KEY_LEN],
+    ) -> Result<&'b [u8], StorageError> {
+        if key.len() > MAX_NAMESPACED_KEY_LEN {
+            return Err(StorageError::TooLarge);
+        }
+        check_lengths(key, &[])?;
+        buf[0] = self.ns.0;
+        buf[1..=key.len()].copy_from_slice(key);
+        Ok(&buf[..=key.len()])
+    }
+}
+
+impl<S: KvStore> KvStore for NamespaceHandle<'_, S> {
+    fn get(&self, key: &[u8], buf: &mut [u8]) -> Result<usize, StorageError> {
+        let mut full = [0; MAX_KEY_LEN];
+        self.store.get(self.full_key(key, &mut full)?, buf)
+    }
+
+    fn put(&mut self, key: &[u8], value: &[u8]) -> Result<(), StorageError> {
+        let mut full = [0; MAX_KEY_LEN];
+        let full = self.full_key(key, &mut full)?;
+        self.store.put(full, value)
+    }
+
+    fn delete(&mut self, key: &[u8]) -> Result<(), StorageError> {
+        let mut full = [0; MAX_KEY_LEN];
+        let full = self.full_key(key, &mut full)?;
+        self.store.delete(full)
+    }
+
+    fn contains(&self, key: &[u8]) -> bool {
+        let mut full = [0; MAX_KEY_LEN];
+        self.full_key(key, &mut full)
+            .is_ok_and(|full| self.store.contains(full))
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &[u8]> {
+        self.store
+            .keys()
+            .filter_map(|key| key.split_first())
+            .filter(|&(&tag, key)| tag == self.ns.0 && !key.is_empty())
+            .map(|(_, key)| key)
+    }
+}
@@ -0,0 +1,38 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Capacity accounting for stores with a fixed byte budget: [`StorageQuota`].
+
+use crate::{KvStore, NamespaceHandle};
+
+/// A [`KvStore`] that reports how much of its byte budget is spent.
+///
+/// A record costs the length of its key plus the length of its value.
+/// Once a `put` would take [`used`](Self::used) past
+/// [`capacity`](Self::capacity) it fails with
+/// [`StorageError::Full`](crate::StorageError::Full) and the store is left
+/// unchanged; overwriting a key only costs the difference in value length.
+pub trait StorageQuota: KvStore {
+    /// Total number of bytes the store can hold.
+    fn capacity(&self) -> usize;
+
+    /// Number of bytes taken by the records currently stored.
+    fn used(&self) -> usize;
+
+    /// Number of bytes left for new records.
+    fn available(&self) -> usize {
+        self.capacity().saturating_sub(self.used())
+    }
+}
+
+/// A namespace shares its store's budget: the figures are those of the
+/// whole store, including the namespace tag byte of every stored key.
+impl<S: StorageQuota> StorageQuota for NamespaceHandle<'_, S> {
+    fn capacity(&self) -> usize {
+        self.store().capacity()
+    }
+
+    fn used(&self) -> usize {
+        self.store().used()
+    }
+}
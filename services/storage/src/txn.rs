@@ -0,0 +1,104 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Atomic multi-record updates: [`Transaction`] and [`AtomicKvStore`].
+
+use crate::kv::check_lengths;
+use crate::{KvStore, StorageError};
+
+/// Most operations one [`Transaction`] can stage.
+pub const MAX_TXN_OPS: usize = 8;
+
+/// One staged operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxnOp<'d> {
+    /// Store `value` under `key`.
+    Put { key: &'d [u8], value: &'d [u8] },
+    /// Remove the value stored under `key`.
+    Delete { key: &'d [u8] },
+}
+
+/// A [`KvStore`] that can apply a batch of operations atomically.
+pub trait AtomicKvStore: KvStore {
+    /// Applies every operation in `ops`, in order, or none of them.
+    ///
+    /// If any operation fails (including a `Delete` of a missing key), or
+    /// power is lost part-way, the store is left exactly as it was before
+    /// the call.
+    fn apply_atomic(&mut self, ops: &[TxnOp<'_>]) -> Result<(), StorageError>;
+}
+
+/// A batch of `put`/`delete` operations committed all-or-nothing.
+///
+/// Staged operations are invisible until [`commit`](Self::commit); the
+/// transaction holds the store mutably, so nothing else can write to it in
+/// the meantime. Dropping the transaction, or calling
+/// [`abort`](Self::abort), discards everything staged.
+///
+/// ```ignore
+/// let mut txn = Transaction::new(&mut store);
+/// txn.put(b"fw.version", b"1.2")?;
+/// txn.put(b"fw.digest", &digest)?;
+/// txn.delete(b"fw.pending")?;
+/// txn.commit()?;   // all three land, or none do
+/// ```
+pub struct Transaction<'s, 'd, S: AtomicKvStore> {
+    store: &'s mut S,
+    /// Staged operations; only the first `len` are meaningful.
+    ops: [TxnOp<'d>; MAX_TXN_OPS],
+    len: usize,
+}
+
+impl<'s, 'd, S: AtomicKvStore> Transaction<'s, 'd, S> {
+    /// Starts an empty transaction against `store`.
+    pub fn new(store: &'s mut S) -> Self {
+        Self {
+            store,
+            ops: [TxnOp::Delete { key: &[] }; MAX_TXN_OPS],
+            len: 0,
+        }
+    }
+
+    /// Stages storing `value` under `key`.
+    ///
+    /// Lengths are checked now, so a bad record fails here rather than at
+    /// commit.
+    pub fn put(&mut self, key: &'d [u8], value: &'d [u8]) -> Result<(), StorageError> {
+        check_lengths(key, value)?;
+        self.stage(TxnOp::Put { key, value })
+    }
+
+    /// Stages removing the value stored under `key`.
+    pub fn delete(&mut self, key: &'d [u8]) -> Result<(), StorageError> {
+        check_lengths(key, &[])?;
+        self.stage(TxnOp::Delete { key })
+    }
+
+    /// Number of staged operations.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing is staged.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Applies every staged operation atomically.
+    pub fn commit(self) -> Result<(), StorageError> {
+        self.store.apply_atomic(&self.ops[..self.len])
+    }
+
+    /// Discards every staged operation. The store is untouched.
+    pub fn abort(self) {}
+
+    fn stage(&mut self, op: TxnOp<'d>) -> Result<(), StorageError> {
+        let slot = self
+            .ops
+            .get_mut(self.len)
+            .ok_or(StorageError::TransactionFull)?;
+        *slot = op;
+        self.len += 1;
+        Ok(())
+    }
+}
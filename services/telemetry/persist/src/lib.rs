@@ -0,0 +1,368 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Telemetry counters persisted across resets.
+//!
+//! [`flush`] saves every counter series of a [`MetricsRegistry`] to an
+//! [`AtomicKvStore`]; the platform calls it on its shutdown path, and may
+//! call it periodically so an unexpected reset loses little. [`restore`]
+//! adds the saved counts back once at boot, so cumulative counters such as
+//! packets since manufacture keep counting. Gauges describe the present and
+//! histograms cannot be rebuilt through their API, so only counters, plain
+//! and labeled, are kept.
+//!
+//! # Crash safety
+//!
+//! A flush rewrites every record it owns in one [`Transaction`], so a
+//! power loss or crash part-way leaves the previous flush intact.
+//! [`flush`] never panics and never allocates, so it can also run from a
+//! panic handler.
+//!
+//! # Layout
+//!
+//! The counters are serialized into an image of at most [`MAX_IMAGE_LEN`]
+//! bytes, split across the records `metrics.0` to `metrics.6`, and the
+//! record `metrics` holds the header. All integers are little-endian.
+//!
+//! ```text
+//! header: version: u8 (= 1)   chunks: u8   image_len: u16
+//! image:  { name_len: u8   name   label_len: u8   label   count: u64 }*
+//! ```
+//!
+//! `label_len` is the length of the label value plus one, or zero for a
+//! plain counter.
+
+#![cfg_attr(not(test), no_std)]
+
+use storage::{AtomicKvStore, KvStore, StorageError, Transaction, MAX_TXN_OPS, MAX_VALUE_LEN};
+use telemetry::{MetricValue, MetricsRegistry};
+
+/// Most records a counter image is split across: one operation of the
+/// flush transaction is left for the header.
+pub const MAX_CHUNKS: usize = MAX_TXN_OPS - 1;
+
+/// Largest counter image, in bytes, [`flush`] can save.
+pub const MAX_IMAGE_LEN: usize = MAX_CHUNKS * MAX_VALUE_LEN;
+
+/// Version byte leading the header.
+const FORMAT_VERSION: u8 = 1;
+
+/// Size of the header record (version, chunks, image length).
+const HEADER_LEN: usize = 4;
+
+const HEADER_KEY: &[u8] = b"metrics";
+
+const CHUNK_KEYS: [&[u8]; MAX_CHUNKS] = [
+    b"metrics.0",
+    b"metrics.1",
+    b"metrics.2",
+    b"metrics.3",
+    b"metrics.4",
+    b"metrics.5",
+    b"metrics.6",
+];
+
+/// Saves every counter series of `registry` to `store`, replacing the
+/// previous flush all-or-nothing.
+///
+/// Returns [`StorageError::TooLarge`] if the counters take more than
+/// [`MAX_IMAGE_LEN`] bytes, and otherwise any error of the commit; on error
+/// the previous flush is left as it was.
+pub fn flush<S: AtomicKvStore, const N: usize>(
+    registry: &MetricsRegistry<N>,
+    store: &mut S,
+) -> Result<(), StorageError> {
+    let mut image = [0; MAX_IMAGE_LEN];
+    let len = encode(registry, &mut image)?;
+    let chunks = len.div_ceil(MAX_VALUE_LEN);
+    // The image fits MAX_CHUNKS records, far below u8 and u16.
+    let [lo, hi] = (len as u16).to_le_bytes();
+    let header = [FORMAT_VERSION, chunks as u8, lo, hi];
+    let stale: [bool; MAX_CHUNKS] =
+        core::array::from_fn(|i| i >= chunks && store.contains(CHUNK_KEYS[i]));
+
+    let mut txn = Transaction::new(store);
+    for (key, chunk) in CHUNK_KEYS.iter().zip(image[..len].chunks(MAX_VALUE_LEN)) {
+        txn.put(key, chunk)?;
+    }
+    for (key, _) in CHUNK_KEYS.iter().zip(stale).filter(|(_, stale)| *stale) {
+        txn.delete(key)?;
+    }
+    txn.put(HEADER_KEY, &header)?;
+    txn.commit()
+}
+
+/// Adds the counts saved by the last [`flush`] to the counters of
+/// `registry`, and returns how many series were restored.
+///
+/// Call it once at boot, after every metric is registered. Saved series
+/// that are no longer registered are skipped, and a store that was never
+/// flushed restores nothing. Fails with [`StorageError::Corrupt`], without
+/// touching any counter, if the saved image is inconsistent.
+pub fn restore<S: KvStore, const N: usize>(
+    registry: &MetricsRegistry<N>,
+    store: &S,
+) -> Result<usize, StorageError> {
+    let mut header = [0; HEADER_LEN];
+    let header_len = match store.get(HEADER_KEY, &mut header) {
+        Err(StorageError::NotFound) => return Ok(0),
+        result => result?,
+    };
+    let (HEADER_LEN, [FORMAT_VERSION, chunks, lo, hi]) = (header_len, header) else {
+        return Err(StorageError::Corrupt);
+    };
+    let len = usize::from(u16::from_le_bytes([lo, hi]));
+    if len > MAX_IMAGE_LEN || usize::from(chunks) != len.div_ceil(MAX_VALUE_LEN) {
+        return Err(StorageError::Corrupt);
+    }
+
+    let mut image = [0; MAX_IMAGE_LEN];
+    for (key, chunk) in CHUNK_KEYS
+        .iter()
+        .zip(image[..len].chunks_mut(MAX_VALUE_LEN))
+    {
+        if store.get(key, chunk)? != chunk.len() {
+            return Err(StorageError::Corrupt);
+        }
+    }
+    let image = &image[..len];
+
+    // Check the whole image before adding anything.
+    for entry in Entries(image) {
+        entry?;
+    }
+    let mut restored = 0;
+    for (name, label, count) in Entries(image).flatten() {
+        if let Some(counter) = registry.find_counter(name, label) {
+            counter.add(count);
+            restored += 1;
+        }
+    }
+    Ok(restored)
+}
+
+/// Serializes the counter series of `registry` into `buf` and returns the
+/// image length.
+fn encode<const N: usize>(
+    registry: &MetricsRegistry<N>,
+    buf: &mut [u8],
+) -> Result<usize, StorageError> {
+    let mut len = 0;
+    let mut put = |bytes: &[u8]| {
+        let dst = buf
+            .get_mut(len..len + bytes.len())
+            .ok_or(StorageError::TooLarge)?;
+        dst.copy_from_slice(bytes);
+        len += bytes.len();
+        Ok(())
+    };
+    for sample in registry.snapshot().iter() {
+        let MetricValue::Counter(count) = sample.value else {
+            continue;
+        };
+        // Registration bounds names and labels far below u8::MAX.
+        put(&[sample.name.len() as u8])?;
+        put(sample.name.as_bytes())?;
+        match sample.label {
+            None => put(&[0])?,
+            Some(label) => {
+                put(&[label.value.len() as u8 + 1])?;
+                put(label.value.as_bytes())?;
+            }
+        }
+        put(&count.to_le_bytes())?;
+    }
+    Ok(len)
+}
+
+/// The `(name, label, count)` entries of a counter image.
+///
+/// Yields [`StorageError::Corrupt`] once, then stops, if the image is
+/// truncated or invalid.
+struct Entries<'a>(&'a [u8]);
+
+impl<'a> Entries<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let (head, rest) = self.0.split_at_checked(len)?;
+        self.0 = rest;
+        Some(head)
+    }
+
+    fn take_str(&mut self, len: usize) -> Option<&'a str> {
+        core::str::from_utf8(self.take(len)?).ok()
+    }
+
+    fn entry(&mut self) -> Option<(&'a str, Option<&'a str>, u64)> {
+        let (&name_len, rest) = self.0.split_first()?;
+        self.0 = rest;
+        let name = self.take_str(usize::from(name_len))?;
+        let (&label_len, rest) = self.0.split_first()?;
+        self.0 = rest;
+        let label = match label_len {
+            0 => None,
+            len => Some(self.take_str(usize::from(len) - 1)?),
+        };
+        let count = u64::from_le_bytes(self.take(8)?.try_into().ok()?);
+        Some((name, label, count))
+    }
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = Result<(&'a str, Option<&'a str>, u64), StorageError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.is_empty() {
+            return None;
+        }
+        match self.entry() {
+            Some(entry) => Some(Ok(entry)),
+            None => {
+                self.0 = &[];
+                Some(Err(StorageError::Corrupt))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use storage::mock::MockKvStore;
+
+    /// Registers the metrics a service would at boot.
+    fn registry() -> MetricsRegistry<6> {
+        let mut registry = MetricsRegistry::new();
+        registry.register_counter("mctp.rx_packets").unwrap();
+        registry.register_gauge("mctp.queue_depth").unwrap();
+        registry
+            .register_labeled_counter("mctp.drops", "reason", ["bootstrap", "foreign_eid"])
+            .unwrap();
+        registry
+    }
+
+    fn counts(registry: &MetricsRegistry<6>) -> [u64; 3] {
+        let get = |name, label| registry.find_counter(name, label).unwrap().get();
+        [
+            get("mctp.rx_packets", None),
+            get("mctp.drops", Some("bootstrap")),
+            get("mctp.drops", Some("foreign_eid")),
+        ]
+    }
+
+    fn record(registry: &MetricsRegistry<6>, rx: u64, foreign: u64) {
+        registry
+            .find_counter("mctp.rx_packets", None)
+            .unwrap()
+            .add(rx);
+        registry
+            .find_counter("mctp.drops", Some("foreign_eid"))
+            .unwrap()
+            .add(foreign);
+    }
+
+    #[test]
+    fn counters_survive_reset() {
+        let mut store = MockKvStore::<16>::new();
+        let before = registry();
+        record(&before, 1234, 5);
+        before
+            .find_counter("mctp.drops", Some("bootstrap"))
+            .unwrap()
+            .inc();
+        flush(&before, &mut store).unwrap();
+
+        store.reset();
+        let after = registry();
+        after.find_counter("mctp.rx_packets", None).unwrap().inc();
+        assert_eq!(restore(&after, &store), Ok(3));
+        assert_eq!(counts(&after), [1235, 1, 5]);
+        assert_eq!(
+            after.snapshot().get("mctp.queue_depth"),
+            Some(MetricValue::Gauge(0))
+        );
+    }
+
+    #[test]
+    fn never_flushed_restores_nothing() {
+        let store = MockKvStore::<16>::new();
+        let registry = registry();
+        assert_eq!(restore(&registry, &store), Ok(0));
+        assert_eq!(counts(&registry), [0, 0, 0]);
+    }
+
+    #[test]
+    fn interrupted_flush_keeps_previous() {
+        let mut store = MockKvStore::<16>::new();
+        let registry = registry();
+        record(&registry, 10, 1);
+        flush(&registry, &mut store).unwrap();
+
+        record(&registry, 90, 1);
+        store.interrupt_commit_after(1);
+        assert_eq!(flush(&registry, &mut store), Err(StorageError::Interrupted));
+
+        store.reset();
+        let rebooted = self::registry();
+        restore(&rebooted, &store).unwrap();
+        assert_eq!(counts(&rebooted), [10, 0, 1]);
+    }
+
+    #[test]
+    fn smaller_flush_deletes_stale_chunks() {
+        const NAMES: [&str; 8] = [
+            "counter.0.with.a.long.name.abcde",
+            "counter.1.with.a.long.name.abcde",
+            "counter.2.with.a.long.name.abcde",
+            "counter.3.with.a.long.name.abcde",
+            "counter.4.with.a.long.name.abcde",
+            "counter.5.with.a.long.name.abcde",
+            "counter.6.with.a.long.name.abcde",
+            "counter.7.with.a.long.name.abcde",
+        ];
+        let mut store = MockKvStore::<16>::new();
+        let mut large = MetricsRegistry::<8>::new();
+        for name in NAMES {
+            large.register_counter(name).unwrap();
+        }
+        flush(&large, &mut store).unwrap();
+        assert!(store.contains(b"metrics.1"));
+
+        let mut small = MetricsRegistry::<1>::new();
+        let id = small.register_counter(NAMES[7]).unwrap();
+        small.counter(id).add(9);
+        flush(&small, &mut store).unwrap();
+        assert!(store.contains(b"metrics.0"));
+        assert!(!store.contains(b"metrics.1"));
+        assert_eq!(restore(&large, &store), Ok(1));
+        assert_eq!(large.find_counter(NAMES[7], None).unwrap().get(), 9);
+    }
+
+    #[test]
+    fn unregistered_series_are_skipped() {
+        let mut store = MockKvStore::<16>::new();
+        let old = registry();
+        record(&old, 7, 2);
+        flush(&old, &mut store).unwrap();
+
+        let mut new = MetricsRegistry::<2>::new();
+        let rx = new.register_counter("mctp.rx_packets").unwrap();
+        new.register_counter("spdm.sessions").unwrap();
+        assert_eq!(restore(&new, &store), Ok(1));
+        assert_eq!(new.counter(rx).get(), 7);
+    }
+
+    #[test]
+    fn corrupt_image_restores_nothing() {
+        let mut store = MockKvStore::<16>::new();
+        let registry = registry();
+        record(&registry, 3, 4);
+        flush(&registry, &mut store).unwrap();
+        let mut chunk = [0; MAX_VALUE_LEN];
+        let len = store.get(b"metrics.0", &mut chunk).unwrap();
+        store.put(b"metrics.0", &chunk[..len - 1]).unwrap();
+
+        let rebooted = self::registry();
+        assert_eq!(restore(&rebooted, &store), Err(StorageError::Corrupt));
+        assert_eq!(counts(&rebooted), [0, 0, 0]);
+    }
+}
@@ -0,0 +1,30 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Timestamp sources: [`Clock`].
+
+/// A monotonic millisecond time source.
+///
+/// Timestamps use the same `now_millis` timebase the MCTP server is driven
+/// with (see `Server::new` and `Server::update`), so log entries, snapshots
+/// and transport timeouts line up.
+pub trait Clock {
+    /// Milliseconds since an arbitrary, fixed epoch such as boot.
+    fn now_millis(&self) -> u64;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now_millis(&self) -> u64 {
+        (**self).now_millis()
+    }
+}
+
+/// A [`Clock`] that always reads zero, for builds with no time source.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoClock;
+
+impl Clock for NoClock {
+    fn now_millis(&self) -> u64 {
+        0
+    }
+}
@@ -0,0 +1,42 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Telemetry error vocabulary.
+
+/// Why a telemetry operation failed.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelemetryError {
+    /// The registry has no free metric slot.
+    Full,
+    /// A metric with this name is already registered.
+    DuplicateName,
+    /// Histogram bucket bounds are not strictly ascending.
+    InvalidBounds,
+    /// The metric name is longer than
+    /// [`MAX_METRIC_NAME_LEN`](crate::MAX_METRIC_NAME_LEN).
+    NameTooLong,
+    /// The output buffer cannot hold the encoding.
+    BufferTooSmall,
+    /// The snapshot holds more series than the encoding can count
+    /// ([`MAX_ENCODED_SERIES`](crate::export::MAX_ENCODED_SERIES)).
+    TooManySeries,
+    /// Encoded telemetry is truncated or invalid.
+    Malformed,
+}
+
+impl core::fmt::Display for TelemetryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Full => f.write_str("metrics registry full"),
+            Self::DuplicateName => f.write_str("duplicate metric name"),
+            Self::InvalidBounds => f.write_str("histogram bounds not ascending"),
+            Self::NameTooLong => f.write_str("metric name too long"),
+            Self::BufferTooSmall => f.write_str("buffer too small"),
+            Self::TooManySeries => f.write_str("too many series to encode"),
+            Self::Malformed => f.write_str("malformed telemetry encoding"),
+        }
+    }
+}
+
+impl core::error::Error for TelemetryError {}
@@ -0,0 +1,468 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Snapshot export over MCTP.
+//!
+//! [`encode`] serializes a [`Snapshot`] into a compact, self-describing byte
+//! format and [`Decoder`] reads it back. [`handle_request`] wraps the
+//! encoding in a small request/response protocol so a host BMC can pull the
+//! metrics over the bus. [`serve_once`] runs it on an MCTP listener: the
+//! platform opens one for [`TELEMETRY_MSG_TYPE`] on its `Stack` and calls
+//! [`serve_once`] for each request, which answers with the request's EID
+//! and tag.
+//!
+//! # Encoding
+//!
+//! All integers are little-endian.
+//!
+//! ```text
+//! version: u8 (= FORMAT_VERSION)   count: u8
+//! count × { kind: u8   name_len: u8   name: [u8; name_len]   value }
+//! ```
+//!
+//! `value` is a `u64` for [`kind::COUNTER`], an `i64` for [`kind::GAUGE`],
+//! and for [`kind::HISTOGRAM`] the bucket bounds (`u64` each), bucket counts
+//! (`u32` each), the overflow count (`u32`) and the sum (`u64`). A series of
+//! a [`LabeledCounter`](crate::LabeledCounter) is encoded as a counter named
+//! `name{key="value"}`. Metric names are at most [`MAX_METRIC_NAME_LEN`]
+//! bytes and labels at most [`MAX_LABEL_LEN`] each, so a snapshot of `n`
+//! series never needs more than [`max_encoded_len`]`(n)` bytes. `count` is
+//! a single byte, so at most [`MAX_ENCODED_SERIES`] series are encoded.
+
+use openprot_mctp_api::{MctpError, MctpListener, MctpRespChannel};
+
+use crate::{
+    HistogramSnapshot, MetricValue, Snapshot, TelemetryError, HISTOGRAM_BUCKETS, MAX_LABEL_LEN,
+    MAX_METRIC_NAME_LEN,
+};
+
+/// MCTP message type the telemetry responder is served on (vendor-defined,
+/// IANA).
+///
+/// Each payload starts with the vendor's 4-byte IANA enterprise number,
+/// most significant byte first. The responder only answers requests
+/// carrying its configured number, and echoes it back.
+pub const TELEMETRY_MSG_TYPE: u8 = 0x7F;
+
+/// Version byte leading every encoded snapshot.
+pub const FORMAT_VERSION: u8 = 1;
+
+/// Most series one encoded snapshot can hold.
+pub const MAX_ENCODED_SERIES: usize = u8::MAX as usize;
+
+/// Metric kind tags.
+pub mod kind {
+    /// A counter; the value is a `u64`.
+    pub const COUNTER: u8 = 1;
+    /// A gauge; the value is an `i64`.
+    pub const GAUGE: u8 = 2;
+    /// A histogram; the value is bounds, buckets, overflow and sum.
+    pub const HISTOGRAM: u8 = 3;
+}
+
+/// Telemetry request command codes.
+pub mod command {
+    /// Read every registered metric.
+    pub const GET_SNAPSHOT: u8 = 0x01;
+}
+
+/// Telemetry response status codes.
+pub mod status {
+    /// The request succeeded; the encoded snapshot follows.
+    pub const SUCCESS: u8 = 0x00;
+    /// The command code is not supported.
+    pub const ERROR_UNSUPPORTED_CMD: u8 = 0x01;
+    /// The snapshot does not fit in the response buffer.
+    pub const ERROR_TOO_LARGE: u8 = 0x02;
+}
+
+/// Size of the vendor (IANA enterprise number) header.
+const VENDOR_HEADER_SIZE: usize = 4;
+
+/// Size of the snapshot header (version, count).
+const SNAPSHOT_HEADER_SIZE: usize = 2;
+
+/// Encoded size of a histogram value.
+const HISTOGRAM_VALUE_SIZE: usize = HISTOGRAM_BUCKETS * (8 + 4) + 4 + 8;
+
+/// Longest encoded series name: a metric name with a `{key="value"}` label.
+const MAX_SERIES_NAME_LEN: usize = MAX_METRIC_NAME_LEN + 2 * MAX_LABEL_LEN + 5;
+
+/// Upper bound on the encoded size of a snapshot holding `metrics` series.
+pub const fn max_encoded_len(metrics: usize) -> usize {
+    SNAPSHOT_HEADER_SIZE + metrics * (2 + MAX_SERIES_NAME_LEN + HISTOGRAM_VALUE_SIZE)
+}
+
+/// Serializes `snapshot` into `buf` and returns the encoded length.
+///
+/// Returns [`TelemetryError::BufferTooSmall`] if `buf` cannot hold it; a
+/// buffer of [`max_encoded_len`]`(N)` bytes always can. Returns
+/// [`TelemetryError::TooManySeries`] if the snapshot holds more than
+/// [`MAX_ENCODED_SERIES`] series.
+pub fn encode<const N: usize>(
+    snapshot: &Snapshot<N>,
+    buf: &mut [u8],
+) -> Result<usize, TelemetryError> {
+    let mut out = Writer { buf, len: 0 };
+    let count = u8::try_from(snapshot.len()).map_err(|_| TelemetryError::TooManySeries)?;
+    out.put(&[FORMAT_VERSION, count])?;
+    for sample in snapshot.iter() {
+        let name = sample.name.as_bytes();
+        let tag = match sample.value {
+            MetricValue::Counter(_) => kind::COUNTER,
+            MetricValue::Gauge(_) => kind::GAUGE,
+            MetricValue::Histogram(_) => kind::HISTOGRAM,
+        };
+        // Registration bounds names to MAX_METRIC_NAME_LEN and labels to
+        // MAX_LABEL_LEN, so the series name fits MAX_SERIES_NAME_LEN.
+        match sample.label {
+            None => {
+                out.put(&[tag, name.len() as u8])?;
+                out.put(name)?;
+            }
+            Some(label) => {
+                let len = name.len() + label.key.len() + label.value.len() + 5;
+                out.put(&[tag, len as u8])?;
+                out.put(name)?;
+                out.put(b"{")?;
+                out.put(label.key.as_bytes())?;
+                out.put(b"=\"")?;
+                out.put(label.value.as_bytes())?;
+                out.put(b"\"}")?;
+            }
+        }
+        match sample.value {
+            MetricValue::Counter(value) => out.put(&value.to_le_bytes())?,
+            MetricValue::Gauge(value) => out.put(&value.to_le_bytes())?,
+            MetricValue::Histogram(histogram) => {
+                for bound in histogram.bounds {
+                    out.put(&bound.to_le_bytes())?;
+                }
+                for count in histogram.buckets {
+                    out.put(&count.to_le_bytes())?;
+                }
+                out.put(&histogram.overflow.to_le_bytes())?;
+                out.put(&histogram.sum.to_le_bytes())?;
+            }
+        }
+    }
+    Ok(out.len)
+}
+
+/// Reads back an encoded snapshot, one `(name, value)` pair at a time.
+///
+/// Yields [`TelemetryError::Malformed`] once, then stops, if the encoding is
+/// truncated or invalid.
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    remaining: u8,
+}
+
+impl<'a> Decoder<'a> {
+    /// Starts decoding `bytes`, checking the snapshot header.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, TelemetryError> {
+        match bytes {
+            [FORMAT_VERSION, count, rest @ ..] => Ok(Self {
+                bytes: rest,
+                remaining: *count,
+            }),
+            _ => Err(TelemetryError::Malformed),
+        }
+    }
+
+    fn take<const LEN: usize>(&mut self) -> Option<[u8; LEN]> {
+        let (head, rest) = self.bytes.split_first_chunk::<LEN>()?;
+        self.bytes = rest;
+        Some(*head)
+    }
+
+    fn next_sample(&mut self) -> Option<(&'a str, MetricValue)> {
+        let [tag, name_len] = self.take()?;
+        let (name, rest) = self.bytes.split_at_checked(usize::from(name_len))?;
+        self.bytes = rest;
+        let name = core::str::from_utf8(name).ok()?;
+        let value = match tag {
+            kind::COUNTER => MetricValue::Counter(u64::from_le_bytes(self.take()?)),
+            kind::GAUGE => MetricValue::Gauge(i64::from_le_bytes(self.take()?)),
+            kind::HISTOGRAM => {
+                let mut histogram = HistogramSnapshot {
+                    bounds: [0; HISTOGRAM_BUCKETS],
+                    buckets: [0; HISTOGRAM_BUCKETS],
+                    overflow: 0,
+                    sum: 0,
+                };
+                for bound in &mut histogram.bounds {
+                    *bound = u64::from_le_bytes(self.take()?);
+                }
+                for count in &mut histogram.buckets {
+                    *count = u32::from_le_bytes(self.take()?);
+                }
+                histogram.overflow = u32::from_le_bytes(self.take()?);
+                histogram.sum = u64::from_le_bytes(self.take()?);
+                MetricValue::Histogram(histogram)
+            }
+            _ => return None,
+        };
+        Some((name, value))
+    }
+}
+
+impl<'a> Iterator for Decoder<'a> {
+    type Item = Result<(&'a str, MetricValue), TelemetryError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        match self.next_sample() {
+            Some(sample) => {
+                self.remaining -= 1;
+                Some(Ok(sample))
+            }
+            None => {
+                self.remaining = 0;
+                Some(Err(TelemetryError::Malformed))
+            }
+        }
+    }
+}
+
+/// Handles a telemetry request and encodes the response.
+///
+/// `request` is the payload received on a [`TELEMETRY_MSG_TYPE`] listener:
+/// the 4-byte vendor header followed by a [`command`] code. The response is
+/// the same vendor header, the command code, a [`status`] code and, on
+/// success, the encoded `snapshot`. Returns the number of response bytes
+/// written, or `None` when the request is too short to answer, is for
+/// another vendor than the IANA enterprise number `vendor`, or `response`
+/// cannot hold even the status.
+pub fn handle_request<const N: usize>(
+    vendor: u32,
+    snapshot: &Snapshot<N>,
+    request: &[u8],
+    response: &mut [u8],
+) -> Option<usize> {
+    let (header_in, rest) = request.split_first_chunk::<VENDOR_HEADER_SIZE>()?;
+    if u32::from_be_bytes(*header_in) != vendor {
+        return None;
+    }
+    let &cmd = rest.first()?;
+    let header_len = VENDOR_HEADER_SIZE + 2;
+    let (header, body) = response.split_at_mut_checked(header_len)?;
+    header[..VENDOR_HEADER_SIZE].copy_from_slice(header_in);
+    header[VENDOR_HEADER_SIZE] = cmd;
+
+    let (code, len) = match cmd {
+        command::GET_SNAPSHOT => match encode(snapshot, body) {
+            Ok(len) => (status::SUCCESS, len),
+            Err(_) => (status::ERROR_TOO_LARGE, 0),
+        },
+        _ => (status::ERROR_UNSUPPORTED_CMD, 0),
+    };
+    header[VENDOR_HEADER_SIZE + 1] = code;
+    Some(header_len + len)
+}
+
+/// Receives one request on `listener` and sends back the response.
+///
+/// `listener` must be bound to [`TELEMETRY_MSG_TYPE`]. The request is read
+/// into `buf`, answered by [`handle_request`] in `response` and returned to
+/// the requester; a request too short to answer, or for another vendor
+/// than `vendor`, is dropped without a response.
+pub fn serve_once<L: MctpListener, const N: usize>(
+    listener: &mut L,
+    vendor: u32,
+    snapshot: &Snapshot<N>,
+    buf: &mut [u8],
+    response: &mut [u8],
+) -> Result<(), MctpError> {
+    let (_meta, request, mut resp) = listener.recv(buf)?;
+    match handle_request(vendor, snapshot, request, response) {
+        Some(len) => resp.send(&response[..len]),
+        None => Ok(()),
+    }
+}
+
+/// Appends to a caller-provided buffer.
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Writer<'_> {
+    fn put(&mut self, bytes: &[u8]) -> Result<(), TelemetryError> {
+        let dst = self
+            .buf
+            .get_mut(self.len..self.len + bytes.len())
+            .ok_or(TelemetryError::BufferTooSmall)?;
+        dst.copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MetricsRegistry;
+
+    const VENDOR: [u8; 4] = [0x00, 0x00, 0xA5, 0x5A];
+    const VENDOR_ID: u32 = 0xA55A;
+
+    fn registry() -> MetricsRegistry<4> {
+        let mut registry = MetricsRegistry::<4>::new();
+        let rx = registry.register_counter("mctp.rx").unwrap();
+        let depth = registry.register_gauge("queue.depth").unwrap();
+        registry.counter(rx).add(1234);
+        registry.gauge(depth).set(-7);
+        registry
+    }
+
+    #[test]
+    fn counter_and_gauge_round_trip() {
+        let snapshot = registry().snapshot();
+        let mut buf = [0u8; max_encoded_len(4)];
+        let len = encode(&snapshot, &mut buf).unwrap();
+        assert_eq!(len, 2 + (2 + 7 + 8) + (2 + 11 + 8));
+
+        let decoded: Vec<_> = Decoder::new(&buf[..len])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            decoded,
+            [
+                ("mctp.rx", MetricValue::Counter(1234)),
+                ("queue.depth", MetricValue::Gauge(-7)),
+            ]
+        );
+    }
+
+    #[test]
+    fn histogram_round_trip() {
+        let mut registry = MetricsRegistry::<1>::new();
+        let id = registry
+            .register_histogram("latency", [1, 2, 4, 8, 16, 32, 64, 128])
+            .unwrap();
+        registry.histogram(id).observe(5);
+        registry.histogram(id).observe(500);
+        let snapshot = registry.snapshot();
+
+        let mut buf = [0u8; max_encoded_len(1)];
+        let len = encode(&snapshot, &mut buf).unwrap();
+        let mut decoder = Decoder::new(&buf[..len]).unwrap();
+        assert_eq!(
+            decoder.next(),
+            Some(Ok(("latency", snapshot.get("latency").unwrap())))
+        );
+        assert_eq!(decoder.next(), None);
+    }
+
+    #[test]
+    fn labeled_series_names() {
+        let mut registry = MetricsRegistry::<2>::new();
+        let id = registry
+            .register_labeled_counter("drops", "reason", ["bootstrap", "foreign_eid"])
+            .unwrap();
+        registry.labeled_counter(id).add(1, 3);
+
+        let mut buf = [0u8; max_encoded_len(2)];
+        let len = encode(&registry.snapshot(), &mut buf).unwrap();
+        let decoded: Vec<_> = Decoder::new(&buf[..len])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            decoded,
+            [
+                ("drops{reason=\"bootstrap\"}", MetricValue::Counter(0)),
+                ("drops{reason=\"foreign_eid\"}", MetricValue::Counter(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_into_short_buffer() {
+        let snapshot = registry().snapshot();
+        let mut buf = [0u8; 20];
+        assert_eq!(
+            encode(&snapshot, &mut buf),
+            Err(TelemetryError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn truncated_encoding_is_malformed() {
+        let snapshot = registry().snapshot();
+        let mut buf = [0u8; max_encoded_len(4)];
+        let len = encode(&snapshot, &mut buf).unwrap();
+
+        let results: Vec<_> = Decoder::new(&buf[..len - 1]).unwrap().collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        assert_eq!(results[1], Err(TelemetryError::Malformed));
+        assert_eq!(
+            Decoder::new(&[FORMAT_VERSION + 1, 0]).map(|_| ()),
+            Err(TelemetryError::Malformed)
+        );
+    }
+
+    #[test]
+    fn request_returns_snapshot() {
+        let snapshot = registry().snapshot();
+        let mut request = VENDOR.to_vec();
+        request.push(command::GET_SNAPSHOT);
+        let mut resp = [0u8; 128];
+
+        let len = handle_request(VENDOR_ID, &snapshot, &request, &mut resp).unwrap();
+        assert_eq!(resp[..4], VENDOR);
+        assert_eq!(resp[4..6], [command::GET_SNAPSHOT, status::SUCCESS]);
+        let decoded: Vec<_> = Decoder::new(&resp[6..len]).unwrap().collect();
+        assert_eq!(decoded[0], Ok(("mctp.rx", MetricValue::Counter(1234))));
+    }
+
+    #[test]
+    fn request_errors() {
+        let snapshot = registry().snapshot();
+        let mut resp = [0u8; 128];
+
+        let len =
+            handle_request(VENDOR_ID, &snapshot, &[0, 0, 0xA5, 0x5A, 0x7E], &mut resp).unwrap();
+        assert_eq!(resp[4..len], [0x7E, status::ERROR_UNSUPPORTED_CMD]);
+
+        let mut small = [0u8; 10];
+        let request = [0, 0, 0xA5, 0x5A, command::GET_SNAPSHOT];
+        let len = handle_request(VENDOR_ID, &snapshot, &request, &mut small).unwrap();
+        assert_eq!(
+            small[4..len],
+            [command::GET_SNAPSHOT, status::ERROR_TOO_LARGE]
+        );
+
+        assert!(handle_request(VENDOR_ID, &snapshot, &VENDOR, &mut resp).is_none());
+    }
+
+    #[test]
+    fn request_for_other_vendor_is_ignored() {
+        let snapshot = registry().snapshot();
+        let mut resp = [0u8; 128];
+        for vendor in [[0, 0, 0, 0], [0x5A, 0xA5, 0, 0], [0, 0, 0xA5, 0x5B]] {
+            let mut request = vendor.to_vec();
+            request.push(command::GET_SNAPSHOT);
+            assert!(handle_request(VENDOR_ID, &snapshot, &request, &mut resp).is_none());
+        }
+    }
+
+    #[test]
+    fn too_many_series() {
+        let mut registry = MetricsRegistry::<{ MAX_ENCODED_SERIES + 1 }>::new();
+        for i in 0..=MAX_ENCODED_SERIES {
+            let name: &'static str = Box::leak(format!("c{i}").into_boxed_str());
+            registry.register_counter(name).unwrap();
+        }
+        let mut buf = vec![0u8; max_encoded_len(MAX_ENCODED_SERIES + 1)];
+        assert_eq!(
+            encode(&registry.snapshot(), &mut buf),
+            Err(TelemetryError::TooManySeries)
+        );
+    }
+}
@@ -0,0 +1,46 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Log severity and filtering.
+
+/// How severe a log record is, most severe first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Level {
+    /// The operation failed.
+    Error = 1,
+    /// Something unexpected happened but was handled.
+    Warn = 2,
+    /// Normal, noteworthy progress.
+    Info = 3,
+    /// Detail useful when debugging.
+    Debug = 4,
+    /// Very verbose, per-packet or per-step detail.
+    Trace = 5,
+}
+
+/// The most verbose level compiled into this build.
+///
+/// Logging macros for levels above this expand to nothing, arguments
+/// included. Release builds keep up to [`Level::Info`], debug builds up to
+/// [`Level::Debug`], and the `max_level_trace` feature keeps everything.
+pub const MAX_LEVEL: Level = if cfg!(feature = "max_level_trace") {
+    Level::Trace
+} else if cfg!(debug_assertions) {
+    Level::Debug
+} else {
+    Level::Info
+};
+
+/// A destination for log records, with a runtime level threshold.
+///
+/// The logging macros ([`log!`](crate::log), [`error!`](crate::error), …)
+/// check [`MAX_LEVEL`] and then [`enabled`](Self::enabled) before evaluating
+/// any arguments, so a filtered-out call costs one comparison.
+pub trait LogSink {
+    /// Returns `true` if records at `level` should be kept.
+    fn enabled(&self, level: Level) -> bool;
+
+    /// Stores one record.
+    fn record(&mut self, level: Level, code: u32, args: &[u32]);
+}
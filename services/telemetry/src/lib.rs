@@ -4,5 +4,50 @@
 //! Telemetry, monitoring, and logging service for OpenPRoT
 //!
 //! This crate provides telemetry collection and monitoring capabilities.
+//!
+//! - [`Counter`], [`Gauge`] and [`Histogram`] — metric primitives that
+//!   update through a shared reference and need no allocation or atomics.
+//! - [`MetricsRegistry`] — a fixed number of named metrics, read all at once
+//!   with [`MetricsRegistry::snapshot`] for export. Services such as the MCTP
+//!   router publish their statistics here. A [`LabeledCounter`] keeps one
+//!   series per value of a label, such as drops by reason.
+//! - [`export`] — a compact, self-describing snapshot encoding and the
+//!   responder that serves it to a host over an MCTP listener.
+//! - [`MetricsRegistry::write_prometheus`] — the same snapshot as
+//!   Prometheus text, for management interfaces that are scraped.
+//! - [`RingLog`] — the most recent structured [`LogEntry`] records, kept for
+//!   dumping after a fault.
+//! - [`log!`], [`error!`], [`warn!`], [`info!`], [`debug!`] and [`trace!`] —
+//!   logging macros filtered at compile time by [`MAX_LEVEL`] and at run
+//!   time by the [`LogSink`]'s threshold, without evaluating the arguments
+//!   of dropped calls.
+//! - [`RateLimiter`] — caps each event code at a budget per time window and
+//!   reports how many occurrences were suppressed, for use with
+//!   [`log_limited!`].
+//! - [`Clock`] — the pluggable time source log entries and snapshots are
+//!   stamped from; [`mock::MockClock`] advances manually for tests.
+
+#![cfg_attr(not(test), no_std)]
+
+mod clock;
+mod error;
+pub mod export;
+mod level;
+mod macros;
+mod metric;
+pub mod mock;
+mod prometheus;
+mod ratelimit;
+mod registry;
+mod ring;
 
-#![no_std]
+pub use clock::{Clock, NoClock};
+pub use error::TelemetryError;
+pub use level::{Level, LogSink, MAX_LEVEL};
+pub use metric::{Counter, Gauge, Histogram, HistogramSnapshot, HISTOGRAM_BUCKETS};
+pub use ratelimit::{RateLimiter, CODE_SUPPRESSED};
+pub use registry::{
+    CounterId, GaugeId, HistogramId, Label, LabeledCounter, LabeledCounterId, MetricValue,
+    MetricsRegistry, Sample, Snapshot, DEFAULT_METRICS, MAX_LABEL_LEN, MAX_METRIC_NAME_LEN,
+};
+pub use ring::{LogEntry, RingLog, MAX_LOG_ARGS};
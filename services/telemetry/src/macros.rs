@@ -0,0 +1,149 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Level-filtered logging macros.
+//!
+//! Each macro takes a [`LogSink`](crate::LogSink), an event code and up to
+//! [`MAX_LOG_ARGS`](crate::MAX_LOG_ARGS) `u32` arguments:
+//!
+//! ```ignore
+//! telemetry::warn!(log, EVT_RETRY, attempt, eid);
+//! ```
+//!
+//! Calls above [`MAX_LEVEL`](crate::MAX_LEVEL) are removed at compile time;
+//! calls above the sink's runtime threshold are skipped. Either way the
+//! arguments are not evaluated. [`log_limited!`](crate::log_limited) adds a
+//! per-code [`RateLimiter`](crate::RateLimiter) check on top.
+
+/// Records an event at an explicit [`Level`](crate::Level).
+#[macro_export]
+macro_rules! log {
+    ($sink:expr, $level:expr, $code:expr $(, $arg:expr)* $(,)?) => {{
+        let level: $crate::Level = $level;
+        if level <= $crate::MAX_LEVEL {
+            let sink = &mut $sink;
+            if $crate::LogSink::enabled(&*sink, level) {
+                $crate::LogSink::record(sink, level, $code, &[$($arg),*]);
+            }
+        }
+    }};
+}
+
+/// Records an event at an explicit [`Level`](crate::Level), subject to a
+/// [`RateLimiter`](crate::RateLimiter).
+///
+/// ```ignore
+/// telemetry::log_limited!(log, limiter, Level::Error, EVT_CRC_FAIL, eid);
+/// ```
+///
+/// Occurrences over the limiter's budget are counted, not logged, and their
+/// arguments are not evaluated.
+#[macro_export]
+macro_rules! log_limited {
+    ($sink:expr, $limiter:expr, $level:expr, $code:expr $(, $arg:expr)* $(,)?) => {{
+        let level: $crate::Level = $level;
+        if level <= $crate::MAX_LEVEL {
+            let sink = &mut $sink;
+            if $crate::LogSink::enabled(&*sink, level) {
+                let code: u32 = $code;
+                if $limiter.admit(sink, code) {
+                    $crate::LogSink::record(sink, level, code, &[$($arg),*]);
+                }
+            }
+        }
+    }};
+}
+
+/// Records an event at [`Level::Error`](crate::Level::Error).
+#[macro_export]
+macro_rules! error {
+    ($sink:expr, $($rest:tt)+) => {
+        $crate::log!($sink, $crate::Level::Error, $($rest)+)
+    };
+}
+
+/// Records an event at [`Level::Warn`](crate::Level::Warn).
+#[macro_export]
+macro_rules! warn {
+    ($sink:expr, $($rest:tt)+) => {
+        $crate::log!($sink, $crate::Level::Warn, $($rest)+)
+    };
+}
+
+/// Records an event at [`Level::Info`](crate::Level::Info).
+#[macro_export]
+macro_rules! info {
+    ($sink:expr, $($rest:tt)+) => {
+        $crate::log!($sink, $crate::Level::Info, $($rest)+)
+    };
+}
+
+/// Records an event at [`Level::Debug`](crate::Level::Debug).
+#[macro_export]
+macro_rules! debug {
+    ($sink:expr, $($rest:tt)+) => {
+        $crate::log!($sink, $crate::Level::Debug, $($rest)+)
+    };
+}
+
+/// Records an event at [`Level::Trace`](crate::Level::Trace).
+#[macro_export]
+macro_rules! trace {
+    ($sink:expr, $($rest:tt)+) => {
+        $crate::log!($sink, $crate::Level::Trace, $($rest)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Level, RingLog, MAX_LEVEL};
+
+    #[test]
+    fn records_at_or_above_threshold() {
+        let mut log = RingLog::<8>::new();
+        crate::error!(log, 1, 10);
+        crate::warn!(log, 2);
+        crate::info!(log, 3, 30, 31);
+
+        let entries: Vec<_> = log.iter().map(|e| (e.level, e.code)).collect();
+        assert_eq!(
+            entries,
+            [(Level::Error, 1), (Level::Warn, 2), (Level::Info, 3)]
+        );
+        assert_eq!(log.iter().last().unwrap().args(), [30, 31]);
+    }
+
+    #[test]
+    fn below_runtime_threshold_dropped_unevaluated() {
+        let mut log = RingLog::<8>::new();
+        log.set_level(Level::Warn);
+        let mut evaluated = false;
+
+        crate::info!(log, 1, {
+            evaluated = true;
+            0
+        });
+        crate::warn!(log, 2);
+
+        assert!(!evaluated);
+        let codes: Vec<u32> = log.iter().map(|e| e.code).collect();
+        assert_eq!(codes, [2]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "max_level_trace"))]
+    fn above_max_level_compiled_out() {
+        assert!(Level::Trace > MAX_LEVEL);
+        let mut log = RingLog::<8>::new();
+        log.set_level(Level::Trace);
+        let mut evaluated = false;
+
+        crate::trace!(log, 1, {
+            evaluated = true;
+            0
+        });
+
+        assert!(!evaluated);
+        assert!(log.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Metric primitives: [`Counter`], [`Gauge`] and [`Histogram`].
+//!
+//! Values are held in [`Cell`]s so a metric can be updated through a shared
+//! reference without atomic read-modify-write instructions, which targets
+//! such as `riscv32imc` lack. Metrics are therefore `!Sync`: share them
+//! across execution contexts behind the platform's lock.
+
+use core::cell::Cell;
+
+use crate::TelemetryError;
+
+/// Number of bounded buckets in a [`Histogram`].
+pub const HISTOGRAM_BUCKETS: usize = 8;
+
+/// A monotonically increasing count of events.
+///
+/// Saturates at `u64::MAX` rather than wrapping.
+#[derive(Debug, Default)]
+pub struct Counter {
+    value: Cell<u64>,
+}
+
+impl Counter {
+    /// A counter at zero.
+    pub const fn new() -> Self {
+        Self {
+            value: Cell::new(0),
+        }
+    }
+
+    /// Adds one.
+    pub fn inc(&self) {
+        self.add(1);
+    }
+
+    /// Adds `n`.
+    pub fn add(&self, n: u64) {
+        self.value.set(self.value.get().saturating_add(n));
+    }
+
+    /// Current count.
+    pub fn get(&self) -> u64 {
+        self.value.get()
+    }
+}
+
+/// A value that can move up and down, such as a queue depth.
+///
+/// Saturates at the `i64` bounds rather than wrapping.
+#[derive(Debug, Default)]
+pub struct Gauge {
+    value: Cell<i64>,
+}
+
+impl Gauge {
+    /// A gauge at zero.
+    pub const fn new() -> Self {
+        Self {
+            value: Cell::new(0),
+        }
+    }
+
+    /// Replaces the value.
+    pub fn set(&self, value: i64) {
+        self.value.set(value);
+    }
+
+    /// Adds `delta`, which may be negative.
+    pub fn add(&self, delta: i64) {
+        self.value.set(self.value.get().saturating_add(delta));
+    }
+
+    /// Current value.
+    pub fn get(&self) -> i64 {
+        self.value.get()
+    }
+}
+
+/// Point-in-time copy of a [`Histogram`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistogramSnapshot {
+    /// Inclusive upper bound of each bucket.
+    pub bounds: [u64; HISTOGRAM_BUCKETS],
+    /// Observations per bucket: bucket `i` counts values in
+    /// `bounds[i - 1] + 1..=bounds[i]` (from zero for bucket 0).
+    pub buckets: [u32; HISTOGRAM_BUCKETS],
+    /// Observations above the last bound.
+    pub overflow: u32,
+    /// Sum of all observed values, saturating.
+    pub sum: u64,
+}
+
+impl HistogramSnapshot {
+    /// Total number of observations.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().map(|&n| u64::from(n)).sum::<u64>() + u64::from(self.overflow)
+    }
+}
+
+/// A distribution of observed values over fixed buckets, such as latencies.
+#[derive(Debug)]
+pub struct Histogram {
+    bounds: [u64; HISTOGRAM_BUCKETS],
+    buckets: [Cell<u32>; HISTOGRAM_BUCKETS],
+    overflow: Cell<u32>,
+    sum: Cell<u64>,
+}
+
+impl Histogram {
+    /// An empty histogram with the given inclusive bucket upper bounds.
+    ///
+    /// Returns [`TelemetryError::InvalidBounds`] unless `bounds` is strictly
+    /// ascending.
+    pub fn new(bounds: [u64; HISTOGRAM_BUCKETS]) -> Result<Self, TelemetryError> {
+        if !bounds.windows(2).all(|pair| pair[0] < pair[1]) {
+            return Err(TelemetryError::InvalidBounds);
+        }
+        Ok(Self {
+            bounds,
+            buckets: Default::default(),
+            overflow: Cell::new(0),
+            sum: Cell::new(0),
+        })
+    }
+
+    /// Records one observation of `value`.
+    pub fn observe(&self, value: u64) {
+        let count = match self.bounds.iter().position(|&bound| value <= bound) {
+            Some(i) => &self.buckets[i],
+            None => &self.overflow,
+        };
+        count.set(count.get().saturating_add(1));
+        self.sum.set(self.sum.get().saturating_add(value));
+    }
+
+    /// Copies the current bucket counts.
+    pub fn snapshot(&self) -> HistogramSnapshot {
+        HistogramSnapshot {
+            bounds: self.bounds,
+            buckets: self.buckets.each_ref().map(Cell::get),
+            overflow: self.overflow.get(),
+            sum: self.sum.get(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: [u64; HISTOGRAM_BUCKETS] = [1, 2, 5, 10, 20, 50, 100, 1000];
+
+    #[test]
+    fn counter_counts_and_saturates() {
+        let counter = Counter::new();
+        counter.inc();
+        counter.add(41);
+        assert_eq!(counter.get(), 42);
+
+        counter.add(u64::MAX);
+        assert_eq!(counter.get(), u64::MAX);
+    }
+
+    #[test]
+    fn gauge_sets_and_adjusts() {
+        let gauge = Gauge::new();
+        gauge.set(10);
+        gauge.add(-15);
+        assert_eq!(gauge.get(), -5);
+
+        gauge.set(i64::MIN);
+        gauge.add(-1);
+        assert_eq!(gauge.get(), i64::MIN);
+    }
+
+    #[test]
+    fn histogram_buckets_observations() {
+        let histogram = Histogram::new(BOUNDS).expect("valid bounds");
+        for value in [0, 1, 2, 3, 10, 11, 1000, 1001, 5000] {
+            histogram.observe(value);
+        }
+
+        let snapshot = histogram.snapshot();
+        assert_eq!(snapshot.buckets, [2, 1, 1, 1, 1, 0, 0, 1]);
+        assert_eq!(snapshot.overflow, 2);
+        assert_eq!(snapshot.count(), 9);
+        assert_eq!(snapshot.sum, 7028);
+    }
+
+    #[test]
+    fn histogram_rejects_unsorted_bounds() {
+        let mut bounds = BOUNDS;
+        bounds.swap(2, 3);
+        assert_eq!(
+            Histogram::new(bounds).map(|_| ()),
+            Err(TelemetryError::InvalidBounds)
+        );
+        assert_eq!(
+            Histogram::new([7; HISTOGRAM_BUCKETS]).map(|_| ()),
+            Err(TelemetryError::InvalidBounds)
+        );
+    }
+}
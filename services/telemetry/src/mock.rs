@@ -0,0 +1,42 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test doubles for host tests.
+
+use core::cell::Cell;
+
+use crate::Clock;
+
+/// A [`Clock`] that only moves when told to.
+///
+/// It advances through a shared reference, so a test can hand `&clock` to a
+/// log and keep moving time while the log holds it.
+#[derive(Debug, Default)]
+pub struct MockClock {
+    now: Cell<u64>,
+}
+
+impl MockClock {
+    /// A clock reading `start_ms`.
+    pub const fn new(start_ms: u64) -> Self {
+        Self {
+            now: Cell::new(start_ms),
+        }
+    }
+
+    /// Moves the clock forward by `ms`.
+    pub fn advance(&self, ms: u64) {
+        self.now.set(self.now.get().saturating_add(ms));
+    }
+
+    /// Sets the current time to `ms`.
+    pub fn set(&self, ms: u64) {
+        self.now.set(ms);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_millis(&self) -> u64 {
+        self.now.get()
+    }
+}
@@ -0,0 +1,229 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prometheus text exposition of a [`Snapshot`].
+//!
+//! Every sample is written as a `# TYPE` line followed by its value lines:
+//! one line for a counter or gauge, and for a histogram the cumulative
+//! `_bucket` series (ending with `le="+Inf"`), `_sum` and `_count`. The
+//! series of a [`LabeledCounter`](crate::LabeledCounter) share one `# TYPE`
+//! line and carry their label, as in `drops{reason="bootstrap"} 3`.
+//! Timestamps are left to the scraper.
+
+use core::fmt::{self, Write};
+
+use crate::{MetricValue, MetricsRegistry, Snapshot};
+
+impl<const N: usize> MetricsRegistry<N> {
+    /// Renders `snap` in the Prometheus text exposition format.
+    ///
+    /// Nothing is allocated: the text goes straight to `out`, typically a
+    /// fixed buffer the platform serves over its management interface.
+    /// Metric names are sanitized to `[a-zA-Z_:][a-zA-Z0-9_:]*`: every
+    /// other character becomes `_`, and a name that does not start with a
+    /// letter, `_` or `:` is prefixed with `_`, so `mctp.rx_packets` is
+    /// exported as `mctp_rx_packets`. Label names are sanitized the same
+    /// way except that `:` is illegal in them too, and label values are
+    /// escaped.
+    pub fn write_prometheus(&self, snap: &Snapshot<N>, out: &mut impl Write) -> fmt::Result {
+        let mut family = None;
+        for sample in snap.iter() {
+            let name = Name {
+                text: sample.name,
+                colons: true,
+            };
+            if let (MetricValue::Counter(value), Some(label)) = (sample.value, sample.label) {
+                if family != Some(sample.name) {
+                    writeln!(out, "# TYPE {name} counter")?;
+                }
+                family = Some(sample.name);
+                let key = Name {
+                    text: label.key,
+                    colons: false,
+                };
+                let label = Escaped(label.value);
+                writeln!(out, "{name}{{{key}=\"{label}\"}} {value}")?;
+                continue;
+            }
+            family = None;
+            match sample.value {
+                MetricValue::Counter(value) => {
+                    writeln!(out, "# TYPE {name} counter")?;
+                    writeln!(out, "{name} {value}")?;
+                }
+                MetricValue::Gauge(value) => {
+                    writeln!(out, "# TYPE {name} gauge")?;
+                    writeln!(out, "{name} {value}")?;
+                }
+                MetricValue::Histogram(histogram) => {
+                    writeln!(out, "# TYPE {name} histogram")?;
+                    let mut cumulative = 0u64;
+                    for (bound, &count) in histogram.bounds.iter().zip(&histogram.buckets) {
+                        cumulative += u64::from(count);
+                        writeln!(out, "{name}_bucket{{le=\"{bound}\"}} {cumulative}")?;
+                    }
+                    let count = histogram.count();
+                    writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {count}")?;
+                    writeln!(out, "{name}_sum {}", histogram.sum)?;
+                    writeln!(out, "{name}_count {count}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A metric or label name, displayed sanitized to the Prometheus name
+/// syntax.
+struct Name<'a> {
+    text: &'a str,
+    /// Whether `:` is legal, as it is in metric names but not label names.
+    colons: bool,
+}
+
+impl fmt::Display for Name<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let legal_start =
+            |c: char| c.is_ascii_alphabetic() || c == '_' || (self.colons && c == ':');
+        if !self.text.starts_with(legal_start) {
+            f.write_char('_')?;
+        }
+        for c in self.text.chars() {
+            let legal = legal_start(c) || c.is_ascii_digit();
+            f.write_char(if legal { c } else { '_' })?;
+        }
+        Ok(())
+    }
+}
+
+/// A label value, displayed with `\`, `"` and newlines escaped.
+struct Escaped<'a>(&'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '\\' => f.write_str("\\\\")?,
+                '"' => f.write_str("\\\"")?,
+                '\n' => f.write_str("\\n")?,
+                _ => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MetricsRegistry, HISTOGRAM_BUCKETS};
+
+    const BOUNDS: [u64; HISTOGRAM_BUCKETS] = [1, 2, 4, 8, 16, 32, 64, 128];
+
+    /// A `fmt::Write` over a fixed buffer, as firmware would use.
+    struct Buf<const N: usize> {
+        bytes: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> Buf<N> {
+        fn new() -> Self {
+            Self {
+                bytes: [0; N],
+                len: 0,
+            }
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+        }
+    }
+
+    impl<const N: usize> core::fmt::Write for Buf<N> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            let dst = self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+            dst.copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn counter_and_gauge_text() {
+        let mut registry = MetricsRegistry::<4>::new();
+        let rx = registry.register_counter("mctp.rx_packets").unwrap();
+        let depth = registry.register_gauge("2nd-queue depth").unwrap();
+        registry.counter(rx).add(1234);
+        registry.gauge(depth).set(-7);
+
+        let mut out = Buf::<256>::new();
+        registry
+            .write_prometheus(&registry.snapshot(), &mut out)
+            .unwrap();
+        assert_eq!(
+            out.as_str(),
+            "# TYPE mctp_rx_packets counter\n\
+             mctp_rx_packets 1234\n\
+             # TYPE _2nd_queue_depth gauge\n\
+             _2nd_queue_depth -7\n"
+        );
+    }
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let mut registry = MetricsRegistry::<1>::new();
+        let latency = registry.register_histogram("latency_ms", BOUNDS).unwrap();
+        registry.histogram(latency).observe(3);
+        registry.histogram(latency).observe(4);
+        registry.histogram(latency).observe(200);
+
+        let mut out = Buf::<512>::new();
+        registry
+            .write_prometheus(&registry.snapshot(), &mut out)
+            .unwrap();
+        let lines: Vec<&str> = out.as_str().lines().collect();
+        assert_eq!(lines[0], "# TYPE latency_ms histogram");
+        assert_eq!(lines[2], "latency_ms_bucket{le=\"2\"} 0");
+        assert_eq!(lines[3], "latency_ms_bucket{le=\"4\"} 2");
+        assert_eq!(lines[8], "latency_ms_bucket{le=\"128\"} 2");
+        assert_eq!(lines[9], "latency_ms_bucket{le=\"+Inf\"} 3");
+        assert_eq!(lines[10], "latency_ms_sum 207");
+        assert_eq!(lines[11], "latency_ms_count 3");
+        assert_eq!(lines.len(), 12);
+    }
+
+    #[test]
+    fn labeled_counter_text() {
+        let mut registry = MetricsRegistry::<4>::new();
+        let drops = registry
+            .register_labeled_counter("mctp.drops", "drop:reason", ["bootstrap", "a\"b"])
+            .unwrap();
+        let rx = registry.register_counter("rx").unwrap();
+        registry.labeled_counter(drops).add(0, 3);
+        registry.labeled_counter(drops).inc(1);
+        registry.counter(rx).inc();
+
+        let mut out = Buf::<256>::new();
+        registry
+            .write_prometheus(&registry.snapshot(), &mut out)
+            .unwrap();
+        assert_eq!(
+            out.as_str(),
+            "# TYPE mctp_drops counter\n\
+             mctp_drops{drop_reason=\"bootstrap\"} 3\n\
+             mctp_drops{drop_reason=\"a\\\"b\"} 1\n\
+             # TYPE rx counter\n\
+             rx 1\n"
+        );
+    }
+
+    #[test]
+    fn short_buffer_fails() {
+        let mut registry = MetricsRegistry::<1>::new();
+        registry.register_counter("rx").unwrap();
+        let mut out = Buf::<8>::new();
+        assert!(registry
+            .write_prometheus(&registry.snapshot(), &mut out)
+            .is_err());
+    }
+}
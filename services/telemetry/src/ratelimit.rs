@@ -0,0 +1,242 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-code log rate limiting: [`RateLimiter`].
+
+use crate::{Clock, Level, LogSink};
+
+/// Event code of the record reporting suppressed occurrences.
+///
+/// The record is logged at [`Level::Warn`] with arguments
+/// `[suppressed_code, count]`.
+pub const CODE_SUPPRESSED: u32 = 0xFFFF_FFFF;
+
+/// Rate-limiting state for one event code within the current window.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    code: u32,
+    window_start_ms: u64,
+    used: u32,
+    suppressed: u32,
+}
+
+/// Caps how often each event code is logged.
+///
+/// Every code may be recorded `budget` times per `window_ms`; further
+/// occurrences in the same window are counted instead of logged. When the
+/// window closes, a single [`CODE_SUPPRESSED`] record reports how many were
+/// dropped, so a fault storm leaves its first occurrences and a count behind
+/// rather than flushing the original cause out of the log.
+///
+/// Limiting is opt-in per call site through [`log_limited!`](crate::log_limited).
+/// State is kept for up to `CODES` distinct codes; once every slot is in
+/// use, codes without one are logged unlimited.
+#[derive(Debug, Clone)]
+pub struct RateLimiter<C: Clock, const CODES: usize = 8> {
+    clock: C,
+    budget: u32,
+    window_ms: u64,
+    buckets: [Option<Bucket>; CODES],
+}
+
+impl<C: Clock, const CODES: usize> RateLimiter<C, CODES> {
+    /// A limiter allowing `budget` records per code every `window_ms`,
+    /// timed by `clock`.
+    pub const fn new(clock: C, budget: u32, window_ms: u64) -> Self {
+        Self {
+            clock,
+            budget,
+            window_ms,
+            buckets: [None; CODES],
+        }
+    }
+
+    /// Decides whether an occurrence of `code` may be logged now.
+    ///
+    /// If `code`'s previous window has closed with suppressed occurrences,
+    /// the [`CODE_SUPPRESSED`] report is written to `sink` first.
+    pub fn admit(&mut self, sink: &mut impl LogSink, code: u32) -> bool {
+        let now = self.clock.now_millis();
+        let window_ms = self.window_ms;
+        let index = match self.find(code) {
+            Some(index) => index,
+            None => match self.buckets.iter().position(Option::is_none) {
+                Some(index) => index,
+                None => return true,
+            },
+        };
+        let bucket = self.buckets[index].get_or_insert(Bucket {
+            code,
+            window_start_ms: now,
+            used: 0,
+            suppressed: 0,
+        });
+        if now.saturating_sub(bucket.window_start_ms) >= window_ms {
+            report(sink, bucket);
+            *bucket = Bucket {
+                code,
+                window_start_ms: now,
+                used: 0,
+                suppressed: 0,
+            };
+        }
+        if bucket.used < self.budget {
+            bucket.used += 1;
+            true
+        } else {
+            bucket.suppressed = bucket.suppressed.saturating_add(1);
+            false
+        }
+    }
+
+    /// Reports every closed window with suppressed occurrences to `sink`
+    /// and frees its slot.
+    ///
+    /// Call periodically so a storm that simply stops still gets its count
+    /// logged.
+    pub fn flush(&mut self, sink: &mut impl LogSink) {
+        let now = self.clock.now_millis();
+        let window_ms = self.window_ms;
+        for slot in &mut self.buckets {
+            let closed = slot.filter(|b| now.saturating_sub(b.window_start_ms) >= window_ms);
+            if let Some(bucket) = closed {
+                report(sink, &bucket);
+                *slot = None;
+            }
+        }
+    }
+
+    /// Occurrences of `code` suppressed so far in its current window.
+    pub fn suppressed(&self, code: u32) -> u32 {
+        self.find(code)
+            .and_then(|index| self.buckets[index])
+            .map_or(0, |bucket| bucket.suppressed)
+    }
+
+    fn find(&self, code: u32) -> Option<usize> {
+        self.buckets
+            .iter()
+            .position(|slot| slot.is_some_and(|bucket| bucket.code == code))
+    }
+}
+
+/// Writes the suppressed-count record for a closing window, if any.
+fn report(sink: &mut impl LogSink, bucket: &Bucket) {
+    if bucket.suppressed > 0 && sink.enabled(Level::Warn) {
+        sink.record(
+            Level::Warn,
+            CODE_SUPPRESSED,
+            &[bucket.code, bucket.suppressed],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockClock;
+    use crate::RingLog;
+
+    const STORM: u32 = 0x10;
+    const OTHER: u32 = 0x20;
+
+    fn records<C: Clock>(log: &RingLog<16, C>) -> Vec<(u32, Vec<u32>)> {
+        log.iter().map(|e| (e.code, e.args().to_vec())).collect()
+    }
+
+    #[test]
+    fn storm_suppressed_and_reported() {
+        let clock = MockClock::new(0);
+        let mut log = RingLog::<16>::new();
+        let mut limiter = RateLimiter::<_, 4>::new(&clock, 2, 100);
+
+        for i in 0..5 {
+            crate::log_limited!(log, limiter, Level::Error, STORM, i);
+        }
+        assert_eq!(records(&log), [(STORM, vec![0]), (STORM, vec![1])]);
+        assert_eq!(limiter.suppressed(STORM), 3);
+
+        // Still inside the window: still suppressed, nothing reported.
+        clock.advance(99);
+        crate::log_limited!(log, limiter, Level::Error, STORM, 5);
+        assert_eq!(log.len(), 2);
+        assert_eq!(limiter.suppressed(STORM), 4);
+
+        // The next occurrence after the window closes reports the count.
+        clock.advance(1);
+        crate::log_limited!(log, limiter, Level::Error, STORM, 6);
+        assert_eq!(
+            records(&log)[2..],
+            [(CODE_SUPPRESSED, vec![STORM, 4]), (STORM, vec![6]),]
+        );
+        assert_eq!(limiter.suppressed(STORM), 0);
+    }
+
+    #[test]
+    fn codes_limited_independently() {
+        let clock = MockClock::new(0);
+        let mut log = RingLog::<16>::new();
+        let mut limiter = RateLimiter::<_, 4>::new(&clock, 1, 100);
+
+        crate::log_limited!(log, limiter, Level::Warn, STORM);
+        crate::log_limited!(log, limiter, Level::Warn, STORM);
+        crate::log_limited!(log, limiter, Level::Warn, OTHER);
+        // Call sites without a limiter are never suppressed.
+        crate::warn!(log, STORM);
+
+        let codes: Vec<u32> = log.iter().map(|e| e.code).collect();
+        assert_eq!(codes, [STORM, OTHER, STORM]);
+        assert_eq!(limiter.suppressed(STORM), 1);
+        assert_eq!(limiter.suppressed(OTHER), 0);
+    }
+
+    #[test]
+    fn flush_reports_closed_windows() {
+        let clock = MockClock::new(0);
+        let mut log = RingLog::<16>::new();
+        let mut limiter = RateLimiter::<_, 4>::new(&clock, 1, 100);
+        for _ in 0..4 {
+            crate::log_limited!(log, limiter, Level::Error, STORM);
+        }
+
+        limiter.flush(&mut log);
+        assert_eq!(log.len(), 1);
+
+        clock.advance(150);
+        limiter.flush(&mut log);
+        assert_eq!(records(&log)[1..], [(CODE_SUPPRESSED, vec![STORM, 3])]);
+
+        // Reported once only.
+        limiter.flush(&mut log);
+        assert_eq!(log.len(), 2);
+    }
+
+    #[test]
+    fn suppressed_calls_do_not_evaluate_args() {
+        let clock = MockClock::new(0);
+        let mut log = RingLog::<16>::new();
+        let mut limiter = RateLimiter::<_, 4>::new(&clock, 0, 100);
+        let mut evaluated = false;
+
+        crate::log_limited!(log, limiter, Level::Error, STORM, {
+            evaluated = true;
+            0
+        });
+        assert!(!evaluated);
+        assert!(log.is_empty());
+    }
+
+    #[test]
+    fn untracked_codes_fail_open() {
+        let clock = MockClock::new(0);
+        let mut log = RingLog::<16>::new();
+        let mut limiter = RateLimiter::<_, 1>::new(&clock, 1, 100);
+
+        for _ in 0..3 {
+            crate::log_limited!(log, limiter, Level::Error, STORM);
+            crate::log_limited!(log, limiter, Level::Error, OTHER);
+        }
+        let codes: Vec<u32> = log.iter().map(|e| e.code).collect();
+        assert_eq!(codes, [STORM, OTHER, OTHER, OTHER]);
+    }
+}
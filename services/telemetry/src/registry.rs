@@ -0,0 +1,561 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named metric storage: [`MetricsRegistry`] and its [`Snapshot`].
+
+use crate::{
+    Clock, Counter, Gauge, Histogram, HistogramSnapshot, TelemetryError, HISTOGRAM_BUCKETS,
+};
+
+/// Default number of metrics a [`MetricsRegistry`] can hold.
+pub const DEFAULT_METRICS: usize = 16;
+
+/// Longest metric name, in bytes, a [`MetricsRegistry`] accepts.
+pub const MAX_METRIC_NAME_LEN: usize = 32;
+
+/// Longest label name or label value, in bytes, a [`MetricsRegistry`]
+/// accepts.
+pub const MAX_LABEL_LEN: usize = 16;
+
+/// Handle to a [`Counter`] registered in a [`MetricsRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CounterId(usize);
+
+/// Handle to a [`Gauge`] registered in a [`MetricsRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GaugeId(usize);
+
+/// Handle to a [`Histogram`] registered in a [`MetricsRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistogramId(usize);
+
+/// Handle to a [`LabeledCounter`] with `N` label values registered in a
+/// [`MetricsRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LabeledCounterId<const N: usize>(usize);
+
+#[derive(Debug)]
+enum Metric {
+    Counter(Counter),
+    Gauge(Gauge),
+    Histogram(Histogram),
+}
+
+#[derive(Debug)]
+struct Entry {
+    name: &'static str,
+    label: Option<Label>,
+    metric: Metric,
+}
+
+/// The label telling apart the series of a [`LabeledCounter`], such as
+/// `reason="bootstrap"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Label {
+    /// Label name, shared by every series of the counter.
+    pub key: &'static str,
+    /// Label value identifying this series.
+    pub value: &'static str,
+}
+
+/// The value of one metric at snapshot time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricValue {
+    /// A [`Counter`] reading.
+    Counter(u64),
+    /// A [`Gauge`] reading.
+    Gauge(i64),
+    /// A [`Histogram`] reading.
+    Histogram(HistogramSnapshot),
+}
+
+/// One named metric reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    /// Name the metric was registered under.
+    pub name: &'static str,
+    /// Label of this series, for a [`LabeledCounter`].
+    pub label: Option<Label>,
+    /// Value at snapshot time.
+    pub value: MetricValue,
+}
+
+/// Readings of every metric in a registry, taken together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot<const N: usize = DEFAULT_METRICS> {
+    timestamp_ms: u64,
+    samples: [Option<Sample>; N],
+}
+
+impl<const N: usize> Snapshot<N> {
+    /// [`Clock::now_millis`] reading when the snapshot was taken, or zero if
+    /// it was taken without a clock.
+    pub fn timestamp_ms(&self) -> u64 {
+        self.timestamp_ms
+    }
+
+    /// Iterates over the readings in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = &Sample> {
+        self.samples.iter().flatten()
+    }
+
+    /// The reading of the unlabeled metric registered as `name`.
+    pub fn get(&self, name: &str) -> Option<MetricValue> {
+        self.iter()
+            .find(|sample| sample.name == name && sample.label.is_none())
+            .map(|sample| sample.value)
+    }
+
+    /// The reading of the [`LabeledCounter`] series registered as `name`
+    /// with label value `value`.
+    pub fn get_labeled(&self, name: &str, value: &str) -> Option<MetricValue> {
+        self.iter()
+            .find(|sample| {
+                sample.name == name && sample.label.is_some_and(|label| label.value == value)
+            })
+            .map(|sample| sample.value)
+    }
+
+    /// Number of readings; a [`LabeledCounter`] contributes one per label
+    /// value.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns `true` if the snapshot holds no readings.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A fixed-capacity set of named metrics.
+///
+/// Services register their metrics once at start-up and keep the returned
+/// ids; updates then go through [`counter`](Self::counter),
+/// [`gauge`](Self::gauge) and [`histogram`](Self::histogram), which only
+/// need a shared reference. An exporter calls [`snapshot`](Self::snapshot)
+/// to read every metric at once.
+///
+/// Ids are only meaningful for the registry that issued them; the lookup
+/// methods panic if given an id from another registry.
+#[derive(Debug)]
+pub struct MetricsRegistry<const N: usize = DEFAULT_METRICS> {
+    entries: [Option<Entry>; N],
+}
+
+impl<const N: usize> MetricsRegistry<N> {
+    /// An empty registry.
+    pub const fn new() -> Self {
+        Self {
+            entries: [const { None }; N],
+        }
+    }
+
+    /// Registers a [`Counter`] named `name`, starting at zero.
+    ///
+    /// Names must be unique and at most [`MAX_METRIC_NAME_LEN`] bytes.
+    pub fn register_counter(&mut self, name: &'static str) -> Result<CounterId, TelemetryError> {
+        self.register(name, Metric::Counter(Counter::new()))
+            .map(CounterId)
+    }
+
+    /// Registers a [`Gauge`] named `name`, starting at zero.
+    pub fn register_gauge(&mut self, name: &'static str) -> Result<GaugeId, TelemetryError> {
+        self.register(name, Metric::Gauge(Gauge::new()))
+            .map(GaugeId)
+    }
+
+    /// Registers an empty [`Histogram`] named `name` with the given bucket
+    /// bounds.
+    pub fn register_histogram(
+        &mut self,
+        name: &'static str,
+        bounds: [u64; HISTOGRAM_BUCKETS],
+    ) -> Result<HistogramId, TelemetryError> {
+        let histogram = Histogram::new(bounds)?;
+        self.register(name, Metric::Histogram(histogram))
+            .map(HistogramId)
+    }
+
+    /// Registers a [`LabeledCounter`] named `name` with one series per
+    /// entry of `values`, all labeled `key` and starting at zero.
+    ///
+    /// Each series takes one slot of the registry. `key` and every value
+    /// must be at most [`MAX_LABEL_LEN`] bytes, and the values must be
+    /// unique.
+    pub fn register_labeled_counter<const L: usize>(
+        &mut self,
+        name: &'static str,
+        key: &'static str,
+        values: [&'static str; L],
+    ) -> Result<LabeledCounterId<L>, TelemetryError> {
+        if key.len() > MAX_LABEL_LEN || values.iter().any(|value| value.len() > MAX_LABEL_LEN) {
+            return Err(TelemetryError::NameTooLong);
+        }
+        if values
+            .iter()
+            .enumerate()
+            .any(|(i, value)| values[..i].contains(value))
+        {
+            return Err(TelemetryError::DuplicateName);
+        }
+        self.check_name(name)?;
+        // Slots are filled in order and never freed, so the series are
+        // contiguous from the first free slot.
+        let first = self.len();
+        if N - first < L {
+            return Err(TelemetryError::Full);
+        }
+        for (entry, value) in self.entries[first..].iter_mut().zip(values) {
+            *entry = Some(Entry {
+                name,
+                label: Some(Label { key, value }),
+                metric: Metric::Counter(Counter::new()),
+            });
+        }
+        Ok(LabeledCounterId(first))
+    }
+
+    /// The counter registered as `id`.
+    pub fn counter(&self, id: CounterId) -> &Counter {
+        match self.metric(id.0) {
+            Metric::Counter(counter) => counter,
+            _ => panic!("CounterId from another registry"),
+        }
+    }
+
+    /// The gauge registered as `id`.
+    pub fn gauge(&self, id: GaugeId) -> &Gauge {
+        match self.metric(id.0) {
+            Metric::Gauge(gauge) => gauge,
+            _ => panic!("GaugeId from another registry"),
+        }
+    }
+
+    /// The histogram registered as `id`.
+    pub fn histogram(&self, id: HistogramId) -> &Histogram {
+        match self.metric(id.0) {
+            Metric::Histogram(histogram) => histogram,
+            _ => panic!("HistogramId from another registry"),
+        }
+    }
+
+    /// The labeled counter registered as `id`.
+    pub fn labeled_counter<const L: usize>(
+        &self,
+        id: LabeledCounterId<L>,
+    ) -> LabeledCounter<'_, L> {
+        let series = self
+            .entries
+            .get(id.0..id.0 + L)
+            .expect("LabeledCounterId from another registry");
+        LabeledCounter {
+            counters: core::array::from_fn(|i| match &series[i] {
+                Some(Entry {
+                    label: Some(_),
+                    metric: Metric::Counter(counter),
+                    ..
+                }) => counter,
+                _ => panic!("LabeledCounterId from another registry"),
+            }),
+        }
+    }
+
+    /// The counter series registered as `name`, labeled with `label` for a
+    /// [`LabeledCounter`] or unlabeled for a plain [`Counter`].
+    ///
+    /// For code that only knows a metric by name, such as restoring
+    /// persisted counts at boot; services keep ids instead.
+    pub fn find_counter(&self, name: &str, label: Option<&str>) -> Option<&Counter> {
+        self.entries.iter().flatten().find_map(|entry| match entry {
+            Entry {
+                metric: Metric::Counter(counter),
+                ..
+            } if entry.name == name && entry.label.map(|label| label.value) == label => {
+                Some(counter)
+            }
+            _ => None,
+        })
+    }
+
+    /// Number of registered series; a [`LabeledCounter`] counts once per
+    /// label value.
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Returns `true` if no metrics are registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads every registered metric.
+    pub fn snapshot(&self) -> Snapshot<N> {
+        self.snapshot_stamped(0)
+    }
+
+    /// Reads every registered metric, stamping the snapshot from `clock`.
+    pub fn snapshot_at(&self, clock: &impl Clock) -> Snapshot<N> {
+        self.snapshot_stamped(clock.now_millis())
+    }
+
+    fn snapshot_stamped(&self, timestamp_ms: u64) -> Snapshot<N> {
+        Snapshot {
+            timestamp_ms,
+            samples: self.entries.each_ref().map(|entry| {
+                entry.as_ref().map(|entry| Sample {
+                    name: entry.name,
+                    label: entry.label,
+                    value: match &entry.metric {
+                        Metric::Counter(counter) => MetricValue::Counter(counter.get()),
+                        Metric::Gauge(gauge) => MetricValue::Gauge(gauge.get()),
+                        Metric::Histogram(histogram) => {
+                            MetricValue::Histogram(histogram.snapshot())
+                        }
+                    },
+                })
+            }),
+        }
+    }
+
+    fn register(&mut self, name: &'static str, metric: Metric) -> Result<usize, TelemetryError> {
+        self.check_name(name)?;
+        let index = self
+            .entries
+            .iter()
+            .position(Option::is_none)
+            .ok_or(TelemetryError::Full)?;
+        self.entries[index] = Some(Entry {
+            name,
+            label: None,
+            metric,
+        });
+        Ok(index)
+    }
+
+    fn check_name(&self, name: &str) -> Result<(), TelemetryError> {
+        if name.len() > MAX_METRIC_NAME_LEN {
+            return Err(TelemetryError::NameTooLong);
+        }
+        if self
+            .entries
+            .iter()
+            .flatten()
+            .any(|entry| entry.name == name)
+        {
+            return Err(TelemetryError::DuplicateName);
+        }
+        Ok(())
+    }
+
+    fn metric(&self, index: usize) -> &Metric {
+        match self.entries.get(index) {
+            Some(Some(entry)) => &entry.metric,
+            _ => panic!("metric id from another registry"),
+        }
+    }
+}
+
+/// A [`Counter`] per value of one label, such as MCTP drops by reason.
+///
+/// Borrowed from the registry with
+/// [`MetricsRegistry::labeled_counter`]. Series are picked by their index
+/// in the `values` given at registration, so a service can count straight
+/// from an enum discriminant; each appears in a [`Snapshot`] as its own
+/// [`Sample`] carrying its [`Label`].
+#[derive(Debug, Clone, Copy)]
+pub struct LabeledCounter<'a, const N: usize> {
+    counters: [&'a Counter; N],
+}
+
+impl<const N: usize> LabeledCounter<'_, N> {
+    /// Adds one to the series for label value `index`.
+    ///
+    /// Panics if `index` is not below `N`.
+    pub fn inc(&self, index: usize) {
+        self.counters[index].inc();
+    }
+
+    /// Adds `n` to the series for label value `index`.
+    ///
+    /// Panics if `index` is not below `N`.
+    pub fn add(&self, index: usize, n: u64) {
+        self.counters[index].add(n);
+    }
+
+    /// Current count of the series for label value `index`.
+    ///
+    /// Panics if `index` is not below `N`.
+    pub fn get(&self, index: usize) -> u64 {
+        self.counters[index].get()
+    }
+}
+
+impl<const N: usize> Default for MetricsRegistry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockClock;
+
+    const BOUNDS: [u64; HISTOGRAM_BUCKETS] = [1, 2, 4, 8, 16, 32, 64, 128];
+
+    #[test]
+    fn snapshot_reads_every_metric() {
+        let mut registry = MetricsRegistry::<4>::new();
+        let rx = registry.register_counter("mctp.rx_packets").unwrap();
+        let depth = registry.register_gauge("mctp.queue_depth").unwrap();
+        let latency = registry
+            .register_histogram("mctp.latency_ms", BOUNDS)
+            .unwrap();
+
+        registry.counter(rx).inc();
+        registry.counter(rx).add(2);
+        registry.gauge(depth).set(5);
+        registry.gauge(depth).add(-2);
+        registry.histogram(latency).observe(3);
+        registry.histogram(latency).observe(200);
+
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.len(), 3);
+        assert_eq!(
+            snapshot.get("mctp.rx_packets"),
+            Some(MetricValue::Counter(3))
+        );
+        assert_eq!(
+            snapshot.get("mctp.queue_depth"),
+            Some(MetricValue::Gauge(3))
+        );
+        let Some(MetricValue::Histogram(histogram)) = snapshot.get("mctp.latency_ms") else {
+            panic!("latency should be a histogram");
+        };
+        assert_eq!(histogram.buckets, [0, 0, 1, 0, 0, 0, 0, 0]);
+        assert_eq!(histogram.overflow, 1);
+        assert_eq!(histogram.sum, 203);
+
+        let names: Vec<&str> = snapshot.iter().map(|sample| sample.name).collect();
+        assert_eq!(
+            names,
+            ["mctp.rx_packets", "mctp.queue_depth", "mctp.latency_ms"]
+        );
+    }
+
+    #[test]
+    fn snapshot_is_a_copy() {
+        let mut registry = MetricsRegistry::<4>::new();
+        let rx = registry.register_counter("rx").unwrap();
+        let before = registry.snapshot();
+        registry.counter(rx).inc();
+
+        assert_eq!(before.get("rx"), Some(MetricValue::Counter(0)));
+        assert_eq!(registry.snapshot().get("rx"), Some(MetricValue::Counter(1)));
+    }
+
+    #[test]
+    fn snapshot_carries_clock_timestamp() {
+        let clock = MockClock::new(42);
+        let registry = MetricsRegistry::<1>::new();
+        assert_eq!(registry.snapshot_at(&clock).timestamp_ms(), 42);
+        clock.advance(8);
+        assert_eq!(registry.snapshot_at(&clock).timestamp_ms(), 50);
+        assert_eq!(registry.snapshot().timestamp_ms(), 0);
+    }
+
+    #[test]
+    fn labeled_counter_series_are_independent() {
+        let mut registry = MetricsRegistry::<4>::new();
+        let rx = registry.register_counter("mctp.rx_packets").unwrap();
+        let drops = registry
+            .register_labeled_counter("mctp.drops", "reason", ["bootstrap", "foreign_eid"])
+            .unwrap();
+        assert_eq!(registry.len(), 3);
+
+        registry.labeled_counter(drops).inc(0);
+        registry.labeled_counter(drops).add(1, 4);
+        registry.labeled_counter(drops).inc(1);
+        registry.counter(rx).inc();
+        assert_eq!(registry.labeled_counter(drops).get(0), 1);
+        assert_eq!(registry.labeled_counter(drops).get(1), 5);
+
+        let snapshot = registry.snapshot();
+        assert_eq!(
+            snapshot.get_labeled("mctp.drops", "bootstrap"),
+            Some(MetricValue::Counter(1))
+        );
+        assert_eq!(
+            snapshot.get_labeled("mctp.drops", "foreign_eid"),
+            Some(MetricValue::Counter(5))
+        );
+        assert_eq!(snapshot.get("mctp.drops"), None);
+        assert_eq!(snapshot.get_labeled("mctp.drops", "other"), None);
+        let labels: Vec<Option<Label>> = snapshot.iter().map(|sample| sample.label).collect();
+        assert_eq!(
+            labels,
+            [
+                None,
+                Some(Label {
+                    key: "reason",
+                    value: "bootstrap"
+                }),
+                Some(Label {
+                    key: "reason",
+                    value: "foreign_eid"
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn labeled_counter_registration_errors() {
+        let mut registry = MetricsRegistry::<3>::new();
+        registry.register_counter("a").unwrap();
+        assert_eq!(
+            registry.register_labeled_counter("a", "k", ["x"]),
+            Err(TelemetryError::DuplicateName)
+        );
+        assert_eq!(
+            registry.register_labeled_counter("b", "k", ["x", "x"]),
+            Err(TelemetryError::DuplicateName)
+        );
+        assert_eq!(
+            registry.register_labeled_counter("b", "k", ["x", "y", "z"]),
+            Err(TelemetryError::Full)
+        );
+        assert_eq!(
+            registry.register_labeled_counter("b", "a-label-over-16-bytes", ["x"]),
+            Err(TelemetryError::NameTooLong)
+        );
+        assert_eq!(registry.len(), 1);
+        let b = registry
+            .register_labeled_counter("b", "k", ["x", "y"])
+            .unwrap();
+        assert_eq!(
+            registry.register_gauge("b"),
+            Err(TelemetryError::DuplicateName)
+        );
+        assert_eq!(registry.register_gauge("c"), Err(TelemetryError::Full));
+        assert_eq!(registry.labeled_counter(b).get(1), 0);
+    }
+
+    #[test]
+    fn registration_errors() {
+        let mut registry = MetricsRegistry::<2>::new();
+        registry.register_counter("a").unwrap();
+        assert_eq!(
+            registry.register_gauge("a"),
+            Err(TelemetryError::DuplicateName)
+        );
+        registry.register_gauge("b").unwrap();
+        assert_eq!(registry.register_counter("c"), Err(TelemetryError::Full));
+        assert_eq!(
+            MetricsRegistry::<2>::new().register_counter("a-name-that-is-over-32-bytes-long"),
+            Err(TelemetryError::NameTooLong)
+        );
+        assert_eq!(registry.len(), 2);
+        assert!(MetricsRegistry::<2>::new().snapshot().is_empty());
+    }
+}
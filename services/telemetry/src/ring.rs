@@ -0,0 +1,243 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bounded in-memory log for postmortem dumps: [`RingLog`].
+
+use crate::{Clock, Level, LogSink, NoClock};
+
+/// Most arguments a [`LogEntry`] keeps; extra arguments are dropped.
+pub const MAX_LOG_ARGS: usize = 4;
+
+/// One structured log record: when it happened, a severity, an event code
+/// and its arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogEntry {
+    /// [`Clock::now_millis`] reading when the entry was recorded.
+    pub timestamp_ms: u64,
+    /// Severity.
+    pub level: Level,
+    /// Event code identifying what happened.
+    pub code: u32,
+    args: [u32; MAX_LOG_ARGS],
+    arg_count: u8,
+}
+
+impl LogEntry {
+    /// Builds an entry, keeping at most [`MAX_LOG_ARGS`] of `args`.
+    pub fn new(timestamp_ms: u64, level: Level, code: u32, args: &[u32]) -> Self {
+        let arg_count = args.len().min(MAX_LOG_ARGS);
+        let mut entry = Self {
+            timestamp_ms,
+            level,
+            code,
+            args: [0; MAX_LOG_ARGS],
+            arg_count: arg_count as u8,
+        };
+        entry.args[..arg_count].copy_from_slice(&args[..arg_count]);
+        entry
+    }
+
+    /// The recorded arguments.
+    pub fn args(&self) -> &[u32] {
+        &self.args[..usize::from(self.arg_count)]
+    }
+}
+
+/// Keeps the most recent `N` log entries, overwriting the oldest when full.
+///
+/// [`record`](Self::record) is constant-time and never allocates, blocks or
+/// panics, so it is safe to call with a spinlock held, e.g. from the UART
+/// console path or a fault handler. The log itself does no locking: callers
+/// that share it across contexts keep it behind that lock.
+///
+/// Entries are stamped from the log's [`Clock`]; a log created with
+/// [`new`](Self::new) uses [`NoClock`] and stamps every entry with zero.
+#[derive(Debug, Clone)]
+pub struct RingLog<const N: usize, C: Clock = NoClock> {
+    entries: [Option<LogEntry>; N],
+    /// Slot the next entry is written to.
+    head: usize,
+    /// Entries lost to wraparound since creation.
+    overwritten: u32,
+    /// Most verbose level recorded; more verbose records are dropped.
+    level: Level,
+    clock: C,
+}
+
+impl<const N: usize> RingLog<N> {
+    /// An empty log without a time source.
+    pub const fn new() -> Self {
+        Self::with_clock(NoClock)
+    }
+}
+
+impl<const N: usize, C: Clock> RingLog<N, C> {
+    /// An empty log that stamps entries from `clock`.
+    pub const fn with_clock(clock: C) -> Self {
+        Self {
+            entries: [None; N],
+            head: 0,
+            overwritten: 0,
+            level: Level::Trace,
+            clock,
+        }
+    }
+
+    /// Sets the runtime threshold: records more verbose than `level` are
+    /// dropped. New logs keep everything that [`MAX_LEVEL`](crate::MAX_LEVEL)
+    /// lets through.
+    pub fn set_level(&mut self, level: Level) {
+        self.level = level;
+    }
+
+    /// The runtime threshold.
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Appends an entry stamped with the current time, overwriting the
+    /// oldest one if the log is full.
+    pub fn record(&mut self, level: Level, code: u32, args: &[u32]) {
+        let now = self.clock.now_millis();
+        self.push(LogEntry::new(now, level, code, args));
+    }
+
+    /// Appends a prepared entry as-is, overwriting the oldest one if the log
+    /// is full.
+    pub fn push(&mut self, entry: LogEntry) {
+        let Some(slot) = self.entries.get_mut(self.head) else {
+            return;
+        };
+        if slot.replace(entry).is_some() {
+            self.overwritten = self.overwritten.saturating_add(1);
+        }
+        self.head = (self.head + 1) % N;
+    }
+
+    /// Iterates over the stored entries, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &LogEntry> {
+        let (newer, older) = self.entries.split_at(self.head);
+        older.iter().chain(newer).flatten()
+    }
+
+    /// Number of stored entries.
+    pub fn len(&self) -> usize {
+        self.entries.iter().flatten().count()
+    }
+
+    /// Returns `true` if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of entries lost to wraparound.
+    pub fn overwritten(&self) -> u32 {
+        self.overwritten
+    }
+
+    /// Discards every entry and resets the overwrite count.
+    pub fn clear(&mut self) {
+        self.entries = [None; N];
+        self.head = 0;
+        self.overwritten = 0;
+    }
+}
+
+impl<const N: usize, C: Clock> LogSink for RingLog<N, C> {
+    fn enabled(&self, level: Level) -> bool {
+        level <= self.level
+    }
+
+    fn record(&mut self, level: Level, code: u32, args: &[u32]) {
+        RingLog::record(self, level, code, args);
+    }
+}
+
+impl<const N: usize> Default for RingLog<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockClock;
+
+    fn codes<const N: usize, C: Clock>(log: &RingLog<N, C>) -> Vec<u32> {
+        log.iter().map(|entry| entry.code).collect()
+    }
+
+    #[test]
+    fn entries_dump_oldest_first() {
+        let mut log = RingLog::<4>::new();
+        assert!(log.is_empty());
+        log.record(Level::Info, 1, &[]);
+        log.record(Level::Warn, 2, &[10]);
+        log.record(Level::Error, 3, &[20, 21]);
+
+        assert_eq!(codes(&log), [1, 2, 3]);
+        let last = log.iter().last().unwrap();
+        assert_eq!(last.level, Level::Error);
+        assert_eq!(last.args(), [20, 21]);
+        assert_eq!(log.overwritten(), 0);
+    }
+
+    #[test]
+    fn wraparound_overwrites_oldest() {
+        let mut log = RingLog::<3>::new();
+        for code in 1..=7 {
+            log.record(Level::Debug, code, &[code]);
+        }
+
+        assert_eq!(log.len(), 3);
+        assert_eq!(codes(&log), [5, 6, 7]);
+        assert_eq!(log.overwritten(), 4);
+
+        // Exactly full, head back at slot 0.
+        log.record(Level::Debug, 8, &[]);
+        log.record(Level::Debug, 9, &[]);
+        assert_eq!(codes(&log), [7, 8, 9]);
+
+        log.clear();
+        assert!(log.is_empty());
+        assert_eq!(log.overwritten(), 0);
+    }
+
+    #[test]
+    fn excess_args_truncated() {
+        let mut log = RingLog::<1>::new();
+        log.record(Level::Trace, 9, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(log.iter().next().unwrap().args(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn entries_carry_clock_timestamps() {
+        let clock = MockClock::new(1_000);
+        let mut log = RingLog::<4, _>::with_clock(&clock);
+        log.record(Level::Info, 1, &[]);
+        clock.advance(250);
+        log.record(Level::Info, 2, &[]);
+        clock.set(5_000);
+        log.record(Level::Info, 3, &[]);
+
+        let stamps: Vec<u64> = log.iter().map(|e| e.timestamp_ms).collect();
+        assert_eq!(stamps, [1_000, 1_250, 5_000]);
+        assert!(RingLog::<1>::new().is_empty());
+    }
+
+    #[test]
+    fn push_keeps_entry_timestamp() {
+        let clock = MockClock::new(10);
+        let mut log = RingLog::<2, _>::with_clock(&clock);
+        log.push(LogEntry::new(3, Level::Warn, 7, &[]));
+        assert_eq!(log.iter().next().unwrap().timestamp_ms, 3);
+    }
+
+    #[test]
+    fn zero_capacity_log_records_nothing() {
+        let mut log = RingLog::<0>::new();
+        log.record(Level::Error, 1, &[]);
+        assert!(log.is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host integration test for the telemetry MCTP responder.
+//!
+//! A BMC-side server requests a snapshot from a PRoT-side server whose
+//! listener is served by [`export::serve_once`], and decodes the response.
+
+use core::cell::RefCell;
+
+use mctp::{Eid, Tag};
+use mctp_lib::fragment::{Fragmenter, SendOutput};
+use mctp_lib::Sender;
+use openprot_mctp_api::{Handle, MctpClient, MctpError, MctpReqChannel, RecvMetadata, Stack};
+use openprot_mctp_server::Server;
+use telemetry::export::{self, command, status, Decoder, TELEMETRY_MSG_TYPE};
+use telemetry::{MetricValue, MetricsRegistry};
+
+/// MTU for MCTP payload (without header)
+const MCTP_MTU: usize = 255;
+/// MCTP header size (4 bytes)
+const MCTP_HEADER_SIZE: usize = 4;
+
+struct BufferSender<'a> {
+    packets: &'a RefCell<Vec<Vec<u8>>>,
+}
+
+impl Sender for BufferSender<'_> {
+    fn send_vectored(
+        &mut self,
+        mut fragmenter: Fragmenter,
+        payload: &[&[u8]],
+    ) -> mctp::Result<Tag> {
+        loop {
+            // Buffer must be MTU + header size
+            let mut buf = [0u8; MCTP_MTU + MCTP_HEADER_SIZE];
+            match fragmenter.fragment_vectored(payload, &mut buf) {
+                SendOutput::Packet(p) => self.packets.borrow_mut().push(p.to_vec()),
+                SendOutput::Complete { tag, .. } => return Ok(tag),
+                SendOutput::Error { err, .. } => return Err(err),
+            }
+        }
+    }
+
+    fn get_mtu(&self) -> usize {
+        MCTP_MTU
+    }
+}
+
+fn transfer<S: Sender, const N: usize>(packets: &RefCell<Vec<Vec<u8>>>, dest: &mut Server<S, N>) {
+    let pkts = packets.borrow();
+    for pkt in pkts.iter() {
+        dest.inbound(pkt).expect("inbound should accept packet");
+    }
+}
+
+struct DirectClient<'a, S: Sender, const N: usize> {
+    server: &'a RefCell<Server<S, N>>,
+}
+
+impl<'a, S: Sender, const N: usize> DirectClient<'a, S, N> {
+    fn new(server: &'a RefCell<Server<S, N>>) -> Self {
+        Self { server }
+    }
+}
+
+impl<S: Sender, const N: usize> MctpClient for DirectClient<'_, S, N> {
+    fn req(&self, eid: u8) -> Result<Handle, MctpError> {
+        self.server.borrow_mut().req(eid)
+    }
+
+    fn listener(&self, msg_type: u8) -> Result<Handle, MctpError> {
+        self.server.borrow_mut().listener(msg_type)
+    }
+
+    fn get_eid(&self) -> u8 {
+        self.server.borrow().get_eid()
+    }
+
+    fn set_eid(&self, eid: u8) -> Result<(), MctpError> {
+        self.server.borrow_mut().set_eid(eid)
+    }
+
+    fn recv(
+        &self,
+        handle: Handle,
+        _timeout_millis: u32,
+        buf: &mut [u8],
+    ) -> Result<RecvMetadata, MctpError> {
+        self.server
+            .borrow_mut()
+            .try_recv(handle, buf)
+            .ok_or(MctpError::from_code(
+                openprot_mctp_api::ResponseCode::TimedOut,
+            ))
+    }
+
+    fn send(
+        &self,
+        handle: Option<Handle>,
+        msg_type: u8,
+        eid: Option<u8>,
+        tag: Option<u8>,
+        integrity_check: bool,
+        buf: &[u8],
+    ) -> Result<u8, MctpError> {
+        self.server
+            .borrow_mut()
+            .send(handle, msg_type, eid, tag, integrity_check, buf)
+    }
+
+    fn drop_handle(&self, handle: Handle) {
+        let _ = self.server.borrow_mut().unbind(handle);
+    }
+}
+
+const PROT_EID: u8 = 8;
+const BMC_EID: u8 = 42;
+const VENDOR: [u8; 4] = [0x00, 0x00, 0xA5, 0x5A];
+const VENDOR_ID: u32 = 0xA55A;
+
+#[test]
+fn snapshot_round_trip_over_mctp() {
+    let buf_prot = RefCell::new(Vec::new());
+    let sender_prot = BufferSender { packets: &buf_prot };
+    let server_prot: RefCell<Server<_, 16>> =
+        RefCell::new(Server::new(Eid(PROT_EID), 0, sender_prot));
+
+    let buf_bmc = RefCell::new(Vec::new());
+    let sender_bmc = BufferSender { packets: &buf_bmc };
+    let server_bmc: RefCell<Server<_, 16>> = RefCell::new(Server::new(Eid(BMC_EID), 0, sender_bmc));
+
+    let stack_prot = Stack::new(DirectClient::new(&server_prot));
+    let stack_bmc = Stack::new(DirectClient::new(&server_bmc));
+
+    let mut registry = MetricsRegistry::<4>::new();
+    let rx = registry.register_counter("mctp.rx").unwrap();
+    let depth = registry.register_gauge("queue.depth").unwrap();
+    registry.counter(rx).add(1234);
+    registry.gauge(depth).set(-7);
+
+    let mut listener = stack_prot
+        .listener(TELEMETRY_MSG_TYPE, 0)
+        .expect("listener setup should succeed");
+    let mut req = stack_bmc
+        .req(PROT_EID, 0)
+        .expect("request channel should open");
+    let mut request = VENDOR.to_vec();
+    request.push(command::GET_SNAPSHOT);
+    req.send(TELEMETRY_MSG_TYPE, &request)
+        .expect("request send should succeed");
+
+    // Deliver the request BMC -> PRoT, serve it, then deliver PRoT -> BMC.
+    transfer(&buf_bmc, &mut server_prot.borrow_mut());
+    buf_bmc.borrow_mut().clear();
+
+    let mut buf = [0u8; 255];
+    let mut response = [0u8; 255];
+    export::serve_once(
+        &mut listener,
+        VENDOR_ID,
+        &registry.snapshot(),
+        &mut buf,
+        &mut response,
+    )
+    .expect("serve_once should receive and reply");
+
+    transfer(&buf_prot, &mut server_bmc.borrow_mut());
+    buf_prot.borrow_mut().clear();
+
+    let mut resp_buf = [0u8; 255];
+    let (meta, resp) = req.recv(&mut resp_buf).expect("response should arrive");
+    assert_eq!(meta.msg_type, TELEMETRY_MSG_TYPE);
+    assert_eq!(meta.remote_eid, PROT_EID);
+    assert_eq!(resp[..4], VENDOR);
+    assert_eq!(resp[4..6], [command::GET_SNAPSHOT, status::SUCCESS]);
+
+    let decoded: Vec<_> = Decoder::new(&resp[6..])
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        decoded,
+        [
+            ("mctp.rx", MetricValue::Counter(1234)),
+            ("queue.depth", MetricValue::Gauge(-7)),
+        ]
+    );
+}
@@ -0,0 +1,193 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+#![no_std]
+
+use kernel::sync::spinlock::SpinLock;
+use pw_status::{Error, Result};
+use registers::uart;
+
+mod rx_fifo;
+mod tx_ring;
+mod uart_nco;
+
+use tx_ring::TxRing;
+pub use uart_nco::uart_nco;
+
+/// Size of the software TX queue used in interrupt-driven mode.
+const TX_RING_SIZE: usize = 1024;
+
+struct Uart {
+    device: uart::Uart0,
+    /// Output waiting for the TX FIFO; only used in interrupt-driven mode.
+    tx_ring: TxRing<TX_RING_SIZE>,
+    /// Set once the platform routes the UART TX-watermark interrupt to
+    /// [`console_backend_tx_interrupt`].
+    tx_irq: bool,
+}
+
+impl Uart {
+    fn set_nco(&mut self, nco: u16) {
+        // Let queued output drain at the old rate.
+        self.flush_ring();
+        self.wait_tx_idle();
+        let reg = self.device.regs_mut();
+        reg.ctrl().modify(|ctrl| ctrl.nco(u32::from(nco)).tx(true));
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        if !self.tx_irq {
+            return self.write_all_polled(buf);
+        }
+        let mut rest = buf;
+        loop {
+            rest = &rest[self.tx_ring.enqueue(rest)..];
+            self.fill_fifo();
+            if rest.is_empty() {
+                break;
+            }
+            // The queue is full: fall back to waiting on the FIFO.
+            self.flush_ring();
+        }
+        self.set_tx_watermark_irq(!self.tx_ring.is_empty());
+        Ok(())
+    }
+
+    /// Moves queued output into the TX FIFO until it is full.
+    fn fill_fifo(&mut self) {
+        let reg = self.device.regs_mut();
+        self.tx_ring.drain(
+            || !reg.status().read().txfull(),
+            |byte| reg.wdata().write(|w| w.wdata(byte as u32)),
+        );
+    }
+
+    /// Busy-waits until all queued output is in the TX FIFO.
+    fn flush_ring(&mut self) {
+        while !self.tx_ring.is_empty() {
+            self.fill_fifo();
+        }
+    }
+
+    /// Busy-waits until the TX FIFO is empty and the last bit is sent.
+    fn wait_tx_idle(&mut self) {
+        let reg = self.device.regs_mut();
+        while !reg.status().read().txidle() {
+            // Wait for the line to go idle.
+        }
+    }
+
+    /// Writes `buf` after all queued output, without the software ring, and
+    /// returns once every byte is on the wire.
+    fn write_all_sync(&mut self, buf: &[u8]) -> Result<()> {
+        self.set_tx_watermark_irq(false);
+        self.flush_ring();
+        self.write_all_polled(buf)?;
+        self.wait_tx_idle();
+        Ok(())
+    }
+
+    fn set_tx_watermark_irq(&mut self, enable: bool) {
+        let reg = self.device.regs_mut();
+        reg.intr_enable().modify(|w| w.tx_watermark(enable));
+    }
+
+    fn write_all_polled(&mut self, buf: &[u8]) -> Result<()> {
+        let reg = self.device.regs_mut();
+        for &byte in buf.iter() {
+            while reg.status().read().txfull() {
+                // Wait while the FIFO is full.
+            }
+            reg.wdata().write(|w| w.wdata(byte as u32));
+        }
+        Ok(())
+    }
+
+    /// Copies the bytes already waiting in the RX FIFO into `buf`, without
+    /// blocking, and returns how many were copied.
+    fn read_available(&mut self, buf: &mut [u8]) -> usize {
+        let reg = self.device.regs_mut();
+        if !reg.ctrl().read().rx() {
+            reg.ctrl().modify(|ctrl| ctrl.rx(true));
+        }
+        rx_fifo::read_available(
+            buf,
+            || reg.status().read().rxempty(),
+            || reg.rdata().read().rdata() as u8,
+        )
+    }
+}
+
+static UART: SpinLock<arch_riscv::Arch, Uart> = SpinLock::new(Uart {
+    device: unsafe { uart::Uart0::new() },
+    tx_ring: TxRing::new(),
+    tx_irq: false,
+});
+
+/// Programs the console UART for `baud`, e.g. 921600 for fast log capture.
+///
+/// Output already queued is sent at the old rate first. Returns
+/// `OutOfRange` if `baud` is not achievable from the peripheral clock (see
+/// [`uart_nco`]); the UART is left unchanged.
+pub fn console_backend_init(baud: u32) -> Result<()> {
+    let nco =
+        uart_nco(baud, earlgrey_clock_domain::PERIPHERAL_CLOCK_HZ).ok_or(Error::OutOfRange)?;
+    UART.lock(arch_riscv::Arch).set_nco(nco);
+    Ok(())
+}
+
+#[unsafe(no_mangle)]
+pub fn console_backend_write_all(buf: &[u8]) -> Result<()> {
+    let mut uart = UART.lock(arch_riscv::Arch);
+    uart.write_all(buf)
+}
+
+/// Writes `buf` synchronously, for output that must not be left queued,
+/// such as a panic dump followed by a halt.
+///
+/// In interrupt-driven mode the software ring is drained first, so output
+/// stays in order. Returns once the UART has finished transmitting.
+#[unsafe(no_mangle)]
+pub fn console_backend_write_all_sync(buf: &[u8]) -> Result<()> {
+    let mut uart = UART.lock(arch_riscv::Arch);
+    uart.write_all_sync(buf)
+}
+
+/// Blocks until at least one byte has been received, then returns the bytes
+/// available, up to `buf.len()`.
+///
+/// The lock is only held while the FIFO is drained, never while waiting, so
+/// console output keeps flowing during a read.
+#[unsafe(no_mangle)]
+pub fn console_backend_read(buf: &mut [u8]) -> Result<usize> {
+    if buf.is_empty() {
+        return Ok(0);
+    }
+    loop {
+        let len = UART.lock(arch_riscv::Arch).read_available(buf);
+        if len > 0 {
+            return Ok(len);
+        }
+        // Wait while the FIFO is empty.
+    }
+}
+
+/// Switches console output to interrupt-driven mode.
+///
+/// Afterwards [`console_backend_write_all`] queues output in a software ring
+/// and returns; the platform must route the UART0 TX-watermark interrupt to
+/// [`console_backend_tx_interrupt`] before calling this. Without it, output
+/// busy-waits on the FIFO as before. When the ring fills up, writers fall
+/// back to busy-waiting rather than dropping output.
+pub fn console_backend_enable_tx_interrupt() {
+    UART.lock(arch_riscv::Arch).tx_irq = true;
+}
+
+/// UART0 TX-watermark interrupt handler: refills the FIFO from the software
+/// ring and masks the interrupt once the ring is empty.
+#[unsafe(no_mangle)]
+pub fn console_backend_tx_interrupt() {
+    let mut uart = UART.lock(arch_riscv::Arch);
+    uart.fill_fifo();
+    let pending = !uart.tx_ring.is_empty();
+    uart.set_tx_watermark_irq(pending);
+}
@@ -0,0 +1,130 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Postmortem panic dump for Earlgrey kernel images.
+//!
+//! On panic, [`dump_and_halt`] writes the panic message, a snapshot of the
+//! trap CSRs and a few core registers, and the telemetry [`RingLog`]
+//! registered with [`register_log`] to the console through
+//! `console_backend_write_all_sync`, then halts once the UART has sent it.
+//! See [`panic_format`] for the layout.
+//!
+//! Images whose kernel does not already provide a panic handler can enable
+//! the `panic_handler` crate feature, set by the
+//! `//target/earlgrey:panic_handler` build flag, to install one that calls
+//! [`dump_and_halt`].
+//!
+//! [`RingLog`]: telemetry::RingLog
+
+#![no_std]
+
+pub mod panic_format;
+
+use core::fmt::{self, Write};
+use core::panic::PanicInfo;
+
+use panic_format::{write_dump, DumpLog, RegisterSnapshot};
+
+mod console_backend {
+    unsafe extern "Rust" {
+        pub fn console_backend_write_all_sync(buf: &[u8]) -> pw_status::Result<()>;
+    }
+}
+
+/// Log listed in the dump, set by [`register_log`].
+///
+/// Held as a raw pointer rather than a reference: the rest of the system
+/// keeps recording into the log through its own `&mut`, and only the
+/// panic handler reads it, after the rules in [`register_log`] stop that.
+static mut PANIC_LOG: Option<*const dyn DumpLog> = None;
+
+/// Registers the log at `log` to be listed in the panic dump.
+///
+/// Nothing is read through `log` until a panic; the owner goes on
+/// recording into it as usual, e.g. through `&raw mut` on a `static mut`.
+///
+/// # Safety
+///
+/// - `log` must stay valid for the rest of the program, e.g. point to a
+///   static.
+/// - Must not race with a panic or another call, e.g. call it once during
+///   early init before threads and interrupts are enabled.
+/// - [`dump_and_halt`] masks interrupts and then reads the log through a
+///   shared reference. No `&mut` to the log may be live at that point:
+///   code that records into it must not panic, or be preempted by code
+///   that panics, while holding one.
+pub unsafe fn register_log(log: *const dyn DumpLog) {
+    // SAFETY: the caller guarantees exclusive access.
+    unsafe { PANIC_LOG = Some(log) };
+}
+
+/// Formats straight to the console, ignoring write errors: there is
+/// nowhere else to report them.
+struct Console;
+
+impl Write for Console {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // SAFETY: provided by the platform's console backend.
+        let _ = unsafe { console_backend::console_backend_write_all_sync(s.as_bytes()) };
+        Ok(())
+    }
+}
+
+/// Reads the trap CSRs and core registers.
+#[inline(always)]
+fn capture_registers() -> RegisterSnapshot {
+    let mut regs = RegisterSnapshot::default();
+    // SAFETY: reads machine-mode CSRs and registers without side effects.
+    unsafe {
+        core::arch::asm!(
+            "csrr {mcause}, mcause",
+            "csrr {mepc}, mepc",
+            "csrr {mtval}, mtval",
+            "csrr {mstatus}, mstatus",
+            "mv {ra}, ra",
+            "mv {sp}, sp",
+            "mv {gp}, gp",
+            "mv {tp}, tp",
+            mcause = out(reg) regs.mcause,
+            mepc = out(reg) regs.mepc,
+            mtval = out(reg) regs.mtval,
+            mstatus = out(reg) regs.mstatus,
+            ra = out(reg) regs.ra,
+            sp = out(reg) regs.sp,
+            gp = out(reg) regs.gp,
+            tp = out(reg) regs.tp,
+            options(nomem, nostack, preserves_flags),
+        );
+    }
+    regs
+}
+
+/// Masks machine interrupts, so nothing else runs during the dump.
+#[inline(always)]
+fn disable_interrupts() {
+    // SAFETY: clearing `mstatus.MIE` only stops interrupts being taken.
+    unsafe { core::arch::asm!("csrci mstatus, 0x8", options(nomem, nostack)) };
+}
+
+/// Writes the panic dump to the console and halts.
+///
+/// Each line is written synchronously, so the whole dump has left the UART
+/// when the hart halts. The console lock is taken for every line, so a
+/// panic raised while the console itself is locked hangs before printing.
+pub fn dump_and_halt(info: &PanicInfo) -> ! {
+    let regs = capture_registers();
+    disable_interrupts();
+    // SAFETY: `register_log` is only called before a panic can occur, and
+    // its caller guarantees no `&mut` to the log is live now.
+    let log = unsafe { PANIC_LOG }.map(|log| unsafe { &*log });
+    let _ = write_dump(&mut Console, &info.message(), &regs, log);
+
+    #[expect(clippy::empty_loop)]
+    loop {}
+}
+
+#[cfg(feature = "panic_handler")]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    dump_and_halt(info)
+}
@@ -0,0 +1,189 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Text layout of the panic dump, kept free of hardware dependencies so it
+//! can be unit-tested on the host.
+//!
+//! ```text
+//! *** PANIC: <message>
+//! regs: mcause=0x00000002 mepc=0x20001234 mtval=0x00000000 mstatus=0x00001880
+//! regs: ra=0x20000100 sp=0x10001f00 gp=0x10000800 tp=0x00000000
+//! log: 2 entries, 0 overwritten
+//! log: [12] WARN code=0x00000101 args=1,2
+//! log: [15] ERROR code=0x00000102 args=
+//! *** END PANIC DUMP
+//! ```
+
+use core::fmt::{self, Display, Write};
+
+use telemetry::{Clock, Level, LogEntry, RingLog};
+
+/// Register values captured when the panic handler runs.
+///
+/// The trap CSRs describe the most recent trap, which is the fault itself
+/// when the panic was raised from an exception handler.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegisterSnapshot {
+    pub mcause: u32,
+    pub mepc: u32,
+    pub mtval: u32,
+    pub mstatus: u32,
+    pub ra: u32,
+    pub sp: u32,
+    pub gp: u32,
+    pub tp: u32,
+}
+
+/// A log that can be listed in the panic dump.
+///
+/// Implemented for every [`RingLog`], so a target can register its log
+/// without naming the log's capacity or clock.
+pub trait DumpLog {
+    /// Number of entries lost to wraparound.
+    fn overwritten(&self) -> u32;
+
+    /// Calls `f` with each stored entry, oldest first.
+    fn for_each_entry(&self, f: &mut dyn FnMut(&LogEntry) -> fmt::Result) -> fmt::Result;
+}
+
+impl<const N: usize, C: Clock> DumpLog for RingLog<N, C> {
+    fn overwritten(&self) -> u32 {
+        RingLog::overwritten(self)
+    }
+
+    fn for_each_entry(&self, f: &mut dyn FnMut(&LogEntry) -> fmt::Result) -> fmt::Result {
+        self.iter().try_for_each(f)
+    }
+}
+
+fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "ERROR",
+        Level::Warn => "WARN",
+        Level::Info => "INFO",
+        Level::Debug => "DEBUG",
+        Level::Trace => "TRACE",
+    }
+}
+
+/// Writes the full dump: the panic message, `regs` and, if present, every
+/// entry of `log`. Lines end in `\r\n` for the serial console.
+pub fn write_dump(
+    out: &mut dyn Write,
+    message: &dyn Display,
+    regs: &RegisterSnapshot,
+    log: Option<&dyn DumpLog>,
+) -> fmt::Result {
+    write!(out, "*** PANIC: {message}\r\n")?;
+    write!(
+        out,
+        "regs: mcause={:#010x} mepc={:#010x} mtval={:#010x} mstatus={:#010x}\r\n",
+        regs.mcause, regs.mepc, regs.mtval, regs.mstatus
+    )?;
+    write!(
+        out,
+        "regs: ra={:#010x} sp={:#010x} gp={:#010x} tp={:#010x}\r\n",
+        regs.ra, regs.sp, regs.gp, regs.tp
+    )?;
+    match log {
+        Some(log) => {
+            let mut count = 0usize;
+            log.for_each_entry(&mut |_| {
+                count += 1;
+                Ok(())
+            })?;
+            write!(
+                out,
+                "log: {count} entries, {} overwritten\r\n",
+                log.overwritten()
+            )?;
+            log.for_each_entry(&mut |entry| write_entry(out, entry))?;
+        }
+        None => out.write_str("log: none registered\r\n")?,
+    }
+    out.write_str("*** END PANIC DUMP\r\n")
+}
+
+fn write_entry(out: &mut dyn Write, entry: &LogEntry) -> fmt::Result {
+    write!(
+        out,
+        "log: [{}] {} code={:#010x} args=",
+        entry.timestamp_ms,
+        level_name(entry.level),
+        entry.code
+    )?;
+    for (i, arg) in entry.args().iter().enumerate() {
+        if i > 0 {
+            out.write_char(',')?;
+        }
+        write!(out, "{arg}")?;
+    }
+    out.write_str("\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use telemetry::mock::MockClock;
+
+    const REGS: RegisterSnapshot = RegisterSnapshot {
+        mcause: 0x2,
+        mepc: 0x2000_1234,
+        mtval: 0,
+        mstatus: 0x1880,
+        ra: 0x2000_0100,
+        sp: 0x1000_1f00,
+        gp: 0x1000_0800,
+        tp: 0,
+    };
+
+    fn dump(log: Option<&dyn DumpLog>) -> String {
+        let mut out = String::new();
+        write_dump(&mut out, &"index out of bounds", &REGS, log).unwrap();
+        out
+    }
+
+    #[test]
+    fn dumps_registers_and_log_entries() {
+        let clock = MockClock::new(12);
+        let mut log = RingLog::<4, _>::with_clock(&clock);
+        log.record(Level::Warn, 0x101, &[1, 2]);
+        clock.advance(3);
+        log.record(Level::Error, 0x102, &[]);
+
+        let lines: Vec<_> = dump(Some(&log)).split("\r\n").map(String::from).collect();
+        assert_eq!(
+            lines,
+            [
+                "*** PANIC: index out of bounds",
+                "regs: mcause=0x00000002 mepc=0x20001234 mtval=0x00000000 mstatus=0x00001880",
+                "regs: ra=0x20000100 sp=0x10001f00 gp=0x10000800 tp=0x00000000",
+                "log: 2 entries, 0 overwritten",
+                "log: [12] WARN code=0x00000101 args=1,2",
+                "log: [15] ERROR code=0x00000102 args=",
+                "*** END PANIC DUMP",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn dumps_oldest_entries_first_after_wraparound() {
+        let mut log = RingLog::<2>::new();
+        for code in 1..=3 {
+            log.record(Level::Info, code, &[code]);
+        }
+        let out = dump(Some(&log));
+        assert!(out.contains(
+            "log: 2 entries, 1 overwritten\r\n\
+             log: [0] INFO code=0x00000002 args=2\r\n\
+             log: [0] INFO code=0x00000003 args=3\r\n"
+        ));
+    }
+
+    #[test]
+    fn notes_missing_log() {
+        let out = dump(None);
+        assert!(out.contains("log: none registered\r\n*** END PANIC DUMP\r\n"));
+    }
+}
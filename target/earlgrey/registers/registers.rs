@@ -0,0 +1,41 @@
+#![no_std]
+pub extern crate adc_ctrl;
+pub extern crate aes;
+pub extern crate alert_handler;
+pub extern crate aon_timer;
+pub extern crate ast;
+pub extern crate clkmgr;
+pub extern crate csrng;
+pub extern crate edn;
+pub extern crate entropy_src;
+pub extern crate flash_ctrl_core;
+pub extern crate flash_ctrl_mem;
+pub extern crate gpio;
+pub extern crate hmac;
+pub extern crate i2c;
+pub extern crate keymgr;
+pub extern crate kmac;
+pub extern crate lc_ctrl;
+pub extern crate otbn;
+pub extern crate otp_ctrl_core;
+pub extern crate otp_ctrl_prim;
+pub extern crate pattgen;
+pub extern crate pinmux;
+pub extern crate pwm;
+pub extern crate pwrmgr;
+pub extern crate rom_ctrl_regs;
+pub extern crate rom_ctrl_rom;
+pub extern crate rstmgr;
+pub extern crate rv_core_ibex;
+pub extern crate rv_dm_mem;
+pub extern crate rv_dm_regs;
+pub extern crate rv_plic;
+pub extern crate rv_timer;
+pub extern crate sensor_ctrl;
+pub extern crate spi_device;
+pub extern crate spi_host;
+pub extern crate sram_ctrl_ram;
+pub extern crate sram_ctrl_regs;
+pub extern crate sysrst_ctrl;
+pub extern crate uart;
+pub extern crate usbdev;
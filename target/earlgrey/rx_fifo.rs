@@ -0,0 +1,68 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Non-blocking console input, kept free of hardware dependencies so it can
+//! be unit-tested on the host.
+
+/// Copies bytes from `read` into `buf` until it is full or `empty` reports
+/// that nothing more is waiting, and returns how many were copied.
+///
+/// `empty` is checked before every byte, e.g. against the UART's `rxempty`
+/// flag, so this never waits for input.
+pub fn read_available(
+    buf: &mut [u8],
+    mut empty: impl FnMut() -> bool,
+    mut read: impl FnMut() -> u8,
+) -> usize {
+    for (len, byte) in buf.iter_mut().enumerate() {
+        if empty() {
+            return len;
+        }
+        *byte = read();
+    }
+    buf.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    /// Reads from `fifo` as the UART would, leaving unread bytes in place.
+    fn read_from(fifo: &mut VecDeque<u8>, buf: &mut [u8]) -> usize {
+        let fifo = RefCell::new(fifo);
+        read_available(
+            buf,
+            || fifo.borrow().is_empty(),
+            || fifo.borrow_mut().pop_front().unwrap(),
+        )
+    }
+
+    #[test]
+    fn returns_fewer_bytes_than_requested() {
+        let mut fifo = VecDeque::from(*b"ok\r");
+        let mut buf = [0u8; 8];
+        assert_eq!(read_from(&mut fifo, &mut buf), 3);
+        assert_eq!(&buf[..3], b"ok\r");
+        assert_eq!(buf[3..], [0; 5]);
+        assert!(fifo.is_empty());
+    }
+
+    #[test]
+    fn empty_fifo_reads_nothing() {
+        let mut fifo = VecDeque::new();
+        let mut buf = [0xAAu8; 4];
+        assert_eq!(read_from(&mut fifo, &mut buf), 0);
+        assert_eq!(buf, [0xAA; 4]);
+    }
+
+    #[test]
+    fn stops_when_buffer_is_full() {
+        let mut fifo = VecDeque::from(*b"hello");
+        let mut buf = [0u8; 2];
+        assert_eq!(read_from(&mut fifo, &mut buf), 2);
+        assert_eq!(&buf, b"he");
+        assert_eq!(fifo, b"llo");
+    }
+}
@@ -0,0 +1,47 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test shutdown for Earlgrey images.
+//!
+//! [`shutdown`] logs the result, drains the console, and reports it through
+//! the Ibex DV-sim test-status register, which makes QEMU exit with a
+//! matching status. Hardware without a simulator attached ignores the write,
+//! so the core then halts.
+
+#![no_std]
+
+pub mod test_status;
+
+use registers::rv_core_ibex::RvCoreIbex;
+use test_status::{result_message, test_status};
+
+mod console_backend {
+    unsafe extern "Rust" {
+        pub fn console_backend_write_all_sync(buf: &[u8]) -> pw_status::Result<()>;
+    }
+}
+
+/// Reports a test result and halts: `code` zero passes, anything else fails.
+pub fn shutdown(code: u32) -> ! {
+    pw_log::info!("Shutting down with code {}", code as u32);
+    let message = result_message(code);
+    match code {
+        0 => pw_log::info!("{}", message as &str),
+        _ => pw_log::info!("{}: {}", message as &str, code as u32),
+    };
+
+    // The runner matches on the lines above, so they must reach the UART
+    // before QEMU exits. An empty write just drains what the log queued.
+    // SAFETY: provided by the console backend linked into every image.
+    let _ = unsafe { console_backend::console_backend_write_all_sync(&[]) };
+
+    // SAFETY: the image is shutting down, so nothing else uses the wrapper.
+    let mut ibex = unsafe { RvCoreIbex::new() };
+    ibex.regs_mut()
+        .dv_sim_window()
+        .at(0)
+        .write(|_| test_status(code));
+
+    #[expect(clippy::empty_loop)]
+    loop {}
+}
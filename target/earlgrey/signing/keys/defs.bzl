@@ -0,0 +1,5 @@
+# Licensed under the Apache-2.0 license
+# SPDX-License-Identifier: Apache-2.0
+
+FPGA_ECDSA_KEY = {"//target/earlgrey/signing/keys:fpga_keyset": "app_prod_0"}
+SILICON_ECDSA_KEY = {"//target/earlgrey/signing/keys:gb00_keyset": "gb00-app-key-prod-0"}
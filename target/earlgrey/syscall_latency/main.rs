@@ -0,0 +1,113 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+#![no_main]
+#![no_std]
+
+mod stats;
+
+use kernel_config::{KernelConfig, KernelConfigInterface};
+use pw_status::{Error, Result};
+use registers::rv_timer::RvTimer;
+use stats::LatencyStats;
+use userspace::{entry, syscall};
+
+const PERIPHERAL_CLOCK_HZ: u64 = earlgrey_clock_domain::PERIPHERAL_CLOCK_HZ;
+
+/// Number of syscalls measured per run.
+const ITERATIONS: usize = 100;
+
+/// Reads the 64-bit `cycle` CSR. The kernel side of this image sets
+/// `mcounteren.CY` so these reads do not trap in U-mode.
+#[inline(always)]
+fn cycle_count() -> u64 {
+    loop {
+        let hi1: u32;
+        let low: u32;
+        let hi2: u32;
+        // SAFETY: reading the cycle counter has no side effects.
+        unsafe {
+            core::arch::asm!(
+                "csrr {hi1}, cycleh",
+                "csrr {low}, cycle",
+                "csrr {hi2}, cycleh",
+                hi1 = out(reg) hi1,
+                low = out(reg) low,
+                hi2 = out(reg) hi2,
+                options(nomem, nostack, preserves_flags),
+            );
+        }
+        if hi1 == hi2 {
+            return ((hi1 as u64) << 32) | (low as u64);
+        }
+    }
+}
+
+#[inline(always)]
+fn rv_timer_value(rv_timer: &RvTimer) -> u64 {
+    let regs = rv_timer.regs();
+    loop {
+        let hi1 = regs.timer_v_upper0().read();
+        let low = regs.timer_v_lower0().read();
+        let hi2 = regs.timer_v_upper0().read();
+        if hi1 == hi2 {
+            return ((hi1 as u64) << 32) | (low as u64);
+        }
+    }
+}
+
+fn measure_nop_syscall(rv_timer: &RvTimer, n: usize) -> Result<()> {
+    let mut total = 0u64;
+    for _ in 0..n {
+        let t0 = rv_timer_value(&rv_timer);
+        syscall::debug_nop()?;
+        let t1 = rv_timer_value(&rv_timer);
+        total += t1 - t0;
+    }
+    pw_log::info!(
+        "Performed {} syscalls in {} rv_timer ticks.",
+        n as usize,
+        total as u64,
+    );
+    let average = total / (n as u64);
+    pw_log::info!("Average latency: {} rv_timer ticks", average as u64);
+
+    let cpu_clocks = average * KernelConfig::SYSTEM_CLOCK_HZ / PERIPHERAL_CLOCK_HZ;
+    pw_log::info!("Average latency: {} cpu clocks", cpu_clocks as u64);
+    Ok(())
+}
+
+/// Measures each syscall round trip in CPU cycles and reports the results
+/// on one line for the test harness:
+///
+/// `SYSCALL_LATENCY iterations=<n> min=<cycles> mean=<cycles> max=<cycles>`
+fn measure_nop_syscall_cycles() -> Result<()> {
+    let mut samples = [0u32; ITERATIONS];
+    for sample in samples.iter_mut() {
+        let t0 = cycle_count();
+        syscall::debug_nop()?;
+        let t1 = cycle_count();
+        *sample = u32::try_from(t1 - t0).unwrap_or(u32::MAX);
+    }
+    let stats = LatencyStats::from_samples(&samples).ok_or(Error::Internal)?;
+    pw_log::info!(
+        "SYSCALL_LATENCY iterations={} min={} mean={} max={}",
+        stats.count as usize,
+        stats.min as u32,
+        stats.mean as u32,
+        stats.max as u32,
+    );
+    Ok(())
+}
+
+#[entry]
+fn entry() -> Result<()> {
+    let rv_timer = unsafe { RvTimer::new() };
+    measure_nop_syscall(&rv_timer, ITERATIONS)?;
+    measure_nop_syscall_cycles()
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    pw_log::error!("PANIC: syscall latency");
+    loop {}
+}
@@ -0,0 +1,76 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Summary statistics over latency samples, kept free of hardware
+//! dependencies so they can be unit-tested on the host.
+
+/// Minimum, mean and maximum of a set of samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyStats {
+    /// Number of samples.
+    pub count: usize,
+    /// Smallest sample.
+    pub min: u32,
+    /// Arithmetic mean, rounded down.
+    pub mean: u32,
+    /// Largest sample.
+    pub max: u32,
+}
+
+impl LatencyStats {
+    /// Summarizes `samples`, or returns `None` if there are none.
+    pub fn from_samples(samples: &[u32]) -> Option<Self> {
+        let (&first, rest) = samples.split_first()?;
+        let mut min = first;
+        let mut max = first;
+        let mut total = u64::from(first);
+        for &sample in rest {
+            min = min.min(sample);
+            max = max.max(sample);
+            total += u64::from(sample);
+        }
+        Some(Self {
+            count: samples.len(),
+            min,
+            // The mean of `u32` samples always fits in a `u32`.
+            mean: (total / samples.len() as u64) as u32,
+            max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = LatencyStats::from_samples(&[120, 95, 310, 101, 99]).unwrap();
+        assert_eq!(
+            stats,
+            LatencyStats {
+                count: 5,
+                min: 95,
+                mean: 145,
+                max: 310,
+            }
+        );
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = LatencyStats::from_samples(&[42]).unwrap();
+        assert_eq!((stats.min, stats.mean, stats.max), (42, 42, 42));
+    }
+
+    #[test]
+    fn mean_does_not_overflow() {
+        let stats = LatencyStats::from_samples(&[u32::MAX, u32::MAX, u32::MAX - 2]).unwrap();
+        assert_eq!(stats.mean, u32::MAX - 1);
+    }
+
+    #[test]
+    fn empty_has_no_stats() {
+        assert_eq!(LatencyStats::from_samples(&[]), None);
+    }
+}
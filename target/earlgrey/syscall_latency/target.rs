@@ -0,0 +1,36 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+#![no_std]
+#![no_main]
+
+use target_common::{declare_target, TargetInterface};
+use {console_backend as _, entry as _, kernel as _};
+
+pub struct Target {}
+
+impl TargetInterface for Target {
+    const NAME: &'static str = "Earlgrey Syscall Latency Test";
+
+    fn main() -> ! {
+        enable_user_cycle_counter();
+        codegen::start();
+        loop {}
+    }
+
+    fn shutdown(code: u32) -> ! {
+        earlgrey_shutdown::shutdown(code)
+    }
+}
+
+/// Lets U-mode read the `cycle`/`cycleh` CSRs, which the app uses to time
+/// syscalls. Without `mcounteren.CY` those reads trap.
+///
+/// Only this measurement image grants it; other images keep the counter
+/// hidden from userspace.
+fn enable_user_cycle_counter() {
+    // SAFETY: setting `mcounteren.CY` (bit 0) only makes the cycle counter
+    // readable from lower privilege modes.
+    unsafe { core::arch::asm!("csrsi mcounteren, 0x1", options(nomem, nostack)) };
+}
+
+declare_target!(Target);
@@ -0,0 +1,59 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mapping from a test's shutdown code to the result line the runner matches
+//! on and the value written to the Ibex DV-sim test-status register, kept
+//! free of hardware dependencies so it can be unit-tested on the host.
+
+/// Status reported for a passing test.
+pub const TEST_STATUS_PASSED: u32 = 0x900d;
+
+/// Status reported for a failing test.
+pub const TEST_STATUS_FAILED: u32 = 0xbaad;
+
+/// Returns the test status for shutdown `code`: zero passes, anything else
+/// fails.
+pub const fn test_status(code: u32) -> u32 {
+    match code {
+        0 => TEST_STATUS_PASSED,
+        _ => TEST_STATUS_FAILED,
+    }
+}
+
+/// Returns the result line logged for shutdown `code`: `PASS` for zero,
+/// `FAIL` (followed by the code) otherwise. Agrees with [`test_status`].
+pub const fn result_message(code: u32) -> &'static str {
+    match code {
+        0 => "PASS",
+        _ => "FAIL",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_passes() {
+        assert_eq!(test_status(0), TEST_STATUS_PASSED);
+    }
+
+    #[test]
+    fn nonzero_fails() {
+        assert_eq!(test_status(1), TEST_STATUS_FAILED);
+        assert_eq!(test_status(0x900d), TEST_STATUS_FAILED);
+        assert_eq!(test_status(u32::MAX), TEST_STATUS_FAILED);
+    }
+
+    #[test]
+    fn message_matches_status() {
+        for code in [0, 1, 0x900d, 0xbaad, u32::MAX] {
+            let (message, status) = match code {
+                0 => ("PASS", TEST_STATUS_PASSED),
+                _ => ("FAIL", TEST_STATUS_FAILED),
+            };
+            assert_eq!(result_message(code), message);
+            assert_eq!(test_status(code), status);
+        }
+    }
+}
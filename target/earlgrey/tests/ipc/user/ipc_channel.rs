@@ -0,0 +1,69 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed message channel over the kernel IPC syscalls.
+//!
+//! Userspace tasks exchange [`IpcMessage`] values instead of raw bytes. The
+//! initiator side of a channel calls [`IpcChannel::send`], which blocks
+//! until the handler has taken the message; the handler side calls
+//! [`IpcChannel::recv`], which waits for a message, decodes it and
+//! acknowledges it with an empty response.
+//!
+//! ```rust,ignore
+//! let channel = IpcChannel::new(handle::IPC);
+//! channel.send(&Ping { seq: 1 })?;        // initiator
+//! let ping: Ping = channel.recv()?;        // handler
+//! ```
+
+#![no_std]
+
+mod message;
+
+pub use message::{decode, encode, IpcMessage, MAX_MESSAGE_SIZE, TAG_SIZE};
+
+use pw_status::{Error, Result};
+use userspace::syscall::{self, Signals};
+use userspace::time::Instant;
+
+/// One end of a kernel IPC channel carrying typed messages.
+pub struct IpcChannel {
+    handle: u32,
+}
+
+impl IpcChannel {
+    /// Wrap the channel object `handle`, as found in the application's
+    /// generated handle module (e.g. `handle::IPC`).
+    pub const fn new(handle: u32) -> Self {
+        Self { handle }
+    }
+
+    /// Send `msg` from the initiator side and wait until the handler has
+    /// received it.
+    ///
+    /// Returns `InvalidArgument` if the message does not fit in
+    /// [`MAX_MESSAGE_SIZE`] bytes and `DataLoss` if the handler answers
+    /// with anything but an empty acknowledgement.
+    pub fn send<T: IpcMessage>(&self, msg: &T) -> Result<()> {
+        let mut buf = [0u8; MAX_MESSAGE_SIZE];
+        let len = encode(msg, &mut buf).ok_or(Error::InvalidArgument)?;
+        let mut ack = [0u8; 0];
+        let resp_len = syscall::channel_transact(self.handle, &buf[..len], &mut ack, Instant::MAX)?;
+        if resp_len != 0 {
+            return Err(Error::DataLoss);
+        }
+        Ok(())
+    }
+
+    /// Wait on the handler side for a message of type `T`.
+    ///
+    /// The message is acknowledged whether or not it decodes, so the
+    /// initiator is never left blocked. Returns `DataLoss` if the message's
+    /// tag or length does not match `T`.
+    pub fn recv<T: IpcMessage>(&self) -> Result<T> {
+        syscall::object_wait(self.handle, Signals::READABLE, Instant::MAX)?;
+        let mut buf = [0u8; MAX_MESSAGE_SIZE];
+        let len = syscall::channel_read(self.handle, 0, &mut buf)?;
+        syscall::channel_respond(self.handle, &[])?;
+        buf.get(..len).and_then(decode::<T>).ok_or(Error::DataLoss)
+    }
+}
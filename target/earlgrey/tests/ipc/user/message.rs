@@ -0,0 +1,157 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wire format for typed IPC messages, kept free of syscall dependencies so
+//! it can be unit-tested on the host.
+//!
+//! A message is encoded as a one-byte type tag followed by the message body
+//! as written by [`IpcMessage::encode_body`]. The tag lets the receiver
+//! reject a message of the wrong type instead of misreading its bytes.
+
+/// Largest encoded message, tag included, that a channel carries.
+pub const MAX_MESSAGE_SIZE: usize = 64;
+
+/// Size of the type tag that precedes every message body.
+pub const TAG_SIZE: usize = 1;
+
+/// A message that can be sent over a typed IPC channel.
+pub trait IpcMessage: Sized {
+    /// Tag identifying this message type on the wire.
+    const TAG: u8;
+
+    /// Encoded body size in bytes; with the tag it must not exceed
+    /// [`MAX_MESSAGE_SIZE`].
+    const BODY_SIZE: usize;
+
+    /// Write the body into `out`, which is exactly
+    /// [`BODY_SIZE`](Self::BODY_SIZE) bytes long.
+    fn encode_body(&self, out: &mut [u8]);
+
+    /// Read a body written by [`encode_body`](Self::encode_body).
+    ///
+    /// `body` is exactly [`BODY_SIZE`](Self::BODY_SIZE) bytes long.
+    /// Returns `None` if the body does not hold a valid message.
+    fn decode_body(body: &[u8]) -> Option<Self>;
+}
+
+/// Encode `msg` with its tag at the start of `buf`.
+///
+/// Returns the number of bytes written, or `None` if `buf` is too small.
+pub fn encode<T: IpcMessage>(msg: &T, buf: &mut [u8]) -> Option<usize> {
+    let len = TAG_SIZE + T::BODY_SIZE;
+    let out = buf.get_mut(..len)?;
+    out[0] = T::TAG;
+    msg.encode_body(&mut out[TAG_SIZE..]);
+    Some(len)
+}
+
+/// Decode a message of type `T` from `bytes`.
+///
+/// Returns `None` if the tag does not match `T::TAG`, the length does not
+/// match `T::BODY_SIZE`, or `T` rejects the body.
+pub fn decode<T: IpcMessage>(bytes: &[u8]) -> Option<T> {
+    let (&tag, body) = bytes.split_first()?;
+    if tag != T::TAG || body.len() != T::BODY_SIZE {
+        return None;
+    }
+    T::decode_body(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sample request: a counter and a flag.
+    #[derive(Debug, PartialEq)]
+    struct Ping {
+        seq: u32,
+        value: u16,
+        urgent: bool,
+    }
+
+    impl IpcMessage for Ping {
+        const TAG: u8 = 0x01;
+        const BODY_SIZE: usize = 7;
+
+        fn encode_body(&self, out: &mut [u8]) {
+            out[..4].copy_from_slice(&self.seq.to_le_bytes());
+            out[4..6].copy_from_slice(&self.value.to_le_bytes());
+            out[6] = self.urgent as u8;
+        }
+
+        fn decode_body(body: &[u8]) -> Option<Self> {
+            let urgent = match body[6] {
+                0 => false,
+                1 => true,
+                _ => return None,
+            };
+            Some(Self {
+                seq: u32::from_le_bytes(body[..4].try_into().ok()?),
+                value: u16::from_le_bytes(body[4..6].try_into().ok()?),
+                urgent,
+            })
+        }
+    }
+
+    /// A message with no body.
+    #[derive(Debug, PartialEq)]
+    struct Ack;
+
+    impl IpcMessage for Ack {
+        const TAG: u8 = 0x02;
+        const BODY_SIZE: usize = 0;
+
+        fn encode_body(&self, _out: &mut [u8]) {}
+
+        fn decode_body(_body: &[u8]) -> Option<Self> {
+            Some(Self)
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let msg = Ping {
+            seq: 0x1234_5678,
+            value: 0xBEEF,
+            urgent: true,
+        };
+        let mut buf = [0u8; MAX_MESSAGE_SIZE];
+        let len = encode(&msg, &mut buf).unwrap();
+        assert_eq!(len, TAG_SIZE + Ping::BODY_SIZE);
+        assert_eq!(buf[..len], [0x01, 0x78, 0x56, 0x34, 0x12, 0xEF, 0xBE, 0x01]);
+        assert_eq!(decode::<Ping>(&buf[..len]), Some(msg));
+    }
+
+    #[test]
+    fn empty_body_roundtrip() {
+        let mut buf = [0u8; MAX_MESSAGE_SIZE];
+        let len = encode(&Ack, &mut buf).unwrap();
+        assert_eq!(buf[..len], [0x02]);
+        assert_eq!(decode::<Ack>(&buf[..len]), Some(Ack));
+    }
+
+    #[test]
+    fn encode_rejects_short_buffer() {
+        let mut buf = [0u8; 4];
+        let msg = Ping {
+            seq: 1,
+            value: 2,
+            urgent: false,
+        };
+        assert_eq!(encode(&msg, &mut buf), None);
+    }
+
+    #[test]
+    fn decode_rejects_wrong_tag_or_length() {
+        assert_eq!(decode::<Ping>(&[]), None);
+        assert_eq!(decode::<Ping>(&[0x02, 0, 0, 0, 0, 0, 0, 0]), None);
+        assert_eq!(decode::<Ping>(&[0x01, 0, 0, 0, 0, 0, 0]), None);
+        assert_eq!(decode::<Ping>(&[0x01, 0, 0, 0, 0, 0, 0, 0, 0]), None);
+        assert_eq!(decode::<Ack>(&[0x01]), None);
+    }
+
+    #[test]
+    fn decode_rejects_invalid_body() {
+        assert_eq!(decode::<Ping>(&[0x01, 0, 0, 0, 0, 0, 0, 2]), None);
+    }
+}
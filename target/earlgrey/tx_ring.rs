@@ -0,0 +1,119 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! Software TX queue for interrupt-driven console output, kept free of
+//! hardware dependencies so it can be unit-tested on the host.
+
+/// A fixed-size byte FIFO.
+pub struct TxRing<const N: usize> {
+    buf: [u8; N],
+    /// Index of the oldest queued byte.
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> TxRing<N> {
+    /// An empty queue.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Queues as much of `bytes` as fits and returns how many were queued.
+    pub fn enqueue(&mut self, bytes: &[u8]) -> usize {
+        let count = bytes.len().min(N - self.len);
+        for &byte in &bytes[..count] {
+            self.buf[(self.head + self.len) % N] = byte;
+            self.len += 1;
+        }
+        count
+    }
+
+    /// Moves queued bytes, oldest first, into `write` for as long as `ready`
+    /// reports room, and returns how many were moved.
+    ///
+    /// `ready` is checked before every byte, e.g. against the UART's
+    /// `txfull` flag.
+    pub fn drain(&mut self, mut ready: impl FnMut() -> bool, mut write: impl FnMut(u8)) -> usize {
+        let mut moved = 0;
+        while self.len > 0 && ready() {
+            write(self.buf[self.head]);
+            self.head = (self.head + 1) % N;
+            self.len -= 1;
+            moved += 1;
+        }
+        moved
+    }
+
+    /// Returns `true` if nothing is queued.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize> Default for TxRing<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn enqueue_then_drain_in_order() {
+        let mut ring = TxRing::<8>::new();
+        assert_eq!(ring.enqueue(b"hello"), 5);
+        assert!(!ring.is_empty());
+
+        let mut out = Vec::new();
+        assert_eq!(ring.drain(|| true, |b| out.push(b)), 5);
+        assert_eq!(out, b"hello");
+        assert!(ring.is_empty());
+    }
+
+    #[test]
+    fn enqueue_stops_when_full() {
+        let mut ring = TxRing::<4>::new();
+        assert_eq!(ring.enqueue(b"abcdef"), 4);
+        assert_eq!(ring.enqueue(b"g"), 0);
+    }
+
+    #[test]
+    fn drain_stops_when_fifo_full() {
+        let mut ring = TxRing::<8>::new();
+        ring.enqueue(b"abcdef");
+
+        // Room for three bytes in the hardware FIFO.
+        let room = Cell::new(3);
+        let mut out = Vec::new();
+        let moved = ring.drain(
+            || room.get() > 0,
+            |b| {
+                room.set(room.get() - 1);
+                out.push(b);
+            },
+        );
+        assert_eq!(moved, 3);
+        assert_eq!(out, b"abc");
+        assert_eq!(ring.drain(|| true, |b| out.push(b)), 3);
+        assert_eq!(out, b"abcdef");
+    }
+
+    #[test]
+    fn wraps_around_the_buffer() {
+        let mut ring = TxRing::<4>::new();
+        let mut out = Vec::new();
+        ring.enqueue(b"abc");
+        ring.drain(|| true, |b| out.push(b));
+        // head is now at index 3; the next bytes wrap to the front.
+        assert_eq!(ring.enqueue(b"defg"), 4);
+        ring.drain(|| true, |b| out.push(b));
+        assert_eq!(out, b"abcdefg");
+    }
+}
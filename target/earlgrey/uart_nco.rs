@@ -0,0 +1,58 @@
+// Licensed under the Apache-2.0 license
+// SPDX-License-Identifier: Apache-2.0
+
+//! UART baud-rate divisor math, kept free of hardware dependencies so it can
+//! be unit-tested on the host.
+
+/// Largest baud-rate error, in parts per thousand, accepted when picking an
+/// NCO value; UART framing tolerates a few percent.
+pub const MAX_BAUD_ERROR_PERMILLE: u64 = 20;
+
+/// Computes the `CTRL.NCO` value that runs the UART at `baud` from a
+/// `clock_hz` peripheral clock.
+///
+/// The UART samples at `NCO * clock_hz / 2^20` bits per second. Returns
+/// `None` if no 16-bit NCO value gets within [`MAX_BAUD_ERROR_PERMILLE`] of
+/// the requested rate.
+pub const fn uart_nco(baud: u32, clock_hz: u64) -> Option<u16> {
+    if baud == 0 || clock_hz == 0 {
+        return None;
+    }
+    let baud = baud as u64;
+    // Round to the nearest NCO value.
+    let nco = ((baud << 20) + clock_hz / 2) / clock_hz;
+    if nco == 0 || nco > u16::MAX as u64 {
+        return None;
+    }
+    let actual = (nco * clock_hz) >> 20;
+    let error = actual.abs_diff(baud);
+    if error * 1000 > baud * MAX_BAUD_ERROR_PERMILLE {
+        return None;
+    }
+    Some(nco as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nco_for_common_rates() {
+        // Silicon and QEMU peripheral clock.
+        assert_eq!(uart_nco(115_200, 24_000_000), Some(5033));
+        assert_eq!(uart_nco(921_600, 24_000_000), Some(40265));
+        // FPGA peripheral clock.
+        assert_eq!(uart_nco(115_200, 6_000_000), Some(20133));
+    }
+
+    #[test]
+    fn unachievable_rates_rejected() {
+        // NCO would exceed 16 bits.
+        assert_eq!(uart_nco(921_600, 6_000_000), None);
+        assert_eq!(uart_nco(115_200, 125_000), None);
+        // The nearest NCO step is too coarse: 30 baud comes out as 22.
+        assert_eq!(uart_nco(30, 24_000_000), None);
+        assert_eq!(uart_nco(0, 24_000_000), None);
+        assert_eq!(uart_nco(115_200, 0), None);
+    }
+}